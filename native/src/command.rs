@@ -0,0 +1,118 @@
+//! External command execution for fixes that shell out (pnpm, cargo, git).
+//!
+//! Rules run commands through `RuleContext::run_command` rather than
+//! `std::process::Command` directly, so tests can substitute a runner that
+//! records invocations instead of spawning processes.
+
+use std::path::Path;
+use std::process::{Command, Output};
+
+/// Runs an external program and captures its output
+pub trait CommandRunner: Send + Sync {
+    fn run(&self, program: &str, args: &[&str], cwd: &Path) -> std::io::Result<Output>;
+}
+
+/// Runs commands as real child processes
+pub struct SystemCommandRunner;
+
+impl CommandRunner for SystemCommandRunner {
+    fn run(&self, program: &str, args: &[&str], cwd: &Path) -> std::io::Result<Output> {
+        Command::new(program).args(args).current_dir(cwd).output()
+    }
+}
+
+/// Refuses every command, so previewed or offline fixes can't shell out. Holds
+/// the reason reported in the error, e.g. `"while previewing fixes"`.
+pub struct NoCommandRunner(pub &'static str);
+
+impl CommandRunner for NoCommandRunner {
+    fn run(&self, program: &str, _args: &[&str], _cwd: &Path) -> std::io::Result<Output> {
+        Err(std::io::Error::other(format!("'{}' is not run {}", program, self.0)))
+    }
+}
+
+/// Whether `program` resolves to a file in one of the PATH directories
+pub fn on_path(program: &str) -> bool {
+    let Some(path) = std::env::var_os("PATH") else {
+        return false;
+    };
+    // Windows shims for pnpm and friends are .cmd files
+    let extensions: &[&str] = if cfg!(windows) {
+        &["", ".exe", ".cmd", ".bat"]
+    } else {
+        &[""]
+    };
+
+    std::env::split_paths(&path).any(|dir| {
+        extensions
+            .iter()
+            .any(|ext| dir.join(format!("{}{}", program, ext)).is_file())
+    })
+}
+
+#[cfg(test)]
+pub use self::testing::RecordingCommandRunner;
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use std::path::PathBuf;
+    use std::sync::Mutex;
+
+    /// Test double that records each invocation and reports success without running anything
+    #[derive(Default)]
+    pub struct RecordingCommandRunner {
+        calls: Mutex<Vec<(String, Vec<String>, PathBuf)>>,
+        stdout: String,
+    }
+
+    impl RecordingCommandRunner {
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// A runner whose commands all print `stdout`
+        pub fn with_stdout(stdout: &str) -> Self {
+            Self {
+                stdout: stdout.to_string(),
+                ..Self::default()
+            }
+        }
+
+        /// Commands run so far, formatted as "program arg1 arg2"
+        pub fn commands(&self) -> Vec<String> {
+            self.calls
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|(program, args, _)| {
+                    std::iter::once(program.clone())
+                        .chain(args.iter().cloned())
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                })
+                .collect()
+        }
+    }
+
+    impl CommandRunner for RecordingCommandRunner {
+        fn run(&self, program: &str, args: &[&str], cwd: &Path) -> std::io::Result<Output> {
+            self.calls.lock().unwrap().push((
+                program.to_string(),
+                args.iter().map(|a| a.to_string()).collect(),
+                cwd.to_path_buf(),
+            ));
+
+            #[cfg(unix)]
+            let status = std::os::unix::process::ExitStatusExt::from_raw(0);
+            #[cfg(windows)]
+            let status = std::os::windows::process::ExitStatusExt::from_raw(0);
+
+            Ok(Output {
+                status,
+                stdout: self.stdout.clone().into_bytes(),
+                stderr: Vec::new(),
+            })
+        }
+    }
+}
@@ -1,98 +1,2413 @@
-use crate::rules::RuleRegistry;
-use crate::types::{Config, LintReport, LintResult, RuleContext, RuleInfo};
-use std::path::PathBuf;
+use crate::rules::{rule_enabled, Rule, RuleError, RuleRegistry};
+use crate::scan::{WorkspaceInfo, WorkspaceScan};
+use crate::walk;
+use crate::types::{
+    CheckDescription, CheckRef, Config, FileContent, FileOverlay, FixError, FixRef, FixSummary,
+    LintReport, LintResult, PlannedChange, ResultOrder, RuleConfig, RuleContext, RuleInfo,
+    ScanStats, Severity, WorkspaceReport,
+};
+use crate::command::{on_path, NoCommandRunner};
+use globset::{GlobBuilder, GlobSetBuilder};
+use std::collections::{HashMap, HashSet};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// Rule ID for results produced by the engine's shared scan rather than a rule
+const SCAN_RULE_ID: &str = "package-json";
+const CHECK_VALID_PACKAGE_JSON: &str = "valid-package-json";
+
+/// Check ID for results reporting a rule that panicked
+const CHECK_RULE_PANICKED: &str = "rule-panicked";
 
 /// Error type for engine operations
 #[derive(Debug, thiserror::Error)]
 pub enum EngineError {
     #[error("Path does not exist: {0}")]
     PathNotFound(String),
+    #[error("Unknown rule: {0}")]
+    UnknownRule(String),
+    #[error("Rule has no fixes: {0}")]
+    RuleNotFixable(String),
+    #[error("Unknown preset: {0} (expected \"recommended\" or \"strict\")")]
+    UnknownPreset(String),
+    #[error("File changed since the fix was planned: {0}")]
+    ChangeConflict(String),
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 }
 
+/// A scan root already checked to exist. Embedders that lint the same root
+/// repeatedly (e.g. a language server) can validate it once and reuse it.
+#[derive(Debug, Clone)]
+pub struct PreparedRoot(PathBuf);
+
+impl PreparedRoot {
+    pub fn new(path: &str) -> Result<Self, EngineError> {
+        let root = PathBuf::from(path);
+        if !root.exists() {
+            return Err(EngineError::PathNotFound(path.to_string()));
+        }
+        Ok(Self(root))
+    }
+}
+
+/// Directories never holding files a rule declares as input
+const INPUT_SKIP_DIRS: &[&str] = &["node_modules", ".git"];
+
+/// A rule's results from its last cached check, with the hash of its inputs then
+struct CachedCheck {
+    inputs_hash: u64,
+    results: Vec<LintResult>,
+}
+
 /// Rule execution engine
 pub struct Runner {
     config: Config,
     registry: RuleRegistry,
+    /// Last results per rule for `run_cached`, kept across calls
+    check_cache: Mutex<HashMap<&'static str, CachedCheck>>,
 }
 
 impl Runner {
     pub fn new(config: Config) -> Self {
+        let registry = if config.load_enabled_rules_only {
+            RuleRegistry::for_config(&config)
+        } else {
+            RuleRegistry::new()
+        };
+        Self::with_registry(config, registry)
+    }
+
+    /// Look up a rule, constructing a built-in that wasn't loaded because it's disabled
+    fn rule(&self, rule_id: &str) -> Option<Arc<dyn Rule>> {
+        match self.registry.get(rule_id) {
+            Some(rule) => Some(rule),
+            None if self.config.load_enabled_rules_only => RuleRegistry::builtin(rule_id),
+            None => None,
+        }
+    }
+
+    /// Run `f` on a registry holding every rule, building the full built-in set
+    /// when only enabled rules were loaded
+    fn with_all_rules<T>(&self, f: impl FnOnce(&RuleRegistry) -> T) -> T {
+        if self.config.load_enabled_rules_only {
+            f(&RuleRegistry::new())
+        } else {
+            f(&self.registry)
+        }
+    }
+
+    fn with_registry(config: Config, registry: RuleRegistry) -> Self {
         Self {
             config,
-            registry: RuleRegistry::new(),
+            registry,
+            check_cache: Mutex::new(HashMap::new()),
         }
     }
 
     /// Run all enabled rules on the specified path
     pub fn run(&self, path: &str) -> Result<LintReport, EngineError> {
-        self.run_internal(path, false)
+        Ok(self.cap_results(self.run_internal(path, false)?))
+    }
+
+    /// Run all enabled rules on a root validated up front, skipping the path check
+    pub fn run_prepared(&self, root: &PreparedRoot) -> LintReport {
+        self.cap_results(self.run_root(&root.0, false, false))
+    }
+
+    /// Run all enabled rules, reusing a rule's results from the previous cached run
+    /// while the files it declares as inputs are unchanged. Meant for watch mode,
+    /// where the same runner lints the same tree repeatedly.
+    pub fn run_cached(&self, path: &str) -> Result<LintReport, EngineError> {
+        let root = PreparedRoot::new(path)?;
+        Ok(self.cap_results(self.run_root(&root.0, false, true)))
     }
 
     /// Run all enabled rules and apply fixes
     pub fn run_with_fix(&self, path: &str) -> Result<LintReport, EngineError> {
-        self.run_internal(path, true)
+        Ok(self.cap_results(self.run_internal(path, true)?))
     }
 
-    fn run_internal(&self, path: &str, fix_mode: bool) -> Result<LintReport, EngineError> {
-        let root = PathBuf::from(path);
-        if !root.exists() {
-            return Err(EngineError::PathNotFound(path.to_string()));
+    /// Apply the `maxResults` cap. Kept out of `run_internal` so baselines are
+    /// built from, and filter, the full result set.
+    fn cap_results(&self, report: LintReport) -> LintReport {
+        match self.config.max_results {
+            Some(max_results) => report.truncate(max_results as usize),
+            None => report,
         }
+    }
 
+    fn run_internal(&self, path: &str, fix_mode: bool) -> Result<LintReport, EngineError> {
+        let root = PreparedRoot::new(path)?;
+        Ok(self.run_root(&root.0, fix_mode, false))
+    }
+
+    fn run_root(&self, root: &Path, fix_mode: bool, cached: bool) -> LintReport {
         let mut total_fixed: u32 = 0;
+        let mut all_results: Vec<LintResult> = Vec::new();
+        let mut nothing_to_fix = false;
+        let mut fix_errors: Vec<FixError> = Vec::new();
+        // Detected once so every rule, in every pass, sees the same workspace facts
+        let workspace = Arc::new(WorkspaceInfo::detect(root));
 
-        // If in fix mode, first apply all fixes
+        // If in fix mode, first apply all fixes. A fix can enable another rule's fix
+        // (husky-init creates .husky, then cspell can add its hook), so passes repeat
+        // until one applies nothing or the iteration cap is hit. Fixes only report
+        // changes they actually made, so satisfied shell-out fixes don't run again.
         if fix_mode {
-            for rule in self.registry.all() {
-                let rule_config = self.config.rules.get(rule.id());
-                let enabled = rule_config.map(|c| c.enabled).unwrap_or(true);
+            // A pre-fix check tells "already compliant" apart from fixes that did nothing
+            if !self.config.skip_check_in_fix_mode {
+                let scan = Arc::new(WorkspaceScan::new(root, self.config.follow_symlinks));
+                let (mut before, _) = self.check_rules(root, &scan, &workspace, false, None);
+                // Offline, fixes that need commands can't run, so they don't count
+                if self.config.offline {
+                    self.drop_command_fixes(&mut before);
+                }
+                nothing_to_fix = before.iter().all(|r| r.fixable_by.is_empty());
+            }
 
-                if !enabled {
-                    continue;
+            let max_passes = self.config.fix_iterations.unwrap_or(1).max(1);
+            for _ in 0..max_passes {
+                let fixed =
+                    self.fix_pass(root, &workspace, None, &mut all_results, &mut fix_errors);
+                total_fixed += fixed;
+                if fixed == 0 {
+                    break;
                 }
+            }
+        }
 
-                let options = rule_config
-                    .map(|c| c.options.clone())
-                    .unwrap_or(serde_json::Value::Null);
+        // Run checks (after fixes if in fix mode) against a single shared scan
+        let scan = Arc::new(WorkspaceScan::new(root, self.config.follow_symlinks));
+        let fail_fast = self.config.fail_fast && !fix_mode;
+        let input_files = cached.then(|| self.input_files(root));
+        let (results, fail_fast_triggered) =
+            self.check_rules(root, &scan, &workspace, fail_fast, input_files.as_deref());
+        all_results.extend(results);
+        all_results.extend(self.invalid_package_json_results(root, &scan));
 
-                let context = RuleContext::new(root.clone(), fix_mode, options);
+        let mut report = LintReport::new(self.finish_results(all_results, root), total_fixed);
+        report.fail_fast_triggered = fail_fast_triggered;
+        report.nothing_to_fix = nothing_to_fix;
+        report.fix_errors = fix_errors;
+        report
+    }
+
+    /// Unreadable package.json files, reported once here instead of by each rule
+    fn invalid_package_json_results(&self, root: &Path, scan: &WorkspaceScan) -> Vec<LintResult> {
+        let excluded_dirs = self.config.excluded_package_dirs();
+        scan.invalid_package_jsons()
+            .into_iter()
+            .filter(|(path, _)| !walk::in_excluded_dir(path, root, &excluded_dirs))
+            .map(|(path, error)| {
+                LintResult::new(
+                    SCAN_RULE_ID,
+                    CHECK_VALID_PACKAGE_JSON,
+                    Severity::Error,
+                    error.to_string(),
+                    path.to_path_buf(),
+                    None,
+                    Some("Fix JSON syntax errors".into()),
+                    vec![],
+                )
+            })
+            .collect()
+    }
 
-                if rule.can_fix() {
-                    if let Ok(fixed) = rule.fix(&context) {
-                        total_fixed += fixed;
+    /// Files under `root` that rules may declare as inputs, as ('/'-separated
+    /// relative path, full path) pairs sorted by relative path
+    fn input_files(&self, root: &Path) -> Vec<(String, PathBuf)> {
+        let mut files: Vec<(String, PathBuf)> =
+            walk::entries(root, self.config.follow_symlinks, INPUT_SKIP_DIRS)
+                .into_iter()
+                .filter(|e| e.file_type().is_file())
+                .filter_map(|e| {
+                    let relative = e.path().strip_prefix(root).ok()?;
+                    let parts: Vec<_> = relative
+                        .components()
+                        .map(|c| c.as_os_str().to_string_lossy())
+                        .collect();
+                    Some((parts.join("/"), e.into_path()))
+                })
+                .collect();
+        files.sort();
+        files
+    }
+
+    /// Hash of everything a rule's check depends on: its options and the path and
+    /// content of each file matching its declared inputs. None when the rule
+    /// declares no inputs and so can't be cached.
+    fn inputs_hash(
+        rule: &dyn Rule,
+        root: &Path,
+        options: &serde_json::Value,
+        files: &[(String, PathBuf)],
+    ) -> Option<u64> {
+        let globs = rule.inputs();
+        if globs.is_empty() {
+            return None;
+        }
+        let mut builder = GlobSetBuilder::new();
+        for glob in globs {
+            builder.add(GlobBuilder::new(glob).literal_separator(true).build().ok()?);
+        }
+        let matcher = builder.build().ok()?;
+
+        let mut hasher = DefaultHasher::new();
+        root.hash(&mut hasher);
+        options.to_string().hash(&mut hasher);
+        for (relative, path) in files.iter().filter(|(relative, _)| matcher.is_match(relative)) {
+            relative.hash(&mut hasher);
+            std::fs::read(path).ok().hash(&mut hasher);
+        }
+        Some(hasher.finish())
+    }
+
+    /// Run the checks of every enabled rule against `scan`. With `fail_fast`, stops
+    /// after the first rule that reports an error; the flag returned says whether it did.
+    /// With `input_files`, rules whose inputs are unchanged since the last cached
+    /// run reuse those results instead of checking again.
+    fn check_rules(
+        &self,
+        root: &Path,
+        scan: &Arc<WorkspaceScan>,
+        workspace: &Arc<WorkspaceInfo>,
+        fail_fast: bool,
+        input_files: Option<&[(String, PathBuf)]>,
+    ) -> (Vec<LintResult>, bool) {
+        let mut results = Vec::new();
+
+        for rule in self.registry.all() {
+            let Some(options) = self.enabled_options(rule.as_ref()) else {
+                continue;
+            };
+            let inputs_hash = input_files
+                .and_then(|files| Self::inputs_hash(rule.as_ref(), root, &options, files));
+
+            let cached = inputs_hash.and_then(|hash| {
+                let cache = self.check_cache.lock().unwrap();
+                cache
+                    .get(rule.id())
+                    .filter(|cached| cached.inputs_hash == hash)
+                    .map(|cached| cached.results.clone())
+            });
+            if let Some(cached) = cached {
+                results.extend(cached);
+            } else {
+                match self.check_rule(rule.as_ref(), options, root, scan, workspace) {
+                    Ok(checked) => {
+                        if let Some(inputs_hash) = inputs_hash {
+                            let results = checked.clone();
+                            self.check_cache
+                                .lock()
+                                .unwrap()
+                                .insert(rule.id(), CachedCheck { inputs_hash, results });
+                        }
+                        results.extend(checked);
+                    }
+                    Err(reason) => {
+                        results.push(panic_result(rule.as_ref(), "check", root, reason))
                     }
                 }
             }
+
+            let reports_error = |r: &LintResult| self.is_reported(r) && self.is_error(&r.severity);
+            if fail_fast && results.iter().any(reports_error) {
+                return (results, true);
+            }
         }
 
-        // Run checks (after fixes if in fix mode)
-        let mut all_results: Vec<LintResult> = Vec::new();
+        (results, false)
+    }
 
-        for rule in self.registry.all() {
-            let rule_config = self.config.rules.get(rule.id());
-            let enabled = rule_config.map(|c| c.enabled).unwrap_or(true);
+    /// Run one rule's check against `scan`; Err holds the panic message
+    fn check_rule(
+        &self,
+        rule: &dyn Rule,
+        options: serde_json::Value,
+        root: &Path,
+        scan: &Arc<WorkspaceScan>,
+        workspace: &Arc<WorkspaceInfo>,
+    ) -> Result<Vec<LintResult>, String> {
+        let context = self.context(root, options, workspace).with_scan(scan.clone());
+        catch_rule_panic(|| rule.check(&context))
+    }
+
+    /// Whether a result survives `suppress` and `quiet` and ends up in the report
+    fn is_reported(&self, result: &LintResult) -> bool {
+        let suppressed = self.config.suppress.iter().any(|p| p.is_match(&result.message));
+        let quieted = self.config.quiet && result.severity == Severity::Info.to_string();
+        !suppressed && !quieted
+    }
+
+    /// Whether a result with this severity ends up as an error once
+    /// `promoteInfo` and `warningsAsErrors` are applied
+    fn is_error(&self, severity: &str) -> bool {
+        severity == Severity::Error.to_string()
+            || (self.config.warnings_as_errors
+                && (severity == Severity::Warning.to_string()
+                    || (self.config.promote_info && severity == Severity::Info.to_string())))
+    }
+
+    /// Apply only the fixes of `rule_id`, then re-check that rule for the report,
+    /// as `run_with_fix` would with only that rule enabled. The rule runs with its
+    /// configured options even if it is disabled in the config.
+    pub fn run_rule_fix(&self, path: &str, rule_id: &str) -> Result<LintReport, EngineError> {
+        let root = PreparedRoot::new(path)?;
+        let root = root.0.as_path();
+
+        let rule = self
+            .rule(rule_id)
+            .ok_or_else(|| EngineError::UnknownRule(rule_id.to_string()))?;
+        if !rule.can_fix() {
+            return Err(EngineError::RuleNotFixable(rule_id.to_string()));
+        }
+
+        let options = self.rule_options(rule_id);
+        let workspace = Arc::new(WorkspaceInfo::detect(root));
+        let mut results = Vec::new();
+        let mut fix_errors = Vec::new();
+        let mut total_fixed = 0;
+        let max_passes = self.config.fix_iterations.unwrap_or(1).max(1);
+        for _ in 0..max_passes {
+            let fixed = self.fix_rule(
+                rule.as_ref(),
+                options.clone(),
+                root,
+                &workspace,
+                None,
+                &mut results,
+                &mut fix_errors,
+            );
+            total_fixed += fixed;
+            if fixed == 0 {
+                break;
+            }
+        }
+
+        let scan = Arc::new(WorkspaceScan::new(root, self.config.follow_symlinks));
+        match self.check_rule(rule.as_ref(), options, root, &scan, &workspace) {
+            Ok(checked) => results.extend(checked),
+            Err(reason) => results.push(panic_result(rule.as_ref(), "check", root, reason)),
+        }
+        results.extend(self.invalid_package_json_results(root, &scan));
+
+        let mut report = LintReport::new(self.finish_results(results, root), total_fixed);
+        report.fix_errors = fix_errors;
+        Ok(self.cap_results(report))
+    }
+
+    /// Build the context a rule runs with, carrying the engine-wide settings
+    fn context(
+        &self,
+        root: &Path,
+        options: serde_json::Value,
+        workspace: &Arc<WorkspaceInfo>,
+    ) -> RuleContext {
+        let context = RuleContext::new(root.to_path_buf(), options)
+            .with_workspace(workspace.clone())
+            .with_follow_symlinks(self.config.follow_symlinks)
+            .with_quiet(self.config.quiet)
+            .with_excluded_dirs(self.config.excluded_package_dirs())
+            .with_max_file_size(self.config.max_file_size);
+        if self.config.offline {
+            context
+                .with_offline(true)
+                .with_command_runner(Arc::new(NoCommandRunner("in offline mode")))
+        } else {
+            context
+        }
+    }
+
+    /// Drop fixes that need an external program from `fixable_by`, since offline
+    /// mode can't run them. Results left without a fix say so in their suggestion.
+    fn drop_command_fixes(&self, results: &mut [LintResult]) {
+        for result in results.iter_mut().filter(|r| !r.fixable_by.is_empty()) {
+            let Some(rule) = self.rule(&result.rule_id) else {
+                continue;
+            };
+            result.fixable_by.retain(|fix_id| rule.fix_requirements(fix_id).is_empty());
+            if !result.fixable_by.is_empty() {
+                continue;
+            }
+            let manual = "Manual action needed: the fix runs external commands, \
+                          which offline mode doesn't allow";
+            result.suggestion = Some(match result.suggestion.take() {
+                Some(suggestion) => format!("{}. {}", manual, suggestion),
+                None => manual.to_string(),
+            });
+        }
+    }
 
-            if !enabled {
+    /// Set `fix_runnable` on fixable results: true when at least one of the fixes
+    /// has every tool it needs on PATH
+    fn annotate_runnable_fixes(&self, results: &mut [LintResult]) {
+        let mut available: HashMap<&'static str, bool> = HashMap::new();
+
+        for result in results.iter_mut().filter(|r| !r.fixable_by.is_empty()) {
+            let Some(rule) = self.rule(&result.rule_id) else {
                 continue;
+            };
+            let runnable = result.fixable_by.iter().any(|fix_id| {
+                rule.fix_requirements(fix_id)
+                    .into_iter()
+                    .all(|tool| *available.entry(tool).or_insert_with(|| on_path(tool)))
+            });
+            result.fix_runnable = Some(runnable);
+        }
+    }
+
+    /// Apply the `quiet` filter and set fingerprints on results before reporting
+    fn finish_results(&self, mut results: Vec<LintResult>, root: &Path) -> Vec<LintResult> {
+        // Not every rule consults `quiet`, so this also drops info results that slipped through
+        results.retain(|r| self.is_reported(r));
+
+        if self.config.offline {
+            self.drop_command_fixes(&mut results);
+        }
+        if self.config.annotate_runnable_fixes {
+            self.annotate_runnable_fixes(&mut results);
+        }
+
+        for result in &mut results {
+            if self.config.promote_info && result.severity == Severity::Info.to_string() {
+                result.severity = Severity::Warning.to_string();
+            }
+            if self.config.warnings_as_errors && result.severity == Severity::Warning.to_string() {
+                result.severity = Severity::Error.to_string();
             }
+            result.fingerprint = fingerprint(result, root);
+        }
 
-            let options = rule_config
-                .map(|c| c.options.clone())
-                .unwrap_or(serde_json::Value::Null);
+        if self.config.sort == Some(ResultOrder::Severity) {
+            // Stable, so results tied on all three keep their discovery order
+            results.sort_by(|a, b| {
+                severity_rank(&a.severity)
+                    .cmp(&severity_rank(&b.severity))
+                    .then_with(|| a.path.cmp(&b.path))
+                    .then_with(|| a.rule_id.cmp(&b.rule_id))
+            });
+        }
 
-            let context = RuleContext::new(root.clone(), fix_mode, options);
+        // After fingerprinting, so baselines don't depend on this setting
+        if self.config.redact_home {
+            if let Some(home) = std::env::home_dir() {
+                redact_home(&mut results, &home);
+            }
+        }
+        if let Some(prefix) = &self.config.strip_prefix {
+            strip_path_prefix(&mut results, prefix);
+        }
+
+        results
+    }
 
-            let results = rule.check(&context);
-            all_results.extend(results);
+    /// Apply the fixes of every enabled rule once, returning the number applied.
+    /// A fix that panics is reported into `results` and the pass carries on.
+    /// With an overlay, writes are buffered there and commands are refused.
+    fn fix_pass(
+        &self,
+        root: &Path,
+        workspace: &Arc<WorkspaceInfo>,
+        overlay: Option<&Arc<FileOverlay>>,
+        results: &mut Vec<LintResult>,
+        errors: &mut Vec<FixError>,
+    ) -> u32 {
+        let mut fixed_count = 0;
+
+        for rule in self.registry.all() {
+            let Some(options) = self.enabled_options(rule.as_ref()) else {
+                continue;
+            };
+            if rule.can_fix() {
+                fixed_count += self.fix_rule(
+                    rule.as_ref(),
+                    options,
+                    root,
+                    workspace,
+                    overlay,
+                    results,
+                    errors,
+                );
+            }
+        }
+
+        fixed_count
+    }
+
+    /// Apply one rule's fixes, returning how many it made. Failures go to `errors`,
+    /// panics to `results`.
+    #[allow(clippy::too_many_arguments)]
+    fn fix_rule(
+        &self,
+        rule: &dyn Rule,
+        options: serde_json::Value,
+        root: &Path,
+        workspace: &Arc<WorkspaceInfo>,
+        overlay: Option<&Arc<FileOverlay>>,
+        results: &mut Vec<LintResult>,
+        errors: &mut Vec<FixError>,
+    ) -> u32 {
+        let mut context = self.context(root, options, workspace);
+        // With atomicFix, a rule's file changes reach disk only if its fix succeeds
+        let transaction =
+            (overlay.is_none() && self.config.atomic_fix).then(|| Arc::new(FileOverlay::default()));
+        if let Some(overlay) = overlay {
+            context = context
+                .with_overlay(overlay.clone())
+                .with_command_runner(Arc::new(NoCommandRunner("while previewing fixes")));
+        } else if let Some(transaction) = &transaction {
+            context = context.with_overlay(transaction.clone());
         }
 
-        Ok(LintReport::new(all_results, total_fixed))
+        match catch_rule_panic(|| rule.fix(&context)) {
+            Ok(Ok(fixed)) => match transaction.map_or(Ok(()), |t| t.commit()) {
+                Ok(()) => return fixed,
+                Err(error) => push_fix_error(errors, rule, error.into()),
+            },
+            Ok(Err(error)) => push_fix_error(errors, rule, error),
+            Err(reason) => results.push(panic_result(rule, "fix", root, reason)),
+        }
+        0
+    }
+
+    /// Run all enabled rules, dropping results whose fingerprint is in the baseline
+    pub fn run_with_baseline(
+        &self,
+        path: &str,
+        baseline: &[String],
+    ) -> Result<LintReport, EngineError> {
+        let mut report = self.run_internal(path, false)?;
+        let baseline: HashSet<&str> = baseline.iter().map(String::as_str).collect();
+
+        report.retain(|r| !baseline.contains(r.fingerprint.as_str()));
+        Ok(self.cap_results(report))
+    }
+
+    /// Collect the fingerprints of all current results, for use as a baseline
+    pub fn baseline(&self, path: &str) -> Result<Vec<String>, EngineError> {
+        let mut fingerprints: Vec<String> = self
+            .run_internal(path, false)?
+            .results
+            .into_iter()
+            .map(|r| r.fingerprint)
+            .collect();
+        fingerprints.sort();
+        fingerprints.dedup();
+        Ok(fingerprints)
+    }
+
+    /// Write the fingerprints of all current results to `out_path`, one per line,
+    /// returning how many were written
+    pub fn write_baseline(&self, path: &str, out_path: &str) -> Result<u32, EngineError> {
+        let fingerprints = self.baseline(path)?;
+        let mut content = fingerprints.join("\n");
+        if !content.is_empty() {
+            content.push('\n');
+        }
+        std::fs::write(out_path, content)?;
+        Ok(fingerprints.len() as u32)
+    }
+
+    /// Run all enabled rules, dropping results listed in a file written by `write_baseline`
+    pub fn run_with_baseline_file(
+        &self,
+        path: &str,
+        baseline_path: &str,
+    ) -> Result<LintReport, EngineError> {
+        let baseline: Vec<String> = std::fs::read_to_string(baseline_path)?
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(String::from)
+            .collect();
+        self.run_with_baseline(path, &baseline)
+    }
+
+    /// Returns the rule's options if it is enabled, or None if it should be skipped.
+    /// Rules not mentioned in the config fall back to `enabledTags`, then to
+    /// `Rule::enabled_by_default`.
+    fn enabled_options(&self, rule: &dyn Rule) -> Option<serde_json::Value> {
+        if !rule_enabled(&self.config, rule.id(), rule.enabled_by_default(), &rule.tags()) {
+            return None;
+        }
+        Some(self.rule_options(rule.id()))
+    }
+
+    /// A rule's configured options, whether or not it is enabled
+    fn rule_options(&self, rule_id: &str) -> serde_json::Value {
+        self.config
+            .rules
+            .get(rule_id)
+            .map(|c| c.options.clone())
+            .unwrap_or(serde_json::Value::Null)
     }
 
     /// List all available rules
     pub fn list_rules(&self) -> Vec<RuleInfo> {
-        self.registry.all().iter().map(|r| r.info()).collect()
+        self.with_all_rules(|registry| registry.all().iter().map(|r| r.info()).collect())
+    }
+
+    /// List the rules that can apply fixes, in execution order
+    pub fn list_fixable_rules(&self) -> Vec<RuleInfo> {
+        self.list_rules().into_iter().filter(|r| r.can_fix).collect()
+    }
+
+    /// Rule ids in the order rules run and fixes are applied
+    pub fn rule_order(&self) -> Vec<String> {
+        self.with_all_rules(|registry| registry.order().to_vec())
+    }
+
+    /// Describe a single check, or None if the rule or check is unknown
+    pub fn describe_check(&self, rule_id: &str, check_id: &str) -> Option<CheckDescription> {
+        self.with_all_rules(|registry| registry.get(rule_id)?.describe_check(check_id))
+    }
+
+    /// Every check of every rule, in rule execution order, for validating config
+    /// such as `disabledChecks` or generating docs
+    pub fn all_check_ids(&self) -> Vec<CheckRef> {
+        self.with_all_rules(|registry| {
+            registry
+                .all()
+                .iter()
+                .flat_map(|rule| {
+                    rule.checks().into_iter().map(|check| CheckRef {
+                        rule_id: rule.id().to_string(),
+                        check_id: check.id,
+                        description: check.description,
+                    })
+                })
+                .collect()
+        })
+    }
+
+    /// Every fix of every rule, in rule execution order
+    pub fn all_fix_ids(&self) -> Vec<FixRef> {
+        self.with_all_rules(|registry| {
+            registry
+                .all()
+                .iter()
+                .flat_map(|rule| {
+                    rule.fixes().into_iter().map(|fix| FixRef {
+                        rule_id: rule.id().to_string(),
+                        fix_id: fix.id,
+                        description: fix.description,
+                        addresses: fix.addresses,
+                    })
+                })
+                .collect()
+        })
+    }
+
+    /// Every file the fixes would change, with its full content afterwards, without
+    /// writing anything. Runs a single fix pass; fixes that need a command (pnpm add,
+    /// husky init) can't run and are left out.
+    pub fn preview_fixed_files(&self, path: &str) -> Result<Vec<FileContent>, EngineError> {
+        let previews = self
+            .plan_fix(path)?
+            .into_iter()
+            .map(|change| FileContent {
+                path: change.path,
+                content: change.content,
+                deleted: change.deleted,
+            })
+            .collect();
+        Ok(previews)
+    }
+
+    /// The file changes the fixes would make, each with the content it was planned
+    /// against so it can be applied on its own later with [`Runner::apply_change`].
+    /// Nothing is written; like the preview, command-based fixes are left out.
+    pub fn plan_fix(&self, path: &str) -> Result<Vec<PlannedChange>, EngineError> {
+        let root = PreparedRoot::new(path)?;
+        let root = root.0.as_path();
+        let workspace = Arc::new(WorkspaceInfo::detect(root));
+        let overlay = Arc::new(FileOverlay::default());
+
+        // Failed fixes and panics only mean fewer planned changes
+        let mut results = Vec::new();
+        let mut fix_errors = Vec::new();
+        self.fix_pass(root, &workspace, Some(&overlay), &mut results, &mut fix_errors);
+
+        let changes = overlay
+            .changes()
+            .into_iter()
+            .filter_map(|(path, content)| {
+                let original = std::fs::read_to_string(&path).ok();
+                let deleted = content.is_none();
+                if deleted && !path.exists() || content.is_some() && content == original {
+                    return None;
+                }
+                Some(PlannedChange {
+                    path: path.display().to_string(),
+                    content: content.unwrap_or_default(),
+                    deleted,
+                    original,
+                })
+            })
+            .collect();
+        Ok(changes)
+    }
+
+    /// Write or delete the one file a planned change targets. Fails with
+    /// [`EngineError::ChangeConflict`] if the file no longer has the content the
+    /// change was planned against.
+    pub fn apply_change(&self, change: &PlannedChange) -> Result<(), EngineError> {
+        let path = Path::new(&change.path);
+        let current = match std::fs::read_to_string(path) {
+            Ok(content) => Some(content),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+            Err(e) => return Err(e.into()),
+        };
+        if current != change.original {
+            return Err(EngineError::ChangeConflict(change.path.clone()));
+        }
+
+        if change.deleted {
+            if current.is_some() {
+                std::fs::remove_file(path)?;
+            }
+        } else {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(path, &change.content)?;
+        }
+        Ok(())
+    }
+
+    /// The package.json files and git repositories rules would see under `path`,
+    /// without running any rule
+    pub fn scan_workspace(&self, path: &str) -> Result<WorkspaceReport, EngineError> {
+        let root = PreparedRoot::new(path)?;
+        let root = root.0.as_path();
+        let workspace = Arc::new(WorkspaceInfo::detect(root));
+        let scan = Arc::new(WorkspaceScan::new(root, self.config.follow_symlinks));
+        let context = self
+            .context(root, serde_json::Value::Null, &workspace)
+            .with_scan(scan);
+
+        let to_strings = |paths: Vec<PathBuf>| -> Vec<String> {
+            let mut paths: Vec<String> = paths.iter().map(|p| p.display().to_string()).collect();
+            paths.sort();
+            paths
+        };
+        let has_workspace_file = workspace.workspace_root.join("pnpm-workspace.yaml").is_file();
+
+        Ok(WorkspaceReport {
+            package_jsons: to_strings(context.package_jsons()),
+            git_repos: to_strings(context.git_repos()),
+            workspace_root: has_workspace_file
+                .then(|| workspace.workspace_root.display().to_string()),
+        })
+    }
+
+    /// How much work the shared scan does under `path`: entries walked, what it
+    /// found and how long it took. Runs the scan once, without any rule.
+    pub fn scan_stats(&self, path: &str) -> Result<ScanStats, EngineError> {
+        let root = PreparedRoot::new(path)?;
+        let started = std::time::Instant::now();
+        let scan = WorkspaceScan::new(&root.0, self.config.follow_symlinks);
+        let duration = started.elapsed();
+        let counts = scan.walk_counts();
+
+        Ok(ScanStats {
+            directories_visited: counts.directories as i64,
+            files_examined: counts.files as i64,
+            package_jsons: scan.package_jsons().len() as u32,
+            git_repos: scan.git_repos().len() as u32,
+            duration_ms: duration.as_millis() as i64,
+        })
+    }
+
+    /// Group the fixes referenced by a report's results, in order of first
+    /// appearance. Fixes the registry doesn't know about are skipped.
+    pub fn summarize_fixes(&self, report: &LintReport) -> Vec<FixSummary> {
+        self.with_all_rules(|registry| {
+            let mut summaries: Vec<FixSummary> = Vec::new();
+            for result in &report.results {
+                for fix_id in &result.fixable_by {
+                    let existing = summaries
+                        .iter_mut()
+                        .find(|s| s.rule_id == result.rule_id && &s.fix_id == fix_id);
+                    let summary = match existing {
+                        Some(summary) => summary,
+                        None => {
+                            let Some(fix) = registry
+                                .get(&result.rule_id)
+                                .and_then(|rule| rule.fixes().into_iter().find(|f| &f.id == fix_id))
+                            else {
+                                continue;
+                            };
+                            summaries.push(FixSummary {
+                                fix_id: fix.id,
+                                rule_id: result.rule_id.clone(),
+                                description: fix.description,
+                                affected_paths: Vec::new(),
+                                resolves_count: 0,
+                            });
+                            summaries.last_mut().unwrap()
+                        }
+                    };
+                    summary.resolves_count += 1;
+                    if !summary.affected_paths.contains(&result.path) {
+                        summary.affected_paths.push(result.path.clone());
+                    }
+                }
+            }
+            summaries
+        })
+    }
+}
+
+/// Record a failed fix. A rule failing the same way in every fix pass is listed once.
+fn push_fix_error(errors: &mut Vec<FixError>, rule: &dyn Rule, error: RuleError) {
+    let error = FixError {
+        rule_id: rule.id().to_string(),
+        message: error.to_string(),
+    };
+    if !errors.contains(&error) {
+        errors.push(error);
+    }
+}
+
+/// Run one rule invocation, catching a panic so a single broken rule doesn't
+/// abort the whole scan. Returns the panic message on failure.
+fn catch_rule_panic<T>(invoke: impl FnOnce() -> T) -> Result<T, String> {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(invoke)).map_err(|payload| {
+        payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_string())
+    })
+}
+
+/// Error result reporting that `rule` panicked during `phase` (check or fix)
+fn panic_result(rule: &dyn Rule, phase: &str, root: &Path, reason: String) -> LintResult {
+    LintResult::new(
+        rule.id(),
+        CHECK_RULE_PANICKED,
+        Severity::Error,
+        format!("Rule '{}' panicked during {}: {}", rule.id(), phase, reason),
+        root.to_path_buf(),
+        None,
+        Some("This is a bug in the rule; please report it".into()),
+        vec![],
+    )
+}
+
+/// Sort key for `ResultOrder::Severity`: errors first, unknown severities last
+fn severity_rank(severity: &str) -> u8 {
+    [Severity::Error, Severity::Warning, Severity::Info]
+        .iter()
+        .position(|s| s.to_string() == severity)
+        .map_or(u8::MAX, |i| i as u8)
+}
+
+/// Replace a leading `home` in result paths with `~` so usernames stay out of logs
+fn redact_home(results: &mut [LintResult], home: &Path) {
+    for result in results {
+        if let Ok(rest) = Path::new(&result.path).strip_prefix(home) {
+            result.path = Path::new("~").join(rest).display().to_string();
+        }
+    }
+}
+
+/// Remove a literal leading `prefix` from result paths so logs don't depend on
+/// where the checkout was mounted
+fn strip_path_prefix(results: &mut [LintResult], prefix: &str) {
+    if prefix.is_empty() {
+        return;
+    }
+    for result in results {
+        if let Some(rest) = result.path.strip_prefix(prefix) {
+            result.path = rest.to_string();
+        }
+    }
+}
+
+/// Build a starting config from a named preset. `recommended` is the defaults;
+/// `strict` enables every rule, including opt-in ones, and reports info and
+/// warning results as errors.
+pub fn preset_config(preset: &str) -> Result<Config, EngineError> {
+    match preset {
+        "recommended" => Ok(Config::default()),
+        "strict" => Ok(Config {
+            rules: RuleRegistry::new()
+                .all()
+                .iter()
+                .map(|rule| (rule.id().to_string(), RuleConfig::default()))
+                .collect(),
+            promote_info: true,
+            warnings_as_errors: true,
+            ..Config::default()
+        }),
+        _ => Err(EngineError::UnknownPreset(preset.to_string())),
+    }
+}
+
+/// Compute a stable fingerprint for a result, independent of line numbers and the
+/// absolute location of the scanned tree. Digits in the message are masked so that
+/// counts and line references don't change the fingerprint.
+fn fingerprint(result: &LintResult, root: &Path) -> String {
+    let path = Path::new(&result.path);
+    let relative = path.strip_prefix(root).unwrap_or(path);
+    let relative = relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/");
+
+    let mut message = String::with_capacity(result.message.len());
+    for c in result.message.chars() {
+        if c.is_ascii_digit() {
+            if !message.ends_with('#') {
+                message.push('#');
+            }
+        } else {
+            message.push(c);
+        }
+    }
+
+    // 64-bit FNV-1a: tiny and stable across platforms and Rust versions
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for part in [&result.rule_id, &result.check_id, &relative, &message] {
+        for byte in part.bytes().chain(std::iter::once(0)) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+    }
+    format!("{:016x}", hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_fingerprint_ignores_root_and_digits() {
+        let make = |path: &str, message: &str| {
+            LintResult::new(
+                "rule",
+                "check",
+                crate::types::Severity::Warning,
+                message.into(),
+                PathBuf::from(path),
+                Some(3),
+                None,
+                vec![],
+            )
+        };
+
+        let a = fingerprint(&make("/one/pkg/file.ts", "File has 11 TODOs"), Path::new("/one"));
+        let b = fingerprint(&make("/two/pkg/file.ts", "File has 12 TODOs"), Path::new("/two"));
+        let c = fingerprint(&make("/two/pkg/other.ts", "File has 12 TODOs"), Path::new("/two"));
+
+        assert_eq!(a, b);
+        assert_ne!(b, c);
+    }
+
+    #[test]
+    fn test_baselined_results_are_suppressed_and_new_ones_surface() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(root.join("package.json"), r#"{"name": "test"}"#).unwrap();
+        let path = root.to_str().unwrap();
+
+        let runner = Runner::new(Config::default());
+        let baseline = runner.baseline(path).unwrap();
+        assert!(!baseline.is_empty());
+        assert!(runner
+            .run_with_baseline(path, &baseline)
+            .unwrap()
+            .results
+            .is_empty());
+
+        // A new issue appears after the baseline was taken
+        fs::write(root.join("yarn.lock"), "# yarn lockfile v1").unwrap();
+
+        let report = runner.run_with_baseline(path, &baseline).unwrap();
+        assert_eq!(report.results.len(), 1);
+        assert!(report.results[0].message.contains("yarn.lock"));
+    }
+
+    #[test]
+    fn test_baseline_keeps_report_flags() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join(".git")).unwrap();
+        fs::write(root.join("package.json"), r#"{"name": "public-pkg"}"#).unwrap();
+        let path = root.to_str().unwrap();
+        let runner = Runner::new(Config {
+            fail_fast: true,
+            ..Config::default()
+        });
+        let baseline = runner.baseline(path).unwrap();
+
+        let report = runner.run_with_baseline(path, &baseline).unwrap();
+
+        assert!(report.results.is_empty());
+        assert_eq!(report.error_count, 0);
+        assert!(report.fail_fast_triggered);
+    }
+
+    #[test]
+    fn test_quiet_drops_info_results_but_keeps_errors() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join(".git")).unwrap();
+        fs::write(root.join("package.json"), r#"{"name": "public-pkg"}"#).unwrap();
+        fs::write(root.join("yarn.lock"), "# yarn lockfile v1").unwrap();
+        let path = root.to_str().unwrap();
+
+        let report = Runner::new(Config::default()).run(path).unwrap();
+        assert!(report.results.iter().any(|r| r.severity == "info"));
+
+        let quiet = Config {
+            quiet: true,
+            ..Config::default()
+        };
+        let report = Runner::new(quiet).run(path).unwrap();
+        assert!(report.results.iter().all(|r| r.severity != "info"));
+        assert!(report
+            .results
+            .iter()
+            .any(|r| r.severity == "error" && r.message.contains("yarn.lock")));
+    }
+
+    #[test]
+    fn test_sort_by_severity_puts_errors_first() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join(".git")).unwrap();
+        fs::write(root.join("package.json"), r#"{"name": "public-pkg"}"#).unwrap();
+        fs::write(root.join("yarn.lock"), "# yarn lockfile v1").unwrap();
+        let path = root.to_str().unwrap();
+
+        let unsorted = Runner::new(Config::default()).run(path).unwrap();
+        let ranks = |report: &LintReport| -> Vec<u8> {
+            report.results.iter().map(|r| severity_rank(&r.severity)).collect()
+        };
+        // The default keeps rule order, which interleaves severities here
+        assert!(!ranks(&unsorted).is_sorted());
+
+        let config = Config::from_json(r#"{"sort": "severity"}"#).unwrap();
+        let sorted = Runner::new(config).run(path).unwrap();
+
+        assert!(ranks(&sorted).is_sorted());
+        assert_eq!(sorted.results.len(), unsorted.results.len());
+        assert!(sorted.results[0].severity == "error");
+        let rule_ids: HashSet<&str> = sorted
+            .results
+            .iter()
+            .take_while(|r| r.severity == "error")
+            .map(|r| r.rule_id.as_str())
+            .collect();
+        assert!(rule_ids.len() > 1, "errors from several rules: {:?}", rule_ids);
+    }
+
+    #[test]
+    fn test_suppress_drops_matching_messages_before_counting() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join(".git")).unwrap();
+        fs::write(root.join("package.json"), r#"{"name": "public-pkg"}"#).unwrap();
+        fs::write(root.join("yarn.lock"), "# yarn lockfile v1").unwrap();
+        let path = root.to_str().unwrap();
+
+        let full = Runner::new(Config::default()).run(path).unwrap();
+        let lockfile: Vec<_> = full
+            .results
+            .iter()
+            .filter(|r| r.message.contains("yarn.lock"))
+            .collect();
+        assert_eq!(lockfile.len(), 1);
+
+        let config = Config::from_json(r#"{"suppress": ["yarn\\.lock"]}"#).unwrap();
+        let report = Runner::new(config).run(path).unwrap();
+
+        assert!(!report.results.iter().any(|r| r.message.contains("yarn.lock")));
+        assert_eq!(report.results.len(), full.results.len() - 1);
+        assert_eq!(report.error_count, full.error_count - 1);
+    }
+
+    type CheckFn = Box<dyn Fn(&RuleContext) -> Vec<LintResult> + Send + Sync>;
+    type FixFn = Box<dyn Fn(&RuleContext) -> Result<u32, RuleError> + Send + Sync>;
+
+    /// Stand-in rule whose check and fix run the given closures
+    struct TestRule {
+        id: &'static str,
+        checks: Vec<crate::types::CheckEntry>,
+        fixes: Vec<crate::types::FixEntry>,
+        inputs: Vec<&'static str>,
+        check: CheckFn,
+        fix: Option<FixFn>,
+    }
+
+    impl TestRule {
+        /// A rule without checks or fixes that reports nothing
+        fn new(id: &'static str) -> Self {
+            Self {
+                id,
+                checks: Vec::new(),
+                fixes: Vec::new(),
+                inputs: Vec::new(),
+                check: Box::new(|_| Vec::new()),
+                fix: None,
+            }
+        }
+
+        fn with_check(
+            mut self,
+            check_id: &'static str,
+            check: impl Fn(&RuleContext) -> Vec<LintResult> + Send + Sync + 'static,
+        ) -> Self {
+            self.checks.push(crate::types::CheckEntry::new(check_id, "Test check"));
+            self.check = Box::new(check);
+            self
+        }
+
+        fn with_fix(
+            mut self,
+            fix_id: &'static str,
+            description: &'static str,
+            fix: impl Fn(&RuleContext) -> Result<u32, RuleError> + Send + Sync + 'static,
+        ) -> Self {
+            self.fixes.push(crate::types::FixEntry::new(fix_id, description, vec![]));
+            self.fix = Some(Box::new(fix));
+            self
+        }
+
+        fn with_inputs(mut self, inputs: Vec<&'static str>) -> Self {
+            self.inputs = inputs;
+            self
+        }
+    }
+
+    impl Rule for TestRule {
+        fn id(&self) -> &'static str {
+            self.id
+        }
+        fn name(&self) -> &'static str {
+            "Test Rule"
+        }
+        fn description(&self) -> &'static str {
+            "Runs closures given by a test"
+        }
+        fn default_severity(&self) -> Severity {
+            Severity::Warning
+        }
+        fn checks(&self) -> Vec<crate::types::CheckEntry> {
+            self.checks.clone()
+        }
+        fn fixes(&self) -> Vec<crate::types::FixEntry> {
+            self.fixes.clone()
+        }
+        fn inputs(&self) -> Vec<&'static str> {
+            self.inputs.clone()
+        }
+        fn check(&self, context: &RuleContext) -> Vec<LintResult> {
+            (self.check)(context)
+        }
+        fn fix(&self, context: &RuleContext) -> Result<u32, RuleError> {
+            self.fix.as_ref().map_or(Ok(0), |fix| fix(context))
+        }
+    }
+
+    /// Stand-in for husky-init that creates .husky without shelling out
+    fn create_husky_dir() -> TestRule {
+        TestRule::new("create-husky-dir").with_fix("create", "Create .husky", |context| {
+            let husky_dir = context.root.join(".husky");
+            if husky_dir.exists() {
+                return Ok(0);
+            }
+            fs::create_dir_all(husky_dir)?;
+            Ok(1)
+        })
+    }
+
+    /// Rule that writes a config, then fails before finishing the rest of its fix
+    fn fails_mid_fix() -> TestRule {
+        TestRule::new("fails-mid-fix").with_fix("migrate", "Migrate config", |context| {
+            context.write_file(&context.root.join("eslint.config.mjs"), "export default [];\n")?;
+            context.remove_file(&context.root.join(".eslintrc.json"))?;
+            Err(RuleError::Io(std::io::Error::other("No space left on device")))
+        })
+    }
+
+    /// Rule with checks but no fixes
+    fn report_only() -> TestRule {
+        TestRule::new("report-only").with_check("noted", |_| vec![])
+    }
+
+    /// Rule counting how often its check runs
+    fn counting_check(checks: &Arc<AtomicUsize>) -> TestRule {
+        let checks = checks.clone();
+        TestRule::new("counting-check").with_check("counted", move |_| {
+            checks.fetch_add(1, Ordering::SeqCst);
+            vec![]
+        })
+    }
+
+    #[test]
+    fn test_atomic_fix_discards_changes_of_a_failed_fix() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let path = root.to_str().unwrap();
+        let runner = |atomic_fix: bool| {
+            let mut registry = RuleRegistry::empty();
+            registry.register(Arc::new(fails_mid_fix()));
+            registry.register(Arc::new(report_only()));
+            let config = Config {
+                atomic_fix,
+                ..Config::default()
+            };
+            Runner::with_registry(config, registry)
+        };
+        fs::write(root.join(".eslintrc.json"), "{}").unwrap();
+
+        let report = runner(true).run_with_fix(path).unwrap();
+
+        assert_eq!(report.fix_errors.len(), 1);
+        assert_eq!(report.fix_errors[0].message, "IO error: No space left on device");
+        assert!(!root.join("eslint.config.mjs").exists());
+        assert_eq!(fs::read_to_string(root.join(".eslintrc.json")).unwrap(), "{}");
+        assert_eq!(fs::read_dir(root).unwrap().count(), 1);
+
+        // Without atomicFix the failed fix leaves its partial changes behind
+        runner(false).run_with_fix(path).unwrap();
+        assert!(root.join("eslint.config.mjs").exists());
+        assert!(!root.join(".eslintrc.json").exists());
+    }
+
+    #[test]
+    fn test_atomic_fix_commits_successful_fixes() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join(".git")).unwrap();
+        fs::create_dir_all(root.join(".husky")).unwrap();
+        fs::write(root.join(".husky/pre-commit"), "pnpm lint\n").unwrap();
+        fs::write(
+            root.join("package.json"),
+            r#"{"name": "app", "scripts": {"prepare": "husky", "test": "vitest"},
+                "devDependencies": {"husky": "^9.0.0"}}"#,
+        )
+        .unwrap();
+        let config = Config::from_json(
+            r#"{"atomicFix": true, "enabledTags": [], "rules": {"husky-init": {"enabled": true, "options": {"prePush": true}}}}"#,
+        )
+        .unwrap();
+
+        let report = Runner::new(config).run_with_fix(root.to_str().unwrap()).unwrap();
+
+        assert!(report.fix_errors.is_empty());
+        let pre_push = root.join(".husky/pre-push");
+        assert!(fs::read_to_string(&pre_push).unwrap().contains("pnpm test"));
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            assert_eq!(fs::metadata(&pre_push).unwrap().permissions().mode() & 0o111, 0o111);
+        }
+    }
+
+    #[test]
+    fn test_fix_errors_are_reported_and_later_fixes_still_run() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let mut registry = RuleRegistry::empty();
+        // Fails like a `pnpm add` that can't reach the registry
+        registry.register(Arc::new(TestRule::new("failing-fix").with_fix(
+            "install",
+            "Install a package",
+            |_| Err(RuleError::Io(std::io::Error::other("pnpm add failed"))),
+        )));
+        registry.register(Arc::new(create_husky_dir()));
+        let config = Config {
+            fix_iterations: Some(3),
+            ..Config::default()
+        };
+
+        let report = Runner::with_registry(config, registry)
+            .run_with_fix(root.to_str().unwrap())
+            .unwrap();
+
+        assert_eq!(report.fixed_count, 1);
+        assert!(root.join(".husky").is_dir());
+        assert_eq!(
+            report.fix_errors,
+            vec![FixError {
+                rule_id: "failing-fix".into(),
+                message: "IO error: pnpm add failed".into(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_catalog_lists_checks_and_fixes_of_all_rules() {
+        let runner = Runner::new(Config::default());
+
+        let checks = runner.all_check_ids();
+        let yarn_lock = checks
+            .iter()
+            .find(|c| c.rule_id == "pnpm-usage" && c.check_id == "yarn-lock-exists")
+            .unwrap();
+        assert!(!yarn_lock.description.is_empty());
+        // Disabled rules are part of the catalog too
+        assert!(checks.iter().any(|c| c.rule_id == "scripts-hygiene"));
+
+        let fixes = runner.all_fix_ids();
+        assert!(fixes.iter().any(|f| f.rule_id == "pnpm-usage"
+            && f.addresses.iter().any(|check| check == "yarn-lock-exists")));
+        assert!(fixes.iter().all(|fix| {
+            fix.addresses.iter().all(|check| {
+                checks.iter().any(|c| c.rule_id == fix.rule_id && c.check_id == *check)
+            })
+        }));
+    }
+
+    #[test]
+    fn test_list_fixable_rules_excludes_report_only_rules() {
+        let mut registry = RuleRegistry::empty();
+        registry.register(Arc::new(report_only()));
+        registry.register(Arc::new(create_husky_dir()));
+        let runner = Runner::with_registry(Config::default(), registry);
+
+        let ids: Vec<String> = runner.list_fixable_rules().into_iter().map(|r| r.id).collect();
+        assert_eq!(ids, vec!["create-husky-dir"]);
+        assert_eq!(runner.list_rules().len(), 2);
+
+        let builtin: Vec<String> = Runner::new(Config::default())
+            .list_fixable_rules()
+            .into_iter()
+            .map(|r| r.id)
+            .collect();
+        assert!(builtin.contains(&"husky-init".to_string()));
+        assert!(!builtin.contains(&"markdown-hygiene".to_string()));
+    }
+
+    #[test]
+    fn test_preview_fixed_files_returns_contents_without_writing() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let package_json =
+            r#"{"name": "app", "devDependencies": {"eslint-config-agent": "^1.0.0"}}"#;
+        fs::write(root.join("package.json"), package_json).unwrap();
+        fs::write(root.join(".eslintrc.json"), "{}").unwrap();
+        let config = Config::from_json(
+            r#"{"rules": {"eslint-config-agent": {"enabled": true}}, "enabledTags": []}"#,
+        )
+        .unwrap();
+        let path = root.to_str().unwrap();
+
+        let previews = Runner::new(config).preview_fixed_files(path).unwrap();
+
+        let expected_config = "import config from \"eslint-config-agent\";\n\nexport default config;\n";
+
+        assert_eq!(
+            previews,
+            vec![
+                FileContent {
+                    path: root.join(".eslintrc.json").display().to_string(),
+                    content: String::new(),
+                    deleted: true,
+                },
+                FileContent {
+                    path: root.join("eslint.config.mjs").display().to_string(),
+                    content: expected_config.into(),
+                    deleted: false,
+                },
+            ]
+        );
+        // Nothing was written to disk
+        assert!(root.join(".eslintrc.json").exists());
+        assert!(!root.join("eslint.config.mjs").exists());
+        assert_eq!(fs::read_to_string(root.join("package.json")).unwrap(), package_json);
+    }
+
+    #[test]
+    fn test_plan_fix_combines_appends_to_a_new_gitignore() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join(".git")).unwrap();
+        fs::create_dir_all(root.join(".claude")).unwrap();
+        fs::write(root.join(".claude/settings.local.json"), "{}").unwrap();
+        let config = Config::from_json(
+            r#"{"enabledTags": [], "rules": {"claude-settings-hooks": {"enabled": true},
+                "gitignore-hygiene": {"enabled": true}}}"#,
+        )
+        .unwrap();
+
+        let changes = Runner::new(config).plan_fix(root.to_str().unwrap()).unwrap();
+
+        let gitignore: Vec<_> =
+            changes.iter().filter(|c| c.path.ends_with(".gitignore")).collect();
+        assert_eq!(gitignore.len(), 1);
+        assert_eq!(gitignore[0].original, None);
+        // Each rule's append builds on the other's buffered content
+        assert!(gitignore[0].content.lines().any(|line| line == ".claude/settings.local.json"));
+        assert!(gitignore[0].content.lines().any(|line| line == ".DS_Store"));
+    }
+
+    #[test]
+    fn test_apply_change_writes_one_planned_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(
+            root.join("package.json"),
+            r#"{"name": "app", "devDependencies": {"eslint-config-agent": "^1.0.0"}}"#,
+        )
+        .unwrap();
+        fs::write(root.join(".eslintrc.json"), "{}").unwrap();
+        let config = Config::from_json(
+            r#"{"rules": {"eslint-config-agent": {"enabled": true}}, "enabledTags": []}"#,
+        )
+        .unwrap();
+        let runner = Runner::new(config);
+
+        let changes = runner.plan_fix(root.to_str().unwrap()).unwrap();
+        assert_eq!(changes.len(), 2);
+        let removal = &changes[0];
+        let write = &changes[1];
+        assert!(removal.deleted);
+        assert_eq!(removal.original.as_deref(), Some("{}"));
+        assert_eq!(write.path, root.join("eslint.config.mjs").display().to_string());
+        assert_eq!(write.original, None);
+
+        runner.apply_change(write).unwrap();
+
+        // Only the selected change was applied
+        assert_eq!(fs::read_to_string(root.join("eslint.config.mjs")).unwrap(), write.content);
+        assert!(root.join(".eslintrc.json").exists());
+
+        // Applying again conflicts: the file now exists where none was planned
+        assert!(matches!(runner.apply_change(write), Err(EngineError::ChangeConflict(_))));
+        fs::write(root.join(".eslintrc.json"), r#"{"root": true}"#).unwrap();
+        assert!(matches!(runner.apply_change(removal), Err(EngineError::ChangeConflict(_))));
+        assert!(root.join(".eslintrc.json").exists());
+    }
+
+    #[test]
+    fn test_scan_workspace_lists_what_rules_see() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join(".git")).unwrap();
+        fs::write(root.join("pnpm-workspace.yaml"), "packages:\n  - 'packages/*'\n").unwrap();
+        fs::write(root.join("package.json"), r#"{"name": "monorepo", "private": true}"#).unwrap();
+        for dir in ["packages/core", "node_modules/lodash", "packages/core/fixtures/app"] {
+            fs::create_dir_all(root.join(dir)).unwrap();
+            fs::write(root.join(dir).join("package.json"), r#"{"name": "x"}"#).unwrap();
+        }
+        let path = root.canonicalize().unwrap();
+
+        let report = Runner::new(Config::default())
+            .scan_workspace(path.to_str().unwrap())
+            .unwrap();
+
+        // node_modules and fixture packages are left out
+        assert_eq!(
+            report.package_jsons,
+            vec![
+                path.join("package.json").display().to_string(),
+                path.join("packages/core/package.json").display().to_string(),
+            ]
+        );
+        assert_eq!(report.git_repos, vec![path.display().to_string()]);
+        assert_eq!(report.workspace_root, Some(path.display().to_string()));
+        assert!(Runner::new(Config::default()).scan_workspace("/nonexistent/path").is_err());
+    }
+
+    #[test]
+    fn test_scan_stats_counts_walked_entries() {
+        // No root package.json, so the scan takes the full walk through .git and
+        // node_modules
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join(".git/refs")).unwrap();
+        fs::write(root.join(".git/HEAD"), "ref: refs/heads/main\n").unwrap();
+        fs::create_dir_all(root.join("packages/web/src")).unwrap();
+        fs::write(root.join("packages/web/package.json"), r#"{"name": "web"}"#).unwrap();
+        fs::write(root.join("packages/web/src/index.ts"), "export {};\n").unwrap();
+        fs::create_dir_all(root.join("node_modules/dep")).unwrap();
+        fs::write(root.join("node_modules/dep/package.json"), "{}").unwrap();
+
+        let stats = Runner::new(Config::default())
+            .scan_stats(root.to_str().unwrap())
+            .unwrap();
+
+        assert_eq!(stats.directories_visited, 8);
+        assert_eq!(stats.files_examined, 4);
+        assert_eq!(stats.package_jsons, 1);
+        assert_eq!(stats.git_repos, 1);
+        assert!(Runner::new(Config::default()).scan_stats("/nonexistent/path").is_err());
+    }
+
+    #[test]
+    fn test_summarize_fixes_groups_results_by_fix() {
+        let mut registry = RuleRegistry::empty();
+        registry.register(Arc::new(report_only()));
+        registry.register(Arc::new(create_husky_dir()));
+        let runner = Runner::with_registry(Config::default(), registry);
+        let result = |rule_id: &str, path: &str, fixable_by: Vec<&str>| {
+            LintResult::new(
+                rule_id,
+                "check",
+                Severity::Warning,
+                "message".into(),
+                PathBuf::from(path),
+                None,
+                None,
+                fixable_by,
+            )
+        };
+        let report = LintReport::new(
+            vec![
+                result("create-husky-dir", "/repo/.husky", vec!["create"]),
+                result("report-only", "/repo/README.md", vec![]),
+                result("create-husky-dir", "/repo/.husky", vec!["create"]),
+                result("create-husky-dir", "/repo/pkg/.husky", vec!["create", "unknown"]),
+            ],
+            0,
+        );
+
+        let summaries = runner.summarize_fixes(&report);
+
+        assert_eq!(summaries.len(), 1);
+        assert_eq!(summaries[0].fix_id, "create");
+        assert_eq!(summaries[0].rule_id, "create-husky-dir");
+        assert_eq!(summaries[0].description, "Create .husky");
+        assert_eq!(summaries[0].affected_paths, vec!["/repo/.husky", "/repo/pkg/.husky"]);
+        assert_eq!(summaries[0].resolves_count, 3);
+    }
+
+    #[test]
+    fn test_fix_on_compliant_repo_reports_nothing_to_fix() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join(".git")).unwrap();
+        fs::write(root.join(".gitignore"), ".DS_Store\nThumbs.db\n*.log\n*.swp\n").unwrap();
+        let path = root.to_str().unwrap();
+        let runner = || {
+            let mut registry = RuleRegistry::empty();
+            registry.register(Arc::new(
+                crate::rules::gitignore_hygiene::GitignoreHygieneRule::new(),
+            ));
+            let config =
+                Config::from_json(r#"{"rules": {"gitignore-hygiene": {"enabled": true}}}"#)
+                    .unwrap();
+            Runner::with_registry(config, registry)
+        };
+
+        let report = runner().run_with_fix(path).unwrap();
+        assert!(report.nothing_to_fix);
+        assert_eq!(report.fixed_count, 0);
+        assert!(!runner().run(path).unwrap().nothing_to_fix);
+
+        fs::write(root.join(".gitignore"), "node_modules\n").unwrap();
+        let report = runner().run_with_fix(path).unwrap();
+        assert!(!report.nothing_to_fix);
+        assert_eq!(report.fixed_count, 1);
+    }
+
+    #[test]
+    fn test_run_cached_reuses_results_of_unchanged_rules() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(root.join("package.json"), r#"{"name": "app"}"#).unwrap();
+        let path = root.to_str().unwrap();
+        let cached_checks = Arc::new(AtomicUsize::new(0));
+        let uncached_checks = Arc::new(AtomicUsize::new(0));
+        let mut registry = RuleRegistry::empty();
+        let counter = cached_checks.clone();
+        let package_json_counter = TestRule::new("package-json-counter")
+            .with_inputs(vec!["**/package.json"])
+            .with_check("counted", move |context| {
+                let run = counter.fetch_add(1, Ordering::SeqCst) + 1;
+                vec![LintResult::new(
+                    "package-json-counter",
+                    "counted",
+                    Severity::Info,
+                    format!("Check run {}", run),
+                    context.root.join("package.json"),
+                    None,
+                    None,
+                    vec![],
+                )]
+            });
+        registry.register(Arc::new(package_json_counter));
+        registry.register(Arc::new(counting_check(&uncached_checks)));
+        let runner = Runner::with_registry(Config::default(), registry);
+
+        let first = runner.run_cached(path).unwrap();
+        // Files outside the rule's inputs don't invalidate it
+        fs::write(root.join("README.md"), "# App\n").unwrap();
+        let second = runner.run_cached(path).unwrap();
+
+        assert_eq!(cached_checks.load(Ordering::SeqCst), 1);
+        assert_eq!(uncached_checks.load(Ordering::SeqCst), 2);
+        assert_eq!(second.results.len(), first.results.len());
+        assert_eq!(second.results[0].fingerprint, first.results[0].fingerprint);
+        assert_eq!(second.results[0].message, "Check run 1");
+
+        fs::write(root.join("package.json"), r#"{"name": "renamed"}"#).unwrap();
+        let third = runner.run_cached(path).unwrap();
+        assert_eq!(cached_checks.load(Ordering::SeqCst), 2);
+        assert_eq!(third.results[0].message, "Check run 2");
+
+        // Plain runs neither use nor fill the cache
+        runner.run(path).unwrap();
+        assert_eq!(cached_checks.load(Ordering::SeqCst), 3);
+        runner.run_cached(path).unwrap();
+        assert_eq!(cached_checks.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_skip_check_in_fix_mode_only_runs_the_post_fix_check() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().to_str().unwrap();
+        let checks_with = |skip_check_in_fix_mode: bool| {
+            let checks = Arc::new(AtomicUsize::new(0));
+            let mut registry = RuleRegistry::empty();
+            registry.register(Arc::new(counting_check(&checks)));
+            registry.register(Arc::new(create_husky_dir()));
+            let config = Config {
+                skip_check_in_fix_mode,
+                ..Config::default()
+            };
+            let report = Runner::with_registry(config, registry).run_with_fix(path).unwrap();
+            (checks.load(Ordering::SeqCst), report)
+        };
+
+        let (checks, report) = checks_with(true);
+        assert_eq!(checks, 1);
+        assert_eq!(report.fixed_count, 1);
+        assert!(!report.nothing_to_fix);
+
+        let (checks, _) = checks_with(false);
+        assert_eq!(checks, 2);
+    }
+
+    #[test]
+    fn test_fix_iterations_resolve_cross_rule_dependencies() {
+        // cspell-config is registered before the rule that creates .husky, so its
+        // pre-commit fix can only succeed on a later pass
+        let runner_with = |fix_iterations: Option<u32>| {
+            let mut registry = RuleRegistry::empty();
+            registry.register(std::sync::Arc::new(
+                crate::rules::cspell_config::CspellConfigRule::new(),
+            ));
+            registry.register(std::sync::Arc::new(create_husky_dir()));
+            Runner::with_registry(
+                Config {
+                    fix_iterations,
+                    ..Config::default()
+                },
+                registry,
+            )
+        };
+        let setup = || {
+            let temp_dir = TempDir::new().unwrap();
+            fs::write(
+                temp_dir.path().join("package.json"),
+                r#"{"name": "test", "devDependencies": {"cspell": "^8.0.0"}}"#,
+            )
+            .unwrap();
+            temp_dir
+        };
+
+        let single = setup();
+        runner_with(None)
+            .run_with_fix(single.path().to_str().unwrap())
+            .unwrap();
+        assert!(single.path().join(".husky").exists());
+        assert!(!single.path().join(".husky/pre-commit").exists());
+
+        let looped = setup();
+        let report = runner_with(Some(3))
+            .run_with_fix(looped.path().to_str().unwrap())
+            .unwrap();
+        let hook = fs::read_to_string(looped.path().join(".husky/pre-commit")).unwrap();
+        assert!(hook.contains("cspell"));
+        assert!(report.results.is_empty());
+        // cspell.json + .husky on the first pass, the hook on the second
+        assert_eq!(report.fixed_count, 3);
+    }
+
+    #[test]
+    fn test_scan_reads_each_package_json_once() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join(".git")).unwrap();
+        fs::write(
+            root.join("package.json"),
+            r#"{"name": "root", "scripts": {"build": "tsc"}, "devDependencies": {"typescript": "^5.0.0"}}"#,
+        )
+        .unwrap();
+        for name in ["a", "b", "c"] {
+            let dir = root.join("packages").join(name);
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(
+                dir.join("package.json"),
+                r#"{"name": "pkg", "scripts": {"typecheck": "tsc --noEmit"}}"#,
+            )
+            .unwrap();
+        }
+        let reads = || crate::scan::PACKAGE_JSON_READS.with(|r| r.get());
+
+        // Without the cache every rule reads package.json files on its own
+        let before = reads();
+        for rule in RuleRegistry::new().all() {
+            rule.check(&RuleContext::new(root.to_path_buf(), serde_json::Value::Null));
+        }
+        let uncached = reads() - before;
+
+        let before = reads();
+        Runner::new(Config::default())
+            .run(root.to_str().unwrap())
+            .unwrap();
+        let cached = reads() - before;
+
+        assert_eq!(cached, 4);
+        assert!(uncached > cached, "uncached {} vs cached {}", uncached, cached);
+    }
+
+    #[test]
+    fn test_invalid_package_json_reported_once() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(root.join("package.json"), r#"{"name": "#).unwrap();
+
+        let report = Runner::new(Config::default())
+            .run(root.to_str().unwrap())
+            .unwrap();
+        let invalid: Vec<_> = report
+            .results
+            .iter()
+            .filter(|r| r.message.contains("Invalid JSON in package.json"))
+            .collect();
+
+        assert_eq!(invalid.len(), 1);
+        assert_eq!(invalid[0].rule_id, SCAN_RULE_ID);
+        assert_eq!(invalid[0].severity, "error");
+    }
+
+    #[test]
+    fn test_prepared_root_matches_normal_run() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join(".git")).unwrap();
+        fs::write(root.join("package.json"), r#"{"name": "public-pkg"}"#).unwrap();
+        let path = root.to_str().unwrap();
+        let runner = Runner::new(Config::default());
+
+        let prepared = PreparedRoot::new(path).unwrap();
+        let expected = serde_json::to_value(runner.run(path).unwrap()).unwrap();
+        for _ in 0..2 {
+            let report = serde_json::to_value(runner.run_prepared(&prepared)).unwrap();
+            assert_eq!(report, expected);
+        }
+
+        assert!(matches!(
+            PreparedRoot::new(&format!("{}/missing", path)),
+            Err(EngineError::PathNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_fix_rule_applies_only_that_rules_fixes() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(
+            root.join("package.json"),
+            r#"{"name": "test", "packageManager": "yarn@1.22.0"}"#,
+        )
+        .unwrap();
+        fs::write(root.join("yarn.lock"), "# yarn lockfile v1").unwrap();
+        fs::write(root.join(".eslintrc.json"), "{}").unwrap();
+        let path = root.to_str().unwrap();
+
+        let runner = Runner::new(Config::default());
+        let report = runner.run_rule_fix(path, "pnpm-usage").unwrap();
+
+        assert!(report.fixed_count >= 2);
+        assert!(!root.join("yarn.lock").exists());
+        assert!(fs::read_to_string(root.join("package.json"))
+            .unwrap()
+            .contains("pnpm@"));
+        assert!(report.results.iter().all(|r| r.rule_id == "pnpm-usage"));
+
+        // eslint-config-agent fixes would remove the legacy config and create a new one
+        assert!(root.join(".eslintrc.json").exists());
+        assert!(!root.join("eslint.config.mjs").exists());
+    }
+
+    #[test]
+    fn test_fix_rule_reports_like_fix_restricted_to_the_rule() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join("packages/broken")).unwrap();
+        fs::write(root.join("packages/broken/package.json"), "{ not json").unwrap();
+        fs::write(root.join(".eslintrc.json"), "{}").unwrap();
+        let mut registry = RuleRegistry::empty();
+        registry.register(Arc::new(fails_mid_fix()));
+        let config = Config {
+            atomic_fix: true,
+            ..Config::default()
+        };
+        let runner = Runner::with_registry(config, registry);
+
+        let report = runner.run_rule_fix(root.to_str().unwrap(), "fails-mid-fix").unwrap();
+
+        assert_eq!(report.fix_errors.len(), 1);
+        assert!(!root.join("eslint.config.mjs").exists());
+        assert!(root.join(".eslintrc.json").exists());
+        assert_eq!(report.results.len(), 1);
+        assert_eq!(report.results[0].rule_id, SCAN_RULE_ID);
+        assert!(report.results[0].path.ends_with("packages/broken/package.json"));
+    }
+
+    #[test]
+    fn test_fix_rule_rejects_unknown_and_unfixable_rules() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().to_str().unwrap();
+        let runner = Runner::new(Config::default());
+
+        assert!(matches!(
+            runner.run_rule_fix(path, "no-such-rule"),
+            Err(EngineError::UnknownRule(_))
+        ));
+        assert!(matches!(
+            runner.run_rule_fix(path, "dockerfile-pinned-base"),
+            Err(EngineError::RuleNotFixable(_))
+        ));
+    }
+
+    #[test]
+    fn test_strict_preset_enables_everything_and_escalates() {
+        let json = serde_json::to_string(&preset_config("strict").unwrap()).unwrap();
+        assert!(json.contains(r#""warningsAsErrors":true"#));
+        let config = Config::from_json(&json).unwrap();
+        assert!(config.warnings_as_errors);
+        assert!(config.promote_info);
+
+        let runner = Runner::new(config);
+        for rule in runner.list_rules() {
+            assert!(runner.config.rules[&rule.id].enabled, "{}", rule.id);
+        }
+
+        assert!(preset_config("recommended").unwrap().rules.is_empty());
+        assert!(matches!(
+            preset_config("lenient"),
+            Err(EngineError::UnknownPreset(_))
+        ));
+    }
+
+    #[test]
+    fn test_warnings_as_errors_escalates_severities() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join(".git")).unwrap();
+        fs::write(root.join("package.json"), r#"{"name": "public-pkg"}"#).unwrap();
+        let path = root.to_str().unwrap();
+
+        let report = Runner::new(Config::default()).run(path).unwrap();
+        assert!(report.warning_count > 0 && report.info_count > 0);
+
+        let config = Config {
+            warnings_as_errors: true,
+            ..Config::default()
+        };
+        let report = Runner::new(config).run(path).unwrap();
+        assert_eq!(report.warning_count, 0);
+        assert!(report.info_count > 0);
+
+        let config = Config {
+            promote_info: true,
+            warnings_as_errors: true,
+            ..Config::default()
+        };
+        let report = Runner::new(config).run(path).unwrap();
+        assert_eq!(report.warning_count + report.info_count, 0);
+        assert_eq!(report.error_count as usize, report.results.len());
+    }
+
+    #[test]
+    fn test_rules_share_detected_workspace_info() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(
+            root.join("package.json"),
+            r#"{"name": "test", "devDependencies": {"husky": "^8.0.3"}}"#,
+        )
+        .unwrap();
+        let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut registry = RuleRegistry::empty();
+        for id in ["first-probe", "second-probe"] {
+            let seen = seen.clone();
+            registry.register(Arc::new(TestRule::new(id).with_check("probe", move |context| {
+                seen.lock().unwrap().push(context.workspace());
+                vec![]
+            })));
+        }
+
+        Runner::with_registry(Config::default(), registry)
+            .run(root.to_str().unwrap())
+            .unwrap();
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.len(), 2);
+        assert!(Arc::ptr_eq(&seen[0], &seen[1]));
+        assert_eq!(seen[0].husky_version(root), Some(8));
+    }
+
+    #[test]
+    fn test_load_enabled_rules_only_keeps_listing_and_results() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join(".git")).unwrap();
+        fs::write(root.join("package.json"), r#"{"name": "public-pkg"}"#).unwrap();
+        let path = root.to_str().unwrap();
+
+        let eager = Runner::new(Config::default());
+        let lazy = Runner::new(Config {
+            load_enabled_rules_only: true,
+            ..Config::default()
+        });
+
+        assert_eq!(lazy.rule_order(), eager.rule_order());
+        assert_eq!(lazy.list_rules().len(), eager.list_rules().len());
+        assert!(lazy.describe_check("todo-budget", "todo-budget-exceeded").is_some());
+        assert_eq!(
+            serde_json::to_value(lazy.run(path).unwrap()).unwrap(),
+            serde_json::to_value(eager.run(path).unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_enabled_tags_runs_only_tagged_rules() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join(".git")).unwrap();
+        fs::write(root.join("package.json"), r#"{"name": "public-pkg"}"#).unwrap();
+        fs::write(root.join("yarn.lock"), "").unwrap();
+        let path = root.to_str().unwrap();
+
+        let config = Config::from_json(
+            r#"{"enabledTags": ["package-manager"], "rules": {"husky-init": {"enabled": true}}}"#,
+        )
+        .unwrap();
+        let runner = Runner::new(config);
+        let report = runner.run(path).unwrap();
+
+        let tagged: Vec<String> = runner
+            .list_rules()
+            .into_iter()
+            .filter(|r| r.tags.iter().any(|t| t == "package-manager"))
+            .map(|r| r.id)
+            .collect();
+        assert!(tagged.contains(&"pnpm-usage".to_string()));
+        assert!(report.results.iter().any(|r| r.rule_id == "pnpm-usage"));
+        // Untagged rules are skipped unless the config enables them by id
+        assert!(report.results.iter().any(|r| r.rule_id == "husky-init"));
+        assert!(report
+            .results
+            .iter()
+            .all(|r| r.rule_id == "husky-init" || tagged.contains(&r.rule_id)));
+        let full = Runner::new(Config::default()).run(path).unwrap();
+        assert!(full.results.iter().any(|r| r.rule_id == "claude-settings-hooks"));
+        assert!(!report.results.iter().any(|r| r.rule_id == "claude-settings-hooks"));
+    }
+
+    #[test]
+    fn test_fail_fast_stops_after_first_error() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        // No .claude/settings.json: claude-settings-hooks, the first rule, reports an error
+        fs::create_dir_all(root.join(".git")).unwrap();
+        fs::write(root.join("package.json"), r#"{"name": "public-pkg"}"#).unwrap();
+        let path = root.to_str().unwrap();
+
+        let full = Runner::new(Config::default()).run(path).unwrap();
+        assert!(!full.fail_fast_triggered);
+
+        let config = Config {
+            fail_fast: true,
+            ..Config::default()
+        };
+        let report = Runner::new(config).run(path).unwrap();
+
+        assert!(report.fail_fast_triggered);
+        assert!(report.error_count > 0);
+        assert!(report.results.len() < full.results.len());
+        assert!(report.results.iter().all(|r| r.rule_id == "claude-settings-hooks"));
+    }
+
+    #[test]
+    fn test_fail_fast_ignores_suppressed_errors() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().to_str().unwrap();
+        let checks = Arc::new(AtomicUsize::new(0));
+        let mut registry = RuleRegistry::empty();
+        registry.register(Arc::new(TestRule::new("known-issue").with_check("known", |context| {
+            vec![LintResult::new(
+                "known-issue",
+                "known",
+                Severity::Error,
+                "Known legacy issue".into(),
+                context.root.clone(),
+                None,
+                None,
+                vec![],
+            )]
+        })));
+        registry.register(Arc::new(counting_check(&checks)));
+        let config = Config {
+            fail_fast: true,
+            ..Config::from_json(r#"{"suppress": ["^Known legacy"]}"#).unwrap()
+        };
+
+        let report = Runner::with_registry(config, registry).run(path).unwrap();
+
+        assert!(!report.fail_fast_triggered);
+        assert!(report.results.is_empty());
+        assert_eq!(checks.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_max_results_truncates_but_keeps_totals() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join(".git")).unwrap();
+        fs::write(root.join("package.json"), r#"{"name": "public-pkg"}"#).unwrap();
+        let path = root.to_str().unwrap();
+
+        let full = Runner::new(Config::default()).run(path).unwrap();
+        assert!(full.results.len() > 2);
+        assert!(!full.truncated);
+
+        let config = Config {
+            max_results: Some(2),
+            ..Config::default()
+        };
+        let report = Runner::new(config).run(path).unwrap();
+
+        assert!(report.truncated);
+        assert_eq!(report.results.len(), 2);
+        assert_eq!(report.total_before_truncation as usize, full.results.len());
+        assert_eq!(
+            report.error_count + report.warning_count + report.info_count,
+            report.total_before_truncation
+        );
+        assert_eq!(report.error_count, full.error_count);
+    }
+
+    #[test]
+    fn test_panicking_rule_is_reported_and_others_still_run() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(root.join("package.json"), r#"{"name": "test"}"#).unwrap();
+        fs::write(root.join("yarn.lock"), "# yarn lockfile v1").unwrap();
+        let path = root.to_str().unwrap();
+
+        let mut registry = RuleRegistry::empty();
+        let panicking = TestRule::new("panicking")
+            .with_check("noop", |_| panic!("malformed input in check"))
+            .with_fix("noop", "Does nothing", |_| panic!("{} in fix", "malformed input"));
+        registry.register(Arc::new(panicking));
+        registry.register(Arc::new(crate::rules::pnpm_usage::PnpmUsageRule::new()));
+        let runner = Runner::with_registry(Config::default(), registry);
+
+        let report = runner.run(path).unwrap();
+        let panicked: Vec<_> = report
+            .results
+            .iter()
+            .filter(|r| r.check_id == CHECK_RULE_PANICKED)
+            .collect();
+        assert_eq!(panicked.len(), 1);
+        assert_eq!(panicked[0].rule_id, "panicking");
+        assert_eq!(panicked[0].severity, "error");
+        assert_eq!(
+            panicked[0].message,
+            "Rule 'panicking' panicked during check: malformed input in check"
+        );
+        assert!(report.results.iter().any(|r| r.message.contains("yarn.lock")));
+
+        let report = runner.run_with_fix(path).unwrap();
+        assert!(report
+            .results
+            .iter()
+            .any(|r| r.message == "Rule 'panicking' panicked during fix: malformed input in fix"));
+        assert!(!root.join("yarn.lock").exists());
+    }
+
+    #[test]
+    fn test_written_baseline_suppresses_unchanged_results() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().join("repo");
+        fs::create_dir_all(&root).unwrap();
+        fs::write(root.join("package.json"), r#"{"name": "test"}"#).unwrap();
+        fs::write(root.join("yarn.lock"), "# yarn lockfile v1").unwrap();
+        let path = root.to_str().unwrap();
+        let baseline_path = temp_dir.path().join("lineup-baseline.txt");
+        let baseline_path = baseline_path.to_str().unwrap();
+
+        let runner = Runner::new(Config::default());
+        let written = runner.write_baseline(path, baseline_path).unwrap();
+        assert!(written > 0);
+        assert_eq!(
+            fs::read_to_string(baseline_path).unwrap().lines().count(),
+            written as usize
+        );
+
+        let report = runner.run_with_baseline_file(path, baseline_path).unwrap();
+        assert!(report.results.is_empty());
+    }
+
+    #[test]
+    fn test_fixture_package_jsons_are_excluded_by_default() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(
+            root.join("package.json"),
+            r#"{"name": "test", "packageManager": "pnpm@9.1.0"}"#,
+        )
+        .unwrap();
+        fs::write(root.join("pnpm-lock.yaml"), "lockfileVersion: '9.0'\n").unwrap();
+        let fixture = root.join("src/__tests__/fixtures/yarn-project");
+        fs::create_dir_all(&fixture).unwrap();
+        fs::write(fixture.join("package.json"), "{ not json").unwrap();
+        let example = root.join("examples/basic");
+        fs::create_dir_all(&example).unwrap();
+        fs::write(example.join("package.json"), r#"{"name": "basic"}"#).unwrap();
+        fs::write(example.join("yarn.lock"), "# yarn lockfile v1").unwrap();
+        let path = root.to_str().unwrap();
+
+        let mentions_fixtures = |report: &LintReport| {
+            report
+                .results
+                .iter()
+                .any(|r| r.path.contains("__tests__") || r.path.contains("examples"))
+        };
+
+        let report = Runner::new(Config::default()).run(path).unwrap();
+        assert!(!mentions_fixtures(&report));
+
+        // Overriding the list lets a real package under examples/ be linted
+        let config = Config::from_json(r#"{"fixtureDirs": ["__tests__"]}"#).unwrap();
+        let report = Runner::new(config).run(path).unwrap();
+        assert!(report.results.iter().any(|r| r.path.ends_with("examples/basic/yarn.lock")));
+        assert!(!report.results.iter().any(|r| r.path.contains("__tests__")));
+
+        let config = Config::from_json(r#"{"excludeFixtures": false}"#).unwrap();
+        let report = Runner::new(config).run(path).unwrap();
+        assert!(report
+            .results
+            .iter()
+            .any(|r| r.rule_id == SCAN_RULE_ID && r.path.contains("__tests__")));
+    }
+
+    #[test]
+    fn test_annotate_runnable_fixes() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join(".git")).unwrap();
+        fs::write(
+            root.join("package.json"),
+            r#"{"name": "test", "scripts": {"build": "node build.js"}}"#,
+        )
+        .unwrap();
+        fs::write(root.join("yarn.lock"), "# yarn lockfile v1").unwrap();
+        let path = root.to_str().unwrap();
+
+        let report = Runner::new(Config::default()).run(path).unwrap();
+        assert!(report.results.iter().all(|r| r.fix_runnable.is_none()));
+
+        let config = Config {
+            annotate_runnable_fixes: true,
+            ..Config::default()
+        };
+        let report = Runner::new(config).run(path).unwrap();
+        for result in &report.results {
+            assert_eq!(result.fix_runnable.is_some(), !result.fixable_by.is_empty());
+        }
+
+        // Deleting a lockfile needs no tools
+        let yarn_lock = report
+            .results
+            .iter()
+            .find(|r| r.path.ends_with("yarn.lock"))
+            .unwrap();
+        assert_eq!(yarn_lock.fix_runnable, Some(true));
+
+        // Installing eslint-config-agent needs pnpm, whichever way CI has it
+        let install = report
+            .results
+            .iter()
+            .find(|r| r.fixable_by == ["install-eslint-config-agent"])
+            .unwrap();
+        assert_eq!(install.fix_runnable, Some(on_path("pnpm")));
+    }
+
+    #[test]
+    fn test_offline_mode_drops_fixes_that_need_commands() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join(".git")).unwrap();
+        fs::write(
+            root.join("package.json"),
+            r#"{"name": "test", "scripts": {"build": "node build.js"}}"#,
+        )
+        .unwrap();
+        fs::write(root.join("yarn.lock"), "# yarn lockfile v1").unwrap();
+        let config = Config {
+            offline: true,
+            ..Config::default()
+        };
+
+        let report = Runner::new(config).run(root.to_str().unwrap()).unwrap();
+
+        let install = report
+            .results
+            .iter()
+            .find(|r| r.check_id == "eslint-config-agent-dependency")
+            .unwrap();
+        assert!(install.fixable_by.is_empty());
+        assert_eq!(
+            install.suggestion.as_deref(),
+            Some(
+                "Manual action needed: the fix runs external commands, which offline mode \
+                 doesn't allow. Install eslint-config-agent using \
+                 'pnpm add -D eslint-config-agent@latest'"
+            )
+        );
+        // File-only fixes stay available
+        let yarn_lock = report
+            .results
+            .iter()
+            .find(|r| r.path.ends_with("yarn.lock"))
+            .unwrap();
+        assert_eq!(yarn_lock.fixable_by, vec!["remove-yarn-lock"]);
+    }
+
+    #[test]
+    fn test_offline_mode_skips_optional_commands_of_fixes() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(root.join("package.json"), r#"{"name": "app", "version": "1.0.0"}"#).unwrap();
+        fs::write(root.join("yarn.lock"), "# yarn lockfile v1").unwrap();
+        let config = Config::from_json(
+            r#"{"offline": true, "enabledTags": [], "rules": {
+                "pnpm-usage": {"enabled": true, "options": {"allowCommands": true}},
+                "publish-hygiene": {"enabled": true, "options": {"allowCommands": true}}}}"#,
+        )
+        .unwrap();
+        let runner = Runner::new(config);
+        let path = root.to_str().unwrap();
+
+        let report = runner.run(path).unwrap();
+        let yarn_lock = report.results.iter().find(|r| r.path.ends_with("yarn.lock")).unwrap();
+        assert_eq!(yarn_lock.fixable_by, vec!["remove-yarn-lock"]);
+        let repository =
+            report.results.iter().find(|r| r.check_id == "repository-field").unwrap();
+        assert!(repository.fixable_by.is_empty());
+
+        let report = runner.run_with_fix(path).unwrap();
+        assert!(report.fix_errors.is_empty());
+        assert!(!root.join("yarn.lock").exists());
+    }
+
+    #[test]
+    fn test_offline_fix_of_command_only_fixes_reports_nothing_to_fix() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join(".git")).unwrap();
+        fs::write(root.join("package.json"), r#"{"name": "app", "scripts": {}}"#).unwrap();
+        let runner = |offline: bool| {
+            let mut registry = RuleRegistry::empty();
+            registry.register(Arc::new(crate::rules::husky_init::HuskyInitRule::new()));
+            Runner::with_registry(
+                Config {
+                    offline,
+                    ..Config::default()
+                },
+                registry,
+            )
+        };
+        let path = root.to_str().unwrap();
+
+        // Initializing husky runs pnpm, which offline mode doesn't allow
+        let report = runner(true).run_with_fix(path).unwrap();
+        assert!(report.nothing_to_fix);
+        assert_eq!(report.fixed_count, 0);
+        assert!(!root.join(".husky").exists());
+
+        let report = runner(false).run(path).unwrap();
+        assert!(report.results.iter().any(|r| r.fixable_by == vec!["init-husky-js"]));
+    }
+
+    #[test]
+    fn test_rule_order_runs_husky_init_before_cspell() {
+        let order = Runner::new(Config::default()).rule_order();
+        let position = |id: &str| order.iter().position(|r| r == id).unwrap();
+
+        assert!(position("husky-init") < position("cspell-config"));
+        assert_eq!(order.last().map(String::as_str), Some("json-format"));
+    }
+
+    #[test]
+    fn test_strip_prefix_removes_configured_prefix() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join(".git")).unwrap();
+        fs::write(root.join("package.json"), r#"{"name": "public-pkg"}"#).unwrap();
+        fs::write(root.join("yarn.lock"), "# yarn lockfile v1").unwrap();
+        let path = root.to_str().unwrap();
+
+        let full = Runner::new(Config::default()).run(path).unwrap();
+        let prefix = format!("{}/", path);
+        let config = Config {
+            strip_prefix: Some(prefix.clone()),
+            ..Config::default()
+        };
+        let report = Runner::new(config).run(path).unwrap();
+
+        assert_eq!(report.results.len(), full.results.len());
+        assert!(report.results.iter().any(|r| r.path == "package.json"));
+        assert!(report.results.iter().all(|r| !r.path.starts_with(&prefix)));
+        // Fingerprints are computed before stripping, so baselines still match
+        for (stripped, original) in report.results.iter().zip(&full.results) {
+            assert_eq!(stripped.fingerprint, original.fingerprint);
+        }
+    }
+
+    #[test]
+    fn test_redact_home_replaces_home_prefix() {
+        let make = |path: &str| {
+            LintResult::new(
+                "rule",
+                "check",
+                Severity::Warning,
+                "message".into(),
+                PathBuf::from(path),
+                None,
+                None,
+                vec![],
+            )
+        };
+        let mut results = vec![
+            make("/Users/alice/projects/app/package.json"),
+            make("/Users/alice-shared/package.json"),
+            make("/opt/ci/package.json"),
+        ];
+
+        redact_home(&mut results, Path::new("/Users/alice"));
+
+        assert_eq!(results[0].path, "~/projects/app/package.json");
+        assert_eq!(results[1].path, "/Users/alice-shared/package.json");
+        assert_eq!(results[2].path, "/opt/ci/package.json");
     }
 }
@@ -0,0 +1,163 @@
+//! Helpers for querying and editing a repository's .gitignore.
+//!
+//! Matching uses the `ignore` crate so negations, anchored patterns and
+//! nested .gitignore files behave the way git treats them.
+
+use crate::types::RuleContext;
+use ignore::gitignore::GitignoreBuilder;
+use std::path::{Path, PathBuf};
+
+/// The git repository holding `dir`, or `scan_root` when none is found below it
+pub fn repo_root(dir: &Path, scan_root: &Path) -> PathBuf {
+    dir.ancestors()
+        .take_while(|ancestor| ancestor.starts_with(scan_root))
+        .find(|ancestor| ancestor.join(".git").exists())
+        .unwrap_or(scan_root)
+        .to_path_buf()
+}
+
+/// Whether `relative` (a path inside `repo_root`) is excluded by the repository's
+/// .gitignore files: the root one plus any in directories along the path
+pub fn is_ignored(repo_root: &Path, relative: &Path, is_dir: bool) -> bool {
+    let mut builder = GitignoreBuilder::new(repo_root);
+    builder.add(repo_root.join(".gitignore"));
+
+    let mut dir = repo_root.to_path_buf();
+    for component in relative.parent().into_iter().flat_map(|p| p.components()) {
+        dir.push(component);
+        let nested = dir.join(".gitignore");
+        if nested.is_file() {
+            builder.add(nested);
+        }
+    }
+
+    match builder.build() {
+        Ok(gitignore) => gitignore
+            .matched_path_or_any_parents(relative, is_dir)
+            .is_ignore(),
+        Err(_) => false,
+    }
+}
+
+/// Append `entry` to the root .gitignore unless a line with it already exists.
+/// Returns true if the file was changed.
+pub fn append_entry(
+    context: &RuleContext,
+    repo_root: &Path,
+    entry: &str,
+) -> std::io::Result<bool> {
+    let path = repo_root.join(".gitignore");
+    let content = if context.file_exists(&path) {
+        context.read_file(&path)?
+    } else {
+        String::new()
+    };
+
+    if content.lines().any(|line| line.trim() == entry) {
+        return Ok(false);
+    }
+
+    let mut updated = content;
+    if !updated.is_empty() && !updated.ends_with('\n') {
+        updated.push('\n');
+    }
+    updated.push_str(entry);
+    updated.push('\n');
+    context.write_file(&path, &updated)?;
+
+    Ok(true)
+}
+
+/// Append the `entries` missing from the root .gitignore as a block under a
+/// `# {header}` comment. If the header already exists, missing entries are added
+/// to the end of its block. Returns true if the file was changed.
+pub fn append_block(
+    context: &RuleContext,
+    repo_root: &Path,
+    header: &str,
+    entries: &[&str],
+) -> std::io::Result<bool> {
+    let path = repo_root.join(".gitignore");
+    let content = if context.file_exists(&path) {
+        context.read_file(&path)?
+    } else {
+        String::new()
+    };
+
+    let missing: Vec<&str> = entries
+        .iter()
+        .copied()
+        .filter(|entry| !content.lines().any(|line| line.trim() == *entry))
+        .collect();
+    if missing.is_empty() {
+        return Ok(false);
+    }
+
+    let header_line = format!("# {}", header);
+    let mut lines: Vec<&str> = content.lines().collect();
+    match lines.iter().position(|line| line.trim() == header_line) {
+        Some(header_index) => {
+            let block_end = lines[header_index + 1..]
+                .iter()
+                .position(|line| line.trim().is_empty())
+                .map_or(lines.len(), |offset| header_index + 1 + offset);
+            for (offset, entry) in missing.iter().enumerate() {
+                lines.insert(block_end + offset, entry);
+            }
+        }
+        None => {
+            if !lines.is_empty() {
+                lines.push("");
+            }
+            lines.push(&header_line);
+            lines.extend(missing.iter().copied());
+        }
+    }
+
+    context.write_file(&path, &(lines.join("\n") + "\n"))?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_is_ignored_follows_gitignore_semantics() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(root.join(".gitignore"), "*.local.json\n!keep.local.json\ndist/\n").unwrap();
+
+        assert!(is_ignored(root, Path::new(".claude/settings.local.json"), false));
+        assert!(!is_ignored(root, Path::new("keep.local.json"), false));
+        assert!(is_ignored(root, Path::new("dist/index.js"), false));
+        assert!(!is_ignored(root, Path::new(".claude/settings.json"), false));
+    }
+
+    #[test]
+    fn test_is_ignored_reads_nested_gitignore() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join(".claude")).unwrap();
+        fs::write(root.join(".claude/.gitignore"), "settings.local.json\n").unwrap();
+
+        assert!(is_ignored(root, Path::new(".claude/settings.local.json"), false));
+    }
+
+    #[test]
+    fn test_append_entry_is_idempotent() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(root.join(".gitignore"), "node_modules").unwrap();
+        let context = RuleContext::new(root.to_path_buf(), serde_json::Value::Null);
+
+        assert!(append_entry(&context, root, "dist/").unwrap());
+        assert!(!append_entry(&context, root, "dist/").unwrap());
+        assert_eq!(
+            fs::read_to_string(root.join(".gitignore")).unwrap(),
+            "node_modules\ndist/\n"
+        );
+    }
+}
@@ -0,0 +1,153 @@
+//! Helpers for generating and inspecting Husky hook scripts.
+//!
+//! Husky v8 required every hook to source `.husky/_/husky.sh`. Husky v9 dropped
+//! that requirement and prints a deprecation warning when the line is present,
+//! so hooks are generated according to the installed major version.
+
+use serde_json::Value;
+use std::path::Path;
+
+/// The v8 boilerplate line sourcing husky.sh
+pub const HUSKY_V8_SHIM: &str = ". \"$(dirname -- \"$0\")/_/husky.sh\"";
+
+/// Detect the husky major version for a project directory.
+///
+/// The installed version in node_modules wins over the declared range in package.json.
+pub fn detect_major_version(project_dir: &Path) -> Option<u64> {
+    let installed = read_json(&project_dir.join("node_modules/husky/package.json"))
+        .and_then(|json| json.get("version").and_then(|v| v.as_str()).and_then(parse_major));
+    if installed.is_some() {
+        return installed;
+    }
+
+    let package_json = read_json(&project_dir.join("package.json"))?;
+    ["devDependencies", "dependencies"].iter().find_map(|key| {
+        package_json
+            .get(key)
+            .and_then(|deps| deps.get("husky"))
+            .and_then(|v| v.as_str())
+            .and_then(parse_major)
+    })
+}
+
+/// Whether hooks for this version still need the husky.sh shim.
+/// Unknown versions are treated as modern (v9+).
+pub fn requires_shim(major: Option<u64>) -> bool {
+    major.is_some_and(|m| m < 9)
+}
+
+/// Build the content of a new hook script running `body`
+pub fn hook_content(major: Option<u64>, body: &str) -> String {
+    if requires_shim(major) {
+        format!("#!/usr/bin/env sh\n{}\n\n{}\n", HUSKY_V8_SHIM, body)
+    } else {
+        format!("{}\n", body)
+    }
+}
+
+/// Whether a hook script still contains the v8 husky.sh shim
+pub fn has_obsolete_shim(content: &str) -> bool {
+    content
+        .lines()
+        .any(|line| line.trim_start().starts_with('.') && line.contains("_/husky.sh"))
+}
+
+/// Describe why a hook script defeats its own purpose, if it does: it bypasses
+/// hooks with `--no-verify`, or exits successfully before running anything
+pub fn bypass_reason(content: &str) -> Option<&'static str> {
+    let commands = effective_commands(content);
+
+    if commands.iter().any(|line| line.contains("--no-verify")) {
+        return Some("uses --no-verify, which skips git hooks");
+    }
+    if commands.first().is_some_and(|line| *line == "exit 0" || *line == "exit") {
+        return Some("exits with success before running any command");
+    }
+    None
+}
+
+/// Lines of a hook script that actually run something, skipping blank lines,
+/// comments, the shebang and the v8 husky.sh shim
+pub fn effective_commands(content: &str) -> Vec<&str> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter(|line| !has_obsolete_shim(line))
+        .collect()
+}
+
+/// Extract the major version from a version or range like `^9.1.7` or `v8`
+fn parse_major(version: &str) -> Option<u64> {
+    let digits: String = version
+        .trim_start_matches(|c: char| !c.is_ascii_digit())
+        .chars()
+        .take_while(|c| c.is_ascii_digit())
+        .collect();
+    digits.parse().ok()
+}
+
+fn read_json(path: &Path) -> Option<Value> {
+    let content = std::fs::read_to_string(path).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_detects_version_from_package_json() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("package.json"),
+            r#"{"devDependencies": {"husky": "^8.0.3"}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(detect_major_version(temp_dir.path()), Some(8));
+    }
+
+    #[test]
+    fn test_installed_version_wins() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("package.json"),
+            r#"{"devDependencies": {"husky": "^8.0.3"}}"#,
+        )
+        .unwrap();
+        let installed = temp_dir.path().join("node_modules/husky");
+        fs::create_dir_all(&installed).unwrap();
+        fs::write(installed.join("package.json"), r#"{"version": "9.1.7"}"#).unwrap();
+
+        assert_eq!(detect_major_version(temp_dir.path()), Some(9));
+    }
+
+    #[test]
+    fn test_hook_content_for_v8_includes_shim() {
+        let content = hook_content(Some(8), "pnpm test");
+
+        assert!(content.starts_with("#!/usr/bin/env sh\n"));
+        assert!(has_obsolete_shim(&content));
+        assert!(content.ends_with("pnpm test\n"));
+    }
+
+    #[test]
+    fn test_hook_content_for_v9_omits_shim() {
+        let content = hook_content(Some(9), "pnpm test");
+
+        assert_eq!(content, "pnpm test\n");
+        assert!(!has_obsolete_shim(&content));
+        assert_eq!(hook_content(None, "pnpm test"), content);
+    }
+
+    #[test]
+    fn test_bypass_reason() {
+        assert!(bypass_reason("pnpm test\ngit commit --amend --no-verify\n").is_some());
+        assert!(bypass_reason(&hook_content(Some(8), "exit 0\npnpm test")).is_some());
+        assert!(bypass_reason("pnpm test || exit 1\nexit 0\n").is_none());
+        assert!(bypass_reason("# never use --no-verify\npnpm lint\n").is_none());
+    }
+}
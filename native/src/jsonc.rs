@@ -0,0 +1,341 @@
+//! JSON with comments, as used by tsconfig.json and .vscode/settings.json.
+//!
+//! `parse` accepts `//` and `/* */` comments and trailing commas. `set_property`
+//! edits the source text in place so a fix doesn't throw away the comments and
+//! layout a re-serialized file would lose.
+
+use serde_json::Value;
+
+/// Parse JSONC into a value by blanking out comments and trailing commas
+pub fn parse(content: &str) -> Result<Value, serde_json::Error> {
+    serde_json::from_str(&strip(content))
+}
+
+/// Replace comments and trailing commas with spaces, keeping byte offsets intact
+fn strip(content: &str) -> String {
+    let bytes = content.as_bytes();
+    let mut out = bytes.to_vec();
+    let mut scanner = Scanner::new(content);
+
+    while scanner.pos < bytes.len() {
+        let start = scanner.pos;
+        match bytes[start] {
+            b'"' => {
+                scanner.skip_string();
+            }
+            b'/' if scanner.skip_comment() => blank(&mut out, start, scanner.pos),
+            b',' => {
+                scanner.pos += 1;
+                let mut lookahead = Scanner { src: content, pos: scanner.pos };
+                lookahead.skip_trivia();
+                if matches!(lookahead.peek(), Some(b'}') | Some(b']')) {
+                    out[start] = b' ';
+                }
+            }
+            _ => scanner.pos += 1,
+        }
+    }
+
+    // Only ASCII bytes outside strings were blanked, so the result is still UTF-8
+    String::from_utf8(out).unwrap_or_default()
+}
+
+/// Overwrite a byte range with spaces, keeping newlines so line numbers survive
+fn blank(out: &mut [u8], start: usize, end: usize) {
+    for byte in &mut out[start..end] {
+        if *byte != b'\n' {
+            *byte = b' ';
+        }
+    }
+}
+
+/// Set the property at `path` (e.g. `["compilerOptions", "skipLibCheck"]`) to
+/// `value`, editing the text so comments and formatting elsewhere are kept.
+/// Missing parent objects are created. Returns None if the text isn't an object
+/// or a parent on the path holds a non-object value.
+pub fn set_property(content: &str, path: &[&str], value: &Value) -> Option<String> {
+    let mut scanner = Scanner::new(content);
+    scanner.skip_trivia();
+    let mut object_start = scanner.pos;
+
+    for (depth, key) in path.iter().enumerate() {
+        scanner.pos = object_start;
+        let (members, close) = scanner.object_members()?;
+        let remaining = &path[depth..];
+
+        let Some(member) = members.iter().find(|m| m.key == *key) else {
+            return Some(insert_member(content, object_start, close, &members, remaining, value));
+        };
+
+        if remaining.len() == 1 {
+            let mut updated = content.to_string();
+            updated.replace_range(member.value_start..member.value_end, &value.to_string());
+            return Some(updated);
+        }
+        if content.as_bytes()[member.value_start] != b'{' {
+            return None;
+        }
+        object_start = member.value_start;
+    }
+
+    None
+}
+
+/// Insert `"path[0]": {...value}` as the last member of the object spanning
+/// `open..=close`, matching the indentation of its existing members
+fn insert_member(
+    content: &str,
+    open: usize,
+    close: usize,
+    members: &[Member],
+    path: &[&str],
+    value: &Value,
+) -> String {
+    let (indent, step) = match members.last() {
+        Some(last) => {
+            let indent = line_indent(content, last.key_start);
+            let outer = line_indent(content, open);
+            let step = indent.strip_prefix(outer.as_str()).unwrap_or("  ").to_string();
+            (indent, step)
+        }
+        None => {
+            let outer = line_indent(content, open);
+            (format!("{}  ", outer), "  ".to_string())
+        }
+    };
+
+    let mut text = value.to_string();
+    for (i, key) in path.iter().enumerate().rev() {
+        let pad = format!("{}{}", indent, step.repeat(i));
+        text = if i == 0 {
+            format!("{}: {}", Value::from(*key), text)
+        } else {
+            let closing = format!("{}{}", indent, step.repeat(i - 1));
+            format!("{{\n{}{}: {}\n{}}}", pad, Value::from(*key), text, closing)
+        };
+    }
+
+    let mut updated = content.to_string();
+    match members.last() {
+        Some(last) => {
+            updated.insert_str(last.value_end, &format!(",\n{}{}", indent, text));
+        }
+        None => {
+            let outer = line_indent(content, open);
+            updated.replace_range(open + 1..close, &format!("\n{}{}\n{}", indent, text, outer));
+        }
+    }
+    updated
+}
+
+/// Leading whitespace of the line containing byte offset `pos`
+fn line_indent(content: &str, pos: usize) -> String {
+    let line_start = content[..pos].rfind('\n').map_or(0, |i| i + 1);
+    content[line_start..]
+        .chars()
+        .take_while(|c| *c == ' ' || *c == '\t')
+        .collect()
+}
+
+/// A key/value pair of a JSONC object with byte offsets into the source
+struct Member {
+    key: String,
+    key_start: usize,
+    value_start: usize,
+    value_end: usize,
+}
+
+/// Minimal JSONC scanner that finds value spans without building a tree
+struct Scanner<'a> {
+    src: &'a str,
+    pos: usize,
+}
+
+impl<'a> Scanner<'a> {
+    fn new(src: &'a str) -> Self {
+        Self { src, pos: 0 }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.src.as_bytes().get(self.pos).copied()
+    }
+
+    /// Skip a comment at the current position, returning false if there isn't one
+    fn skip_comment(&mut self) -> bool {
+        let rest = &self.src[self.pos..];
+        if rest.starts_with("//") {
+            self.pos += rest.find('\n').unwrap_or(rest.len());
+            true
+        } else if let Some(body) = rest.strip_prefix("/*") {
+            self.pos += body.find("*/").map_or(rest.len(), |i| i + 4);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Skip whitespace and comments
+    fn skip_trivia(&mut self) {
+        loop {
+            match self.peek() {
+                Some(c) if c.is_ascii_whitespace() => self.pos += 1,
+                Some(b'/') if self.skip_comment() => {}
+                _ => return,
+            }
+        }
+    }
+
+    /// Skip a string starting at the current `"`, returning its raw contents
+    fn skip_string(&mut self) -> &'a str {
+        let start = self.pos + 1;
+        let bytes = self.src.as_bytes();
+        self.pos = start;
+        while self.pos < bytes.len() {
+            match bytes[self.pos] {
+                b'\\' => self.pos += 2,
+                b'"' => {
+                    self.pos += 1;
+                    return &self.src[start..self.pos - 1];
+                }
+                _ => self.pos += 1,
+            }
+        }
+        self.pos = bytes.len();
+        &self.src[start..]
+    }
+
+    /// Skip one value (string, object, array or literal)
+    fn skip_value(&mut self) -> Option<()> {
+        match self.peek()? {
+            b'"' => {
+                self.skip_string();
+            }
+            b'{' => {
+                self.object_members()?;
+            }
+            b'[' => {
+                self.pos += 1;
+                loop {
+                    self.skip_trivia();
+                    match self.peek()? {
+                        b']' => break,
+                        b',' => self.pos += 1,
+                        _ => self.skip_value()?,
+                    }
+                }
+                self.pos += 1;
+            }
+            _ => {
+                let start = self.pos;
+                while self
+                    .peek()
+                    .is_some_and(|c| !matches!(c, b',' | b'}' | b']' | b'/') && !c.is_ascii_whitespace())
+                {
+                    self.pos += 1;
+                }
+                if self.pos == start {
+                    return None;
+                }
+            }
+        }
+        Some(())
+    }
+
+    /// Read the members of the object starting at the current `{`. Leaves the
+    /// position after the closing `}` and returns the members and its offset.
+    fn object_members(&mut self) -> Option<(Vec<Member>, usize)> {
+        if self.peek()? != b'{' {
+            return None;
+        }
+        self.pos += 1;
+        let mut members = Vec::new();
+
+        loop {
+            self.skip_trivia();
+            match self.peek()? {
+                b'}' => {
+                    let close = self.pos;
+                    self.pos += 1;
+                    return Some((members, close));
+                }
+                b',' => self.pos += 1,
+                b'"' => {
+                    let key_start = self.pos;
+                    let key = self.skip_string();
+                    let key = serde_json::from_str::<String>(&format!("\"{}\"", key)).ok()?;
+                    self.skip_trivia();
+                    if self.peek()? != b':' {
+                        return None;
+                    }
+                    self.pos += 1;
+                    self.skip_trivia();
+                    let value_start = self.pos;
+                    self.skip_value()?;
+                    members.push(Member {
+                        key,
+                        key_start,
+                        value_start,
+                        value_end: self.pos,
+                    });
+                }
+                _ => return None,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_accepts_comments_and_trailing_commas() {
+        let value = parse(
+            "{\n  // line comment\n  \"a\": \"http://x\", /* block */\n  \"b\": [1, 2,],\n}\n",
+        )
+        .unwrap();
+
+        assert_eq!(value["a"], "http://x");
+        assert_eq!(value["b"], serde_json::json!([1, 2]));
+    }
+
+    #[test]
+    fn test_set_property_replaces_existing_value() {
+        let content = "{\n  // keep me\n  \"compilerOptions\": {\n    \"skipLibCheck\": false, // off\n  },\n}\n";
+        let updated =
+            set_property(content, &["compilerOptions", "skipLibCheck"], &Value::Bool(true)).unwrap();
+
+        assert_eq!(
+            updated,
+            "{\n  // keep me\n  \"compilerOptions\": {\n    \"skipLibCheck\": true, // off\n  },\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_set_property_appends_member_with_matching_indent() {
+        let content = "{\n    \"compilerOptions\": {\n        \"strict\": true\n    }\n}\n";
+        let updated =
+            set_property(content, &["compilerOptions", "skipLibCheck"], &Value::Bool(true)).unwrap();
+
+        assert_eq!(
+            updated,
+            "{\n    \"compilerOptions\": {\n        \"strict\": true,\n        \"skipLibCheck\": true\n    }\n}\n"
+        );
+        assert_eq!(parse(&updated).unwrap()["compilerOptions"]["skipLibCheck"], true);
+    }
+
+    #[test]
+    fn test_set_property_creates_missing_parents() {
+        let content = "{\n  \"extends\": \"./base.json\", // shared\n}\n";
+        let updated =
+            set_property(content, &["compilerOptions", "skipLibCheck"], &Value::Bool(true)).unwrap();
+
+        assert_eq!(
+            updated,
+            "{\n  \"extends\": \"./base.json\",\n  \"compilerOptions\": {\n    \"skipLibCheck\": true\n  }, // shared\n}\n"
+        );
+
+        let updated = set_property("{}", &["exclude"], &serde_json::json!(["node_modules"])).unwrap();
+        assert_eq!(updated, "{\n  \"exclude\": [\"node_modules\"]\n}");
+    }
+}
@@ -1,14 +1,25 @@
 #![deny(clippy::all)]
 
+mod command;
 mod engine;
+mod gitignore;
+mod husky;
+mod jsonc;
+mod lsp;
 mod rules;
+mod scan;
+mod shell;
 mod types;
+mod walk;
 
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
 
-use engine::Runner;
-use types::{Config, LintReport, RuleInfo};
+use engine::{PreparedRoot, Runner};
+use types::{
+    CheckDescription, CheckRef, Config, FileContent, FixRef, FixSummary, LintReport,
+    PlannedChange, RuleInfo, ScanStats, WorkspaceReport,
+};
 
 /// Engine wrapper exposed to JavaScript
 #[napi]
@@ -16,20 +27,26 @@ pub struct Engine {
     inner: Runner,
 }
 
+/// A scan root checked to exist once, for linting the same root repeatedly
+#[napi(js_name = "PreparedRoot")]
+pub struct PreparedRootHandle {
+    inner: PreparedRoot,
+}
+
 #[napi]
 impl Engine {
     #[napi(constructor)]
     pub fn new(config_json: String) -> Result<Self> {
-        let config: Config = if config_json.is_empty() || config_json == "{}" {
-            Config::default()
-        } else {
-            serde_json::from_str(&config_json)
-                .map_err(|e| Error::from_reason(format!("Invalid config: {}", e)))?
-        };
-
-        Ok(Self {
+        let config =
+            Config::from_json(&config_json).map_err(|e| Error::from_reason(e.to_string()))?;
+
+        Ok(Self::from_config(config))
+    }
+
+    fn from_config(config: Config) -> Self {
+        Self {
             inner: Runner::new(config),
-        })
+        }
     }
 
     /// Run all enabled rules on the specified path
@@ -40,6 +57,28 @@ impl Engine {
             .map_err(|e| Error::from_reason(e.to_string()))
     }
 
+    /// Validate a path once, for repeated `lintPrepared` calls (e.g. a language server)
+    #[napi]
+    pub fn prepare_root(&self, path: String) -> Result<PreparedRootHandle> {
+        let inner = PreparedRoot::new(&path).map_err(|e| Error::from_reason(e.to_string()))?;
+        Ok(PreparedRootHandle { inner })
+    }
+
+    /// Lint a root returned by `prepareRoot`, skipping the path check
+    #[napi]
+    pub fn lint_prepared(&self, root: &PreparedRootHandle) -> LintReport {
+        self.inner.run_prepared(&root.inner)
+    }
+
+    /// Lint like `lint`, reusing a rule's previous results from this engine while
+    /// the files it reads are unchanged. Suited to watch mode.
+    #[napi]
+    pub fn lint_cached(&self, path: String) -> Result<LintReport> {
+        self.inner
+            .run_cached(&path)
+            .map_err(|e| Error::from_reason(e.to_string()))
+    }
+
     /// Run rules and apply fixes where possible
     #[napi]
     pub fn fix(&self, path: String) -> Result<LintReport> {
@@ -48,11 +87,136 @@ impl Engine {
             .map_err(|e| Error::from_reason(e.to_string()))
     }
 
+    /// Apply only the fixes of one rule, then re-check that rule
+    #[napi]
+    pub fn fix_rule(&self, path: String, rule_id: String) -> Result<LintReport> {
+        self.inner
+            .run_rule_fix(&path, &rule_id)
+            .map_err(|e| Error::from_reason(e.to_string()))
+    }
+
+    /// Run all enabled rules, suppressing results whose fingerprint is in the baseline
+    #[napi]
+    pub fn lint_with_baseline(
+        &self,
+        path: String,
+        baseline_fingerprints: Vec<String>,
+    ) -> Result<LintReport> {
+        self.inner
+            .run_with_baseline(&path, &baseline_fingerprints)
+            .map_err(|e| Error::from_reason(e.to_string()))
+    }
+
+    /// Collect the fingerprints of all current results to use as a baseline
+    #[napi]
+    pub fn baseline_fingerprints(&self, path: String) -> Result<Vec<String>> {
+        self.inner
+            .baseline(&path)
+            .map_err(|e| Error::from_reason(e.to_string()))
+    }
+
+    /// Write the fingerprints of all current results to `out_path`, one per line.
+    /// Returns the number of fingerprints written.
+    #[napi]
+    pub fn write_baseline(&self, path: String, out_path: String) -> Result<u32> {
+        self.inner
+            .write_baseline(&path, &out_path)
+            .map_err(|e| Error::from_reason(e.to_string()))
+    }
+
+    /// Run all enabled rules, suppressing results listed in a baseline file
+    #[napi]
+    pub fn lint_with_baseline_file(
+        &self,
+        path: String,
+        baseline_path: String,
+    ) -> Result<LintReport> {
+        self.inner
+            .run_with_baseline_file(&path, &baseline_path)
+            .map_err(|e| Error::from_reason(e.to_string()))
+    }
+
     /// List all available rules
     #[napi]
     pub fn list_rules(&self) -> Vec<RuleInfo> {
         self.inner.list_rules()
     }
+
+    /// List only the rules that can apply fixes
+    #[napi]
+    pub fn list_fixable_rules(&self) -> Vec<RuleInfo> {
+        self.inner.list_fixable_rules()
+    }
+
+    /// Rule ids in execution order; fixes are applied in this order
+    #[napi]
+    pub fn rule_order(&self) -> Vec<String> {
+        self.inner.rule_order()
+    }
+
+    /// Describe a single check and the fixes that address it
+    #[napi]
+    pub fn describe_check(&self, rule_id: String, check_id: String) -> Option<CheckDescription> {
+        self.inner.describe_check(&rule_id, &check_id)
+    }
+
+    /// Every check id across all rules, for validating config and generating docs
+    #[napi]
+    pub fn all_check_ids(&self) -> Vec<CheckRef> {
+        self.inner.all_check_ids()
+    }
+
+    /// Every fix id across all rules, with the checks each one addresses
+    #[napi]
+    pub fn all_fix_ids(&self) -> Vec<FixRef> {
+        self.inner.all_fix_ids()
+    }
+
+    /// Summarize the fixes a report's results point at, without running them
+    #[napi]
+    pub fn summarize_fixes(&self, report: LintReport) -> Vec<FixSummary> {
+        self.inner.summarize_fixes(&report)
+    }
+
+    /// Full content of every file the fixes would change, without writing anything
+    #[napi]
+    pub fn preview_fixed_files(&self, path: String) -> Result<Vec<FileContent>> {
+        self.inner
+            .preview_fixed_files(&path)
+            .map_err(|e| Error::from_reason(e.to_string()))
+    }
+
+    /// Plan the file changes fixes would make, for applying selectively with applyChange
+    #[napi]
+    pub fn plan_fix(&self, path: String) -> Result<Vec<PlannedChange>> {
+        self.inner
+            .plan_fix(&path)
+            .map_err(|e| Error::from_reason(e.to_string()))
+    }
+
+    /// Apply one planned change, failing if its file changed since it was planned
+    #[napi]
+    pub fn apply_change(&self, change: PlannedChange) -> Result<()> {
+        self.inner
+            .apply_change(&change)
+            .map_err(|e| Error::from_reason(e.to_string()))
+    }
+
+    /// Measure the shared scan under a path: entries walked, what it found and how long it took
+    #[napi]
+    pub fn scan_stats(&self, path: String) -> Result<ScanStats> {
+        self.inner
+            .scan_stats(&path)
+            .map_err(|e| Error::from_reason(e.to_string()))
+    }
+
+    /// List the package.json files and git repositories the scan finds, without running rules
+    #[napi]
+    pub fn scan_workspace(&self, path: String) -> Result<WorkspaceReport> {
+        self.inner
+            .scan_workspace(&path)
+            .map_err(|e| Error::from_reason(e.to_string()))
+    }
 }
 
 /// Create an engine with the given configuration
@@ -60,3 +224,48 @@ impl Engine {
 pub fn create_engine(config_json: String) -> Result<Engine> {
     Engine::new(config_json)
 }
+
+/// Create an engine with a YAML configuration
+#[napi]
+pub fn create_engine_yaml(config_yaml: String) -> Result<Engine> {
+    let config =
+        Config::from_yaml(&config_yaml).map_err(|e| Error::from_reason(e.to_string()))?;
+
+    Ok(Engine::from_config(config))
+}
+
+/// Create an engine from a config file on disk (YAML for `.yaml`/`.yml`, JSON otherwise)
+#[napi]
+pub fn create_engine_from_file(config_path: String) -> Result<Engine> {
+    let config = Config::from_file(std::path::Path::new(&config_path))
+        .map_err(|e| Error::from_reason(e.to_string()))?;
+
+    Ok(Engine::from_config(config))
+}
+
+/// Return a starting config as JSON: `"recommended"` (the defaults) or `"strict"`
+/// (every rule enabled, info and warnings reported as errors)
+#[napi]
+pub fn default_config(preset: String) -> Result<String> {
+    let config = engine::preset_config(&preset).map_err(|e| Error::from_reason(e.to_string()))?;
+    serde_json::to_string_pretty(&config).map_err(|e| Error::from_reason(e.to_string()))
+}
+
+/// Merge two lint reports, e.g. produced by scanning separate directories.
+/// When `dedupe` is true, identical results (same rule, check, path and message) are kept once.
+#[napi]
+pub fn merge_reports(first: LintReport, second: LintReport, dedupe: Option<bool>) -> LintReport {
+    let merged = first.merge(second);
+    if dedupe.unwrap_or(false) {
+        merged.dedup()
+    } else {
+        merged
+    }
+}
+
+/// Convert a report into LSP diagnostics grouped by file URI, as `[{uri, diagnostics}]`
+/// ready to send with `textDocument/publishDiagnostics`
+#[napi]
+pub fn to_lsp_diagnostics(report: LintReport) -> serde_json::Value {
+    lsp::to_lsp_diagnostics(&report)
+}
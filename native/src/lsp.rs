@@ -0,0 +1,163 @@
+//! Conversion of lint reports into Language Server Protocol diagnostics
+
+use crate::types::LintReport;
+use serde_json::{json, Value};
+
+/// LSP `DiagnosticSeverity` for a result severity; unknown severities count as info
+fn lsp_severity(severity: &str) -> u8 {
+    match severity {
+        "error" => 1,
+        "warning" => 2,
+        _ => 3,
+    }
+}
+
+/// A `file://` URI for an absolute path, percent-encoding everything outside the
+/// unreserved set. Relative paths (e.g. after `stripPrefix`) are encoded as-is.
+fn file_uri(path: &str) -> String {
+    let mut path = path.replace('\\', "/");
+    // Windows drive paths such as C:/repo become file:///C:/repo
+    if path.as_bytes().get(1) == Some(&b':') {
+        path.insert(0, '/');
+    }
+
+    let mut encoded = String::with_capacity(path.len());
+    for byte in path.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => {
+                encoded.push(byte as char)
+            }
+            // Keep the drive letter's colon readable, as editors send it
+            b':' if encoded.len() == 2 => encoded.push(':'),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+
+    if encoded.starts_with('/') {
+        format!("file://{}", encoded)
+    } else {
+        encoded
+    }
+}
+
+/// Group a report's results by file into LSP `PublishDiagnosticsParams` objects
+/// (`[{uri, diagnostics}]`), in order of each file's first result. Results carry
+/// no column, so each range spans the start of its line; results without a line
+/// point at the first line.
+pub fn to_lsp_diagnostics(report: &LintReport) -> Value {
+    let mut files: Vec<(String, Vec<Value>)> = Vec::new();
+
+    for result in &report.results {
+        let uri = file_uri(&result.path);
+        // LintResult lines are 1-based, LSP positions 0-based
+        let line = result.line.map_or(0, |line| line.saturating_sub(1));
+        let diagnostic = json!({
+            "range": {
+                "start": {"line": line, "character": 0},
+                "end": {"line": line, "character": 0},
+            },
+            "severity": lsp_severity(&result.severity),
+            "source": result.rule_id,
+            "code": result.check_id,
+            "message": result.message,
+        });
+
+        match files.iter_mut().find(|(existing, _)| *existing == uri) {
+            Some((_, diagnostics)) => diagnostics.push(diagnostic),
+            None => files.push((uri, vec![diagnostic])),
+        }
+    }
+
+    Value::Array(
+        files
+            .into_iter()
+            .map(|(uri, diagnostics)| json!({"uri": uri, "diagnostics": diagnostics}))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{LintResult, Severity};
+    use std::path::PathBuf;
+
+    fn result(path: &str, line: Option<u32>, severity: Severity) -> LintResult {
+        LintResult::new(
+            "tsconfig",
+            "skip-lib-check",
+            severity,
+            "message".into(),
+            PathBuf::from(path),
+            line,
+            None,
+            vec![],
+        )
+    }
+
+    #[test]
+    fn test_groups_multi_file_report_by_uri() {
+        let report = LintReport::new(
+            vec![
+                result("/repo/tsconfig.json", Some(3), Severity::Error),
+                result("/repo/my app/package.json", None, Severity::Info),
+                result("/repo/tsconfig.json", None, Severity::Warning),
+            ],
+            0,
+        );
+
+        let diagnostics = to_lsp_diagnostics(&report);
+
+        assert_eq!(
+            diagnostics,
+            json!([
+                {
+                    "uri": "file:///repo/tsconfig.json",
+                    "diagnostics": [
+                        {
+                            "range": {
+                                "start": {"line": 2, "character": 0},
+                                "end": {"line": 2, "character": 0}
+                            },
+                            "severity": 1,
+                            "source": "tsconfig",
+                            "code": "skip-lib-check",
+                            "message": "message"
+                        },
+                        {
+                            "range": {
+                                "start": {"line": 0, "character": 0},
+                                "end": {"line": 0, "character": 0}
+                            },
+                            "severity": 2,
+                            "source": "tsconfig",
+                            "code": "skip-lib-check",
+                            "message": "message"
+                        }
+                    ]
+                },
+                {
+                    "uri": "file:///repo/my%20app/package.json",
+                    "diagnostics": [
+                        {
+                            "range": {
+                                "start": {"line": 0, "character": 0},
+                                "end": {"line": 0, "character": 0}
+                            },
+                            "severity": 3,
+                            "source": "tsconfig",
+                            "code": "skip-lib-check",
+                            "message": "message"
+                        }
+                    ]
+                }
+            ])
+        );
+    }
+
+    #[test]
+    fn test_file_uri_handles_windows_paths() {
+        assert_eq!(file_uri(r"C:\repo\package.json"), "file:///C:/repo/package.json");
+        assert_eq!(file_uri("packages/web/package.json"), "packages/web/package.json");
+    }
+}
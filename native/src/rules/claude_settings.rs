@@ -1,8 +1,8 @@
+use crate::gitignore;
 use crate::rules::{Rule, RuleError};
 use crate::types::{CheckEntry, FixEntry, LintResult, RuleContext, Severity};
 use serde_json::{json, Value};
-use std::path::Path;
-use walkdir::WalkDir;
+use std::path::{Path, PathBuf};
 
 // Check IDs
 const CHECK_CLAUDE_DIR_EXISTS: &str = "claude-dir-exists";
@@ -10,10 +10,17 @@ const CHECK_SETTINGS_FILE_EXISTS: &str = "settings-file-exists";
 const CHECK_HOOKS_OBJECT_EXISTS: &str = "hooks-object-exists";
 const CHECK_PRE_TOOL_USE_EXISTS: &str = "pre-tool-use-exists";
 const CHECK_BASH_MATCHER_EXISTS: &str = "bash-matcher-exists";
+const CHECK_REQUIRED_MATCHER_EXISTS: &str = "required-matcher-exists";
+const CHECK_PRE_TOOL_USE_IS_ARRAY: &str = "pre-tool-use-is-array";
+const CHECK_LOCAL_SETTINGS_IGNORED: &str = "local-settings-ignored";
 
 // Fix IDs
 const FIX_CREATE_SETTINGS: &str = "create-settings";
 const FIX_MERGE_HOOKS: &str = "merge-hooks";
+const FIX_IGNORE_LOCAL_SETTINGS: &str = "ignore-local-settings";
+
+/// Per-user settings file that is meant to stay out of version control
+const LOCAL_SETTINGS: &str = ".claude/settings.local.json";
 
 /// Rule: Ensure all git repositories have .claude/settings.json with required hooks
 pub struct ClaudeSettingsRule;
@@ -23,29 +30,63 @@ impl ClaudeSettingsRule {
         Self
     }
 
-    /// Find all .git directories in the given root (each represents a git repository)
-    fn find_git_repos(&self, root: &Path) -> Vec<std::path::PathBuf> {
-        let mut repos = Vec::new();
+    /// Directories that should hold .claude/settings.json: every git repository,
+    /// or with the `claudeAtWorkspaceRoot` option only the scan root, so a
+    /// monorepo with nested repos gets a single settings file
+    fn target_dirs(&self, context: &RuleContext) -> Vec<PathBuf> {
+        let repos = context.git_repos();
+        let at_workspace_root = context
+            .config
+            .get("claudeAtWorkspaceRoot")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
+        if at_workspace_root && !repos.is_empty() {
+            vec![context.root.clone()]
+        } else {
+            repos
+        }
+    }
 
-        for entry in WalkDir::new(root)
-            .follow_links(false)
+    /// PreToolUse entries settings.json must contain: the built-in Bash hook, then
+    /// one per `additionalMatchers` option entry (`{"matcher": ..., "command": ...}`)
+    fn required_hooks(&self, context: &RuleContext) -> Vec<Value> {
+        let mut required = vec![self.get_required_bash_hook()];
+
+        let additional = context
+            .config
+            .get("additionalMatchers")
+            .and_then(|v| v.as_array())
             .into_iter()
-            .filter_map(|e| e.ok())
-        {
-            let path = entry.path();
-            if path.is_dir() && path.file_name().is_some_and(|n| n == ".git") {
-                // Return the parent directory (the repo root), not the .git folder itself
-                if let Some(parent) = path.parent() {
-                    repos.push(parent.to_path_buf());
-                }
+            .flatten();
+        for entry in additional {
+            let matcher = entry.get("matcher").and_then(|m| m.as_str());
+            let command = entry.get("command").and_then(|c| c.as_str());
+            let (Some(matcher), Some(command)) = (matcher, command) else {
+                continue;
+            };
+            if required.iter().any(|hook| hook["matcher"] == matcher) {
+                continue;
             }
+            required.push(json!({
+                "matcher": matcher,
+                "hooks": [{"type": "command", "command": command}]
+            }));
         }
 
-        repos
+        required
+    }
+
+    fn has_matcher(entries: &[Value], matcher: &str) -> bool {
+        entries.iter().any(|item| {
+            item.get("matcher")
+                .and_then(|m| m.as_str())
+                .is_some_and(|m| m == matcher)
+        })
     }
 
     /// Check if a git repository has proper .claude/settings.json configuration
-    fn check_repo(&self, repo_root: &Path) -> Vec<LintResult> {
+    fn check_repo(&self, repo_root: &Path, required: &[Value]) -> Vec<LintResult> {
         let mut results = Vec::new();
         let claude_dir = repo_root.join(".claude");
         let settings_path = claude_dir.join("settings.json");
@@ -81,11 +122,35 @@ impl ClaudeSettingsRule {
         }
 
         // Validate the settings file content
-        self.check_settings_content(&settings_path)
+        self.check_settings_content(&settings_path, required)
+    }
+
+    /// Whether the repo has a settings.local.json that .gitignore doesn't exclude
+    fn local_settings_committable(&self, repo_root: &Path) -> bool {
+        repo_root.join(LOCAL_SETTINGS).is_file()
+            && !gitignore::is_ignored(repo_root, Path::new(LOCAL_SETTINGS), false)
+    }
+
+    /// Check that the per-user settings.local.json is gitignored
+    fn check_local_settings(&self, repo_root: &Path) -> Option<LintResult> {
+        if !self.local_settings_committable(repo_root) {
+            return None;
+        }
+
+        Some(LintResult::new(
+            self.id(),
+            CHECK_LOCAL_SETTINGS_IGNORED,
+            Severity::Warning,
+            ".claude/settings.local.json is not gitignored and may be committed".into(),
+            repo_root.join(LOCAL_SETTINGS),
+            None,
+            Some("Add '.claude/settings.local.json' to .gitignore".into()),
+            vec![FIX_IGNORE_LOCAL_SETTINGS],
+        ))
     }
 
     /// Check if the settings.json has the required hooks configuration
-    fn check_settings_content(&self, path: &Path) -> Vec<LintResult> {
+    fn check_settings_content(&self, path: &Path, required: &[Value]) -> Vec<LintResult> {
         let mut results = Vec::new();
 
         // Parse and validate the settings file
@@ -96,29 +161,11 @@ impl ClaudeSettingsRule {
                     if let Some(hooks) = json.get("hooks") {
                         // Check for PreToolUse hook
                         if let Some(pre_tool_use) = hooks.get("PreToolUse") {
-                            // Check if it's an array with the Bash matcher
+                            // Check if it's an array with every required matcher
                             if let Some(arr) = pre_tool_use.as_array() {
-                                let has_bash_hook = arr.iter().any(|item| {
-                                    item.get("matcher")
-                                        .and_then(|m| m.as_str())
-                                        .is_some_and(|m| m == "Bash")
-                                });
-
-                                if !has_bash_hook {
-                                    results.push(LintResult::new(
-                                        self.id(),
-                                        CHECK_BASH_MATCHER_EXISTS,
-                                        Severity::Warning,
-                                        "PreToolUse hooks missing Bash matcher".into(),
-                                        path.to_path_buf(),
-                                        None,
-                                        Some(
-                                            "Add a Bash matcher hook to prevent dangerous commands"
-                                                .into(),
-                                        ),
-                                        vec![FIX_MERGE_HOOKS],
-                                    ));
-                                }
+                                results.extend(self.missing_matcher_results(arr, required, path));
+                            } else {
+                                results.push(self.pre_tool_use_type_result(pre_tool_use, path));
                             }
                         } else {
                             results.push(LintResult::new(
@@ -175,33 +222,90 @@ impl ClaudeSettingsRule {
         results
     }
 
+    /// One warning per required matcher absent from the PreToolUse entries
+    fn missing_matcher_results(
+        &self,
+        entries: &[Value],
+        required: &[Value],
+        path: &Path,
+    ) -> Vec<LintResult> {
+        required
+            .iter()
+            .filter_map(|hook| hook["matcher"].as_str())
+            .filter(|matcher| !Self::has_matcher(entries, matcher))
+            .map(|matcher| {
+                let (check_id, message, suggestion) = if matcher == "Bash" {
+                    (
+                        CHECK_BASH_MATCHER_EXISTS,
+                        "PreToolUse hooks missing Bash matcher".to_string(),
+                        "Add a Bash matcher hook to prevent dangerous commands".to_string(),
+                    )
+                } else {
+                    (
+                        CHECK_REQUIRED_MATCHER_EXISTS,
+                        format!("PreToolUse hooks missing {} matcher", matcher),
+                        format!(
+                            "Add the {} matcher hook configured in additionalMatchers",
+                            matcher
+                        ),
+                    )
+                };
+                LintResult::new(
+                    self.id(),
+                    check_id,
+                    Severity::Warning,
+                    message,
+                    path.to_path_buf(),
+                    None,
+                    Some(suggestion),
+                    vec![FIX_MERGE_HOOKS],
+                )
+            })
+            .collect()
+    }
+
+    /// A single matcher entry written as an object instead of a one-element array;
+    /// the merge fix can wrap it
+    fn is_wrappable_pre_tool_use(value: &Value) -> bool {
+        value.is_object() && (value.get("matcher").is_some() || value.get("hooks").is_some())
+    }
+
+    /// Error for a PreToolUse value that isn't an array
+    fn pre_tool_use_type_result(&self, pre_tool_use: &Value, path: &Path) -> LintResult {
+        let (suggestion, fixable_by) = if Self::is_wrappable_pre_tool_use(pre_tool_use) {
+            ("Wrap the PreToolUse entry in an array: [{ ... }]", vec![FIX_MERGE_HOOKS])
+        } else {
+            ("Replace PreToolUse with an array of { matcher, hooks } entries", vec![])
+        };
+
+        LintResult::new(
+            self.id(),
+            CHECK_PRE_TOOL_USE_IS_ARRAY,
+            Severity::Error,
+            "hooks.PreToolUse must be an array".into(),
+            path.to_path_buf(),
+            None,
+            Some(suggestion.into()),
+            fixable_by,
+        )
+    }
+
     /// Generate the default settings content
-    fn default_settings_content(&self) -> String {
+    fn default_settings_content(&self, required: &[Value]) -> String {
         let settings = json!({
             "hooks": {
-                "PreToolUse": [
-                    {
-                        "matcher": "Bash",
-                        "hooks": [
-                            {
-                                "type": "command",
-                                "command": "INPUT=$(cat); if echo \"$INPUT\" | grep -q 'git push' && echo \"$INPUT\" | grep -qE -- '--no-verify|-n[^a-z]'; then echo 'BLOCKED: --no-verify is not allowed on git push' >&2; exit 2; fi"
-                            }
-                        ]
-                    }
-                ]
+                "PreToolUse": required
             }
         });
         serde_json::to_string_pretty(&settings).unwrap()
     }
 
     /// Deep merge hooks into existing settings, returns true if changes were made
-    fn deep_merge_hooks(&self, existing: &mut Value) -> bool {
-        let required_hook = self.get_required_bash_hook();
+    fn deep_merge_hooks(&self, existing: &mut Value, required: &[Value]) -> bool {
         let mut changes_made = false;
 
         // Ensure "hooks" object exists
-        if !existing.get("hooks").is_some() {
+        if existing.get("hooks").is_none() {
             existing["hooks"] = json!({});
             changes_made = true;
         }
@@ -209,24 +313,27 @@ impl ClaudeSettingsRule {
         let hooks = existing.get_mut("hooks").unwrap();
 
         // Ensure "PreToolUse" array exists
-        if !hooks.get("PreToolUse").is_some() {
+        if hooks.get("PreToolUse").is_none() {
             hooks["PreToolUse"] = json!([]);
             changes_made = true;
         }
 
         let pre_tool_use = hooks.get_mut("PreToolUse").unwrap();
 
+        // A lone matcher object is wrapped; other wrong types are left for the user
+        if Self::is_wrappable_pre_tool_use(pre_tool_use) {
+            *pre_tool_use = json!([pre_tool_use.take()]);
+            changes_made = true;
+        }
+
         if let Some(arr) = pre_tool_use.as_array_mut() {
-            // Check if a Bash matcher already exists
-            let has_bash_hook = arr.iter().any(|item| {
-                item.get("matcher")
-                    .and_then(|m| m.as_str())
-                    .is_some_and(|m| m == "Bash")
-            });
-
-            if !has_bash_hook {
-                arr.push(required_hook);
-                changes_made = true;
+            // Add each required matcher that isn't there yet
+            for hook in required {
+                let matcher = hook["matcher"].as_str().unwrap_or_default();
+                if !Self::has_matcher(arr, matcher) {
+                    arr.push(hook.clone());
+                    changes_made = true;
+                }
             }
         }
 
@@ -270,11 +377,15 @@ impl Rule for ClaudeSettingsRule {
         Severity::Error
     }
 
+    fn tags(&self) -> Vec<&'static str> {
+        vec!["agent"]
+    }
+
     fn checks(&self) -> Vec<CheckEntry> {
         vec![
             CheckEntry::new(
                 CHECK_CLAUDE_DIR_EXISTS,
-                "Verify .claude directory exists in git repositories",
+                "Verify .claude directory exists in git repositories (option: claudeAtWorkspaceRoot)",
             ),
             CheckEntry::new(
                 CHECK_SETTINGS_FILE_EXISTS,
@@ -288,10 +399,22 @@ impl Rule for ClaudeSettingsRule {
                 CHECK_PRE_TOOL_USE_EXISTS,
                 "Verify PreToolUse hook array is configured",
             ),
+            CheckEntry::new(
+                CHECK_PRE_TOOL_USE_IS_ARRAY,
+                "Verify hooks.PreToolUse is an array rather than a single object",
+            ),
             CheckEntry::new(
                 CHECK_BASH_MATCHER_EXISTS,
                 "Verify Bash matcher hook is present to prevent dangerous commands",
             ),
+            CheckEntry::new(
+                CHECK_REQUIRED_MATCHER_EXISTS,
+                "Verify PreToolUse hooks configured by option additionalMatchers are present",
+            ),
+            CheckEntry::new(
+                CHECK_LOCAL_SETTINGS_IGNORED,
+                "Verify .claude/settings.local.json is excluded by .gitignore",
+            ),
         ]
     }
 
@@ -308,20 +431,26 @@ impl Rule for ClaudeSettingsRule {
                 vec![
                     CHECK_HOOKS_OBJECT_EXISTS,
                     CHECK_PRE_TOOL_USE_EXISTS,
+                    CHECK_PRE_TOOL_USE_IS_ARRAY,
                     CHECK_BASH_MATCHER_EXISTS,
+                    CHECK_REQUIRED_MATCHER_EXISTS,
                 ],
             ),
+            FixEntry::new(
+                FIX_IGNORE_LOCAL_SETTINGS,
+                "Append .claude/settings.local.json to .gitignore",
+                vec![CHECK_LOCAL_SETTINGS_IGNORED],
+            ),
         ]
     }
 
     fn check(&self, context: &RuleContext) -> Vec<LintResult> {
         let mut results = Vec::new();
+        let required = self.required_hooks(context);
 
-        // Find all git repositories
-        let repos = self.find_git_repos(&context.root);
-
-        for repo in repos {
-            results.extend(self.check_repo(&repo));
+        for repo in self.target_dirs(context) {
+            results.extend(self.check_repo(&repo, &required));
+            results.extend(self.check_local_settings(&repo));
         }
 
         results
@@ -329,24 +458,22 @@ impl Rule for ClaudeSettingsRule {
 
     fn fix(&self, context: &RuleContext) -> Result<u32, RuleError> {
         let mut fixed = 0;
+        let required = self.required_hooks(context);
 
-        // Find all git repositories
-        let repos = self.find_git_repos(&context.root);
-
-        for repo in repos {
+        for repo in self.target_dirs(context) {
             let claude_dir = repo.join(".claude");
             let settings_path = claude_dir.join("settings.json");
 
-            if !settings_path.exists() {
+            if !context.file_exists(&settings_path) {
                 // Create the .claude directory and settings.json file
                 // write_file handles creating parent directories
-                context.write_file(&settings_path, &self.default_settings_content())?;
+                context.write_file(&settings_path, &self.default_settings_content(&required))?;
                 fixed += 1;
             } else {
                 // File exists - deep merge to add missing hooks without overriding existing content
                 if let Ok(content) = context.read_file(&settings_path) {
                     if let Ok(mut existing) = serde_json::from_str::<Value>(&content) {
-                        if self.deep_merge_hooks(&mut existing) {
+                        if self.deep_merge_hooks(&mut existing, &required) {
                             let merged_content = serde_json::to_string_pretty(&existing)?;
                             context.write_file(&settings_path, &merged_content)?;
                             fixed += 1;
@@ -354,6 +481,12 @@ impl Rule for ClaudeSettingsRule {
                     }
                 }
             }
+
+            if self.local_settings_committable(&repo)
+                && gitignore::append_entry(context, &repo, LOCAL_SETTINGS)?
+            {
+                fixed += 1;
+            }
         }
 
         Ok(fixed)
@@ -374,7 +507,7 @@ mod tests {
     }
 
     fn create_context(root: PathBuf) -> RuleContext {
-        RuleContext::new(root, true, serde_json::json!({}))
+        RuleContext::new(root, serde_json::json!({}))
     }
 
     #[test]
@@ -617,7 +750,7 @@ mod tests {
         let rule = ClaudeSettingsRule::new();
         let mut existing = json!({});
 
-        let changed = rule.deep_merge_hooks(&mut existing);
+        let changed = rule.deep_merge_hooks(&mut existing, &[rule.get_required_bash_hook()]);
 
         assert!(changed);
         assert!(existing.get("hooks").is_some());
@@ -635,7 +768,7 @@ mod tests {
             }
         });
 
-        let changed = rule.deep_merge_hooks(&mut existing);
+        let changed = rule.deep_merge_hooks(&mut existing, &[rule.get_required_bash_hook()]);
 
         assert!(changed);
         assert!(existing["hooks"].get("PreToolUse").is_some());
@@ -653,8 +786,205 @@ mod tests {
             }
         });
 
-        let changed = rule.deep_merge_hooks(&mut existing);
+        let changed = rule.deep_merge_hooks(&mut existing, &[rule.get_required_bash_hook()]);
 
         assert!(!changed);
     }
+
+    #[test]
+    fn test_local_settings_not_ignored_is_flagged_and_fixed() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo_root = setup_git_repo(&temp_dir);
+        let context = create_context(repo_root.clone());
+        let rule = ClaudeSettingsRule::new();
+        rule.fix(&context).unwrap();
+        fs::write(repo_root.join(LOCAL_SETTINGS), "{}").unwrap();
+        fs::write(repo_root.join(".gitignore"), "node_modules\n").unwrap();
+
+        let results = rule.check(&context);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].check_id, CHECK_LOCAL_SETTINGS_IGNORED);
+
+        assert_eq!(rule.fix(&context).unwrap(), 1);
+        assert_eq!(
+            fs::read_to_string(repo_root.join(".gitignore")).unwrap(),
+            "node_modules\n.claude/settings.local.json\n"
+        );
+        assert!(rule.check(&context).is_empty());
+        assert_eq!(rule.fix(&context).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_local_settings_ignored_by_pattern_is_accepted() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo_root = setup_git_repo(&temp_dir);
+        let context = create_context(repo_root.clone());
+        let rule = ClaudeSettingsRule::new();
+        rule.fix(&context).unwrap();
+        fs::write(repo_root.join(LOCAL_SETTINGS), "{}").unwrap();
+        fs::write(repo_root.join(".gitignore"), "*.local.json\n").unwrap();
+
+        assert!(rule.check(&context).is_empty());
+    }
+
+    #[test]
+    fn test_pre_tool_use_object_is_flagged_and_wrapped() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo_root = setup_git_repo(&temp_dir);
+        let settings_path = repo_root.join(".claude/settings.json");
+        fs::create_dir_all(repo_root.join(".claude")).unwrap();
+        fs::write(
+            &settings_path,
+            r#"{"hooks": {"PreToolUse": {"matcher": "Edit", "hooks": [{"type": "command", "command": "lint"}]}}}"#,
+        )
+        .unwrap();
+
+        let rule = ClaudeSettingsRule::new();
+        let context = create_context(repo_root);
+        let results: Vec<_> = rule
+            .check(&context)
+            .into_iter()
+            .filter(|r| r.check_id == CHECK_PRE_TOOL_USE_IS_ARRAY)
+            .collect();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].severity, "error");
+        assert_eq!(results[0].fixable_by, vec![FIX_MERGE_HOOKS]);
+
+        rule.fix(&context).unwrap();
+        let content: Value =
+            serde_json::from_str(&fs::read_to_string(&settings_path).unwrap()).unwrap();
+        let pre_tool_use = content["hooks"]["PreToolUse"].as_array().unwrap();
+        assert_eq!(pre_tool_use.len(), 2);
+        assert_eq!(pre_tool_use[0]["matcher"], "Edit");
+        assert_eq!(pre_tool_use[1]["matcher"], "Bash");
+        assert!(rule
+            .check(&context)
+            .iter()
+            .all(|r| r.check_id != CHECK_PRE_TOOL_USE_IS_ARRAY));
+    }
+
+    #[test]
+    fn test_pre_tool_use_of_unknown_shape_is_unfixable() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo_root = setup_git_repo(&temp_dir);
+        let settings_path = repo_root.join(".claude/settings.json");
+        fs::create_dir_all(repo_root.join(".claude")).unwrap();
+        let original = r#"{"hooks": {"PreToolUse": "bash ./check.sh"}}"#;
+        fs::write(&settings_path, original).unwrap();
+
+        let rule = ClaudeSettingsRule::new();
+        let context = create_context(repo_root);
+        let results: Vec<_> = rule
+            .check(&context)
+            .into_iter()
+            .filter(|r| r.check_id == CHECK_PRE_TOOL_USE_IS_ARRAY)
+            .collect();
+        assert_eq!(results.len(), 1);
+        assert!(results[0].fixable_by.is_empty());
+
+        rule.fix(&context).unwrap();
+        let content: Value =
+            serde_json::from_str(&fs::read_to_string(&settings_path).unwrap()).unwrap();
+        assert_eq!(content["hooks"]["PreToolUse"], "bash ./check.sh");
+    }
+
+    #[test]
+    fn test_workspace_root_option_targets_only_the_root() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = setup_git_repo(&temp_dir);
+        for submodule in ["packages/api", "packages/web"] {
+            fs::create_dir_all(root.join(submodule).join(".git")).unwrap();
+        }
+        let rule = ClaudeSettingsRule::new();
+
+        let per_repo = create_context(root.clone());
+        assert_eq!(rule.check(&per_repo).len(), 3);
+
+        let workspace = RuleContext::new(
+            root.clone(),
+            serde_json::json!({"claudeAtWorkspaceRoot": true}),
+        );
+        let results = rule.check(&workspace);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].path, root.to_string_lossy());
+
+        assert_eq!(rule.fix(&workspace).unwrap(), 1);
+        assert!(root.join(".claude/settings.json").is_file());
+        assert!(!root.join("packages/api/.claude").exists());
+        assert!(!root.join("packages/web/.claude").exists());
+        assert!(rule.check(&workspace).is_empty());
+        // Per-repo targeting still wants settings in each nested repository
+        assert_eq!(rule.check(&per_repo).len(), 2);
+    }
+
+    #[test]
+    fn test_additional_matchers_are_required_and_merged() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo_root = setup_git_repo(&temp_dir);
+        let claude_dir = repo_root.join(".claude");
+        fs::create_dir_all(&claude_dir).unwrap();
+        fs::write(
+            claude_dir.join("settings.json"),
+            serde_json::to_string_pretty(&json!({
+                "hooks": {"PreToolUse": [ClaudeSettingsRule::new().get_required_bash_hook()]}
+            }))
+            .unwrap(),
+        )
+        .unwrap();
+        let rule = ClaudeSettingsRule::new();
+        let context = RuleContext::new(
+            repo_root.clone(),
+            json!({"additionalMatchers": [
+                {"matcher": "Write", "command": "./scripts/guard-write.sh"}
+            ]}),
+        );
+
+        // The Bash hook alone satisfies the default configuration
+        assert!(rule.check(&create_context(repo_root.clone())).is_empty());
+        let results = rule.check(&context);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].check_id, CHECK_REQUIRED_MATCHER_EXISTS);
+        assert_eq!(results[0].message, "PreToolUse hooks missing Write matcher");
+        assert_eq!(results[0].fixable_by, vec![FIX_MERGE_HOOKS]);
+
+        assert_eq!(rule.fix(&context).unwrap(), 1);
+
+        let settings: Value =
+            serde_json::from_str(&fs::read_to_string(claude_dir.join("settings.json")).unwrap())
+                .unwrap();
+        let pre_tool_use = settings["hooks"]["PreToolUse"].as_array().unwrap();
+        assert_eq!(pre_tool_use.len(), 2);
+        assert_eq!(pre_tool_use[1]["matcher"], "Write");
+        assert_eq!(pre_tool_use[1]["hooks"][0]["command"], "./scripts/guard-write.sh");
+        assert!(rule.check(&context).is_empty());
+    }
+
+    #[test]
+    fn test_create_settings_includes_additional_matchers() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo_root = setup_git_repo(&temp_dir);
+        let rule = ClaudeSettingsRule::new();
+        let context = RuleContext::new(
+            repo_root.clone(),
+            json!({"additionalMatchers": [
+                {"matcher": "Write", "command": "true"},
+                {"matcher": "Edit"}
+            ]}),
+        );
+
+        assert_eq!(rule.fix(&context).unwrap(), 1);
+
+        let settings: Value = serde_json::from_str(
+            &fs::read_to_string(repo_root.join(".claude/settings.json")).unwrap(),
+        )
+        .unwrap();
+        let matchers: Vec<&str> = settings["hooks"]["PreToolUse"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(|h| h["matcher"].as_str())
+            .collect();
+        // Entries without a command are ignored
+        assert_eq!(matchers, vec!["Bash", "Write"]);
+    }
 }
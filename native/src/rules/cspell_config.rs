@@ -1,18 +1,23 @@
-use crate::rules::{Rule, RuleError};
+use crate::husky;
+use crate::jsonc;
+use crate::rules::{file_too_large_result, Rule, RuleError};
 use crate::types::{CheckEntry, FixEntry, LintResult, RuleContext, Severity};
 use serde_json::Value;
-use std::path::{Path, PathBuf};
-use walkdir::WalkDir;
+use std::path::Path;
 
 // Check IDs
 const CHECK_CSPELL_JSON_EXISTS: &str = "cspell-json-exists";
 const CHECK_CSPELL_DEPENDENCY: &str = "cspell-dependency";
 const CHECK_CSPELL_PRE_COMMIT: &str = "cspell-pre-commit-hook";
+const CHECK_CSPELL_WORDS_BUDGET: &str = "cspell-words-budget";
+const CHECK_CSPELL_LANGUAGE: &str = "cspell-language";
+const CHECK_CSPELL_CONFIG_EXPORTS: &str = "cspell-config-exports";
 
 // Fix IDs
 const FIX_CREATE_CSPELL_JSON: &str = "create-cspell-json";
 const FIX_ADD_CSPELL_DEPENDENCY: &str = "add-cspell-dependency";
 const FIX_ADD_CSPELL_PRE_COMMIT: &str = "add-cspell-pre-commit";
+const FIX_SET_CSPELL_LANGUAGE: &str = "set-cspell-language";
 
 /// Rule: Ensure projects have cspell configured for spell checking
 pub struct CspellConfigRule;
@@ -22,35 +27,8 @@ impl CspellConfigRule {
         Self
     }
 
-    /// Find all package.json files in the given root (excluding node_modules)
-    fn find_package_jsons(&self, root: &Path) -> Vec<PathBuf> {
-        let mut package_jsons = Vec::new();
-
-        for entry in WalkDir::new(root)
-            .follow_links(false)
-            .into_iter()
-            .filter_map(|e| e.ok())
-        {
-            let path = entry.path();
-
-            // Skip node_modules directories
-            if path
-                .components()
-                .any(|c| c.as_os_str() == "node_modules")
-            {
-                continue;
-            }
-
-            if path.is_file() && path.file_name().is_some_and(|n| n == "package.json") {
-                package_jsons.push(path.to_path_buf());
-            }
-        }
-
-        package_jsons
-    }
-
     /// Check a single project directory for cspell configuration
-    fn check_project(&self, package_json_path: &Path) -> Vec<LintResult> {
+    fn check_project(&self, package_json_path: &Path, context: &RuleContext) -> Vec<LintResult> {
         let mut results = Vec::new();
         let project_dir = package_json_path.parent().unwrap_or(Path::new("."));
 
@@ -81,48 +59,35 @@ impl CspellConfigRule {
             ));
         }
 
+        // A JS config only counts if it exports something
+        for config_js in [&cspell_config_js, &cspell_config_cjs] {
+            results.extend(self.check_config_exports(config_js));
+        }
+
+        // Opt-in: cap the size of the inline words list
+        if let Some(max_words) = Self::max_words(context) {
+            results.extend(self.check_words_budget(&cspell_json, max_words));
+        }
+
+        // Opt-in: pin the spelling language
+        if let Some(language) = Self::require_language(context) {
+            results.extend(self.check_language(&cspell_json, &language));
+        }
+
         // Check 2: cspell dependency in package.json
-        match std::fs::read_to_string(package_json_path) {
-            Ok(content) => match serde_json::from_str::<Value>(&content) {
-                Ok(json) => {
-                    let has_cspell_dep = self.has_cspell_dependency(&json);
+        if let Some(json) = context.package_json_value(package_json_path) {
+            let has_cspell_dep = self.has_cspell_dependency(&json);
 
-                    if !has_cspell_dep {
-                        results.push(LintResult::new(
-                            self.id(),
-                            CHECK_CSPELL_DEPENDENCY,
-                            self.default_severity(),
-                            "Missing cspell in devDependencies".into(),
-                            package_json_path.to_path_buf(),
-                            None,
-                            Some("Add 'cspell' to devDependencies in package.json".into()),
-                            vec![FIX_ADD_CSPELL_DEPENDENCY],
-                        ));
-                    }
-                }
-                Err(e) => {
-                    results.push(LintResult::new(
-                        self.id(),
-                        CHECK_CSPELL_DEPENDENCY,
-                        Severity::Error,
-                        format!("Invalid JSON in package.json: {}", e),
-                        package_json_path.to_path_buf(),
-                        None,
-                        Some("Fix JSON syntax errors".into()),
-                        vec![],
-                    ));
-                }
-            },
-            Err(e) => {
+            if !has_cspell_dep {
                 results.push(LintResult::new(
                     self.id(),
                     CHECK_CSPELL_DEPENDENCY,
-                    Severity::Error,
-                    format!("Cannot read package.json: {}", e),
+                    self.default_severity(),
+                    "Missing cspell in devDependencies".into(),
                     package_json_path.to_path_buf(),
                     None,
-                    None,
-                    vec![],
+                    Some("Add 'cspell' to devDependencies in package.json".into()),
+                    vec![FIX_ADD_CSPELL_DEPENDENCY],
                 ));
             }
         }
@@ -130,7 +95,7 @@ impl CspellConfigRule {
         // Check 3: cspell in pre-commit hook
         let husky_pre_commit = project_dir.join(".husky").join("pre-commit");
         if husky_pre_commit.exists() {
-            match std::fs::read_to_string(&husky_pre_commit) {
+            match context.read_file_capped(&husky_pre_commit) {
                 Ok(content) => {
                     let has_cspell_hook = content.contains("cspell")
                         || content.contains("pnpm spell")
@@ -153,6 +118,9 @@ impl CspellConfigRule {
                         ));
                     }
                 }
+                Err(e) if e.kind() == std::io::ErrorKind::FileTooLarge => {
+                    results.push(file_too_large_result(self, &husky_pre_commit, &e));
+                }
                 Err(e) => {
                     results.push(LintResult::new(
                         self.id(),
@@ -186,6 +154,140 @@ impl CspellConfigRule {
         results
     }
 
+    /// Whether JS source assigns `module.exports`/`exports.x` or has an `export
+    /// default`, ignoring comment lines. A heuristic, since the file isn't run.
+    fn has_export(content: &str) -> bool {
+        content
+            .lines()
+            .map(str::trim_start)
+            .filter(|line| !["//", "/*", "*"].iter().any(|c| line.starts_with(c)))
+            .any(|line| {
+                line.contains("module.exports")
+                    || line.contains("exports.")
+                    || line.contains("export default")
+            })
+    }
+
+    /// Warn when cspell.config.js/cjs exists but doesn't appear to export a config
+    fn check_config_exports(&self, config_js: &Path) -> Option<LintResult> {
+        let content = std::fs::read_to_string(config_js).ok()?;
+        if Self::has_export(&content) {
+            return None;
+        }
+
+        let name = config_js.file_name()?.to_string_lossy();
+        Some(LintResult::new(
+            self.id(),
+            CHECK_CSPELL_CONFIG_EXPORTS,
+            Severity::Warning,
+            format!("{} does not export a cspell configuration", name),
+            config_js.to_path_buf(),
+            None,
+            Some(
+                "Export the config with 'module.exports = { ... }' or 'export default { ... }'"
+                    .into(),
+            ),
+            vec![], // Report-only: the intended config can't be inferred
+        ))
+    }
+
+    /// Read the `maxWords` option; the words budget check only runs when it is set
+    fn max_words(context: &RuleContext) -> Option<usize> {
+        context
+            .config
+            .get("maxWords")
+            .and_then(|v| v.as_u64())
+            .map(|n| n as usize)
+    }
+
+    /// Warn when cspell.json's `words` list is longer than the budget
+    fn check_words_budget(&self, cspell_json: &Path, max_words: usize) -> Option<LintResult> {
+        let content = std::fs::read_to_string(cspell_json).ok()?;
+        let json = jsonc::parse(&content).ok()?;
+        let count = json.get("words").and_then(|w| w.as_array())?.len();
+        if count <= max_words {
+            return None;
+        }
+
+        Some(LintResult::new(
+            self.id(),
+            CHECK_CSPELL_WORDS_BUDGET,
+            Severity::Warning,
+            format!(
+                "cspell.json lists {} words, more than the budget of {}",
+                count, max_words
+            ),
+            cspell_json.to_path_buf(),
+            None,
+            Some(
+                "Move project terms to a custom dictionary file (dictionaryDefinitions) \
+                 or use per-file overrides instead of growing 'words'"
+                    .into(),
+            ),
+            vec![], // Report-only: pruning the list needs a human
+        ))
+    }
+
+    /// Read the `requireLanguage` option; the language check only runs when it is set
+    fn require_language(context: &RuleContext) -> Option<String> {
+        context
+            .config
+            .get("requireLanguage")
+            .and_then(|v| v.as_str())
+            .map(String::from)
+    }
+
+    /// Report when cspell.json's `language` is missing or differs from the required one
+    fn check_language(&self, cspell_json: &Path, required: &str) -> Option<LintResult> {
+        let content = std::fs::read_to_string(cspell_json).ok()?;
+        let json = jsonc::parse(&content).ok()?;
+        let message = match json.get("language").and_then(|l| l.as_str()) {
+            Some(language) if language == required => return None,
+            Some(language) => format!(
+                "cspell.json language is '{}', expected '{}'",
+                language, required
+            ),
+            None => format!("cspell.json does not set language to '{}'", required),
+        };
+
+        Some(LintResult::new(
+            self.id(),
+            CHECK_CSPELL_LANGUAGE,
+            Severity::Warning,
+            message,
+            cspell_json.to_path_buf(),
+            None,
+            Some(format!("Set \"language\": \"{}\" in cspell.json", required)),
+            vec![FIX_SET_CSPELL_LANGUAGE],
+        ))
+    }
+
+    /// Set cspell.json's `language`, keeping the rest of the file and its comments
+    fn set_language(&self, project_dir: &Path, context: &RuleContext) -> Result<bool, RuleError> {
+        let Some(required) = Self::require_language(context) else {
+            return Ok(false);
+        };
+        let cspell_json = project_dir.join("cspell.json");
+        if !context.file_exists(&cspell_json) {
+            return Ok(false);
+        }
+
+        let content = context.read_file(&cspell_json)?;
+        let Ok(json) = jsonc::parse(&content) else {
+            return Ok(false); // Leave invalid files for the user to fix
+        };
+        if json.get("language").and_then(|l| l.as_str()) == Some(required.as_str()) {
+            return Ok(false);
+        }
+        let Some(updated) = jsonc::set_property(&content, &["language"], &Value::from(required))
+        else {
+            return Ok(false);
+        };
+        context.write_file(&cspell_json, &updated)?;
+
+        Ok(true)
+    }
+
     /// Check if package.json has cspell as a dependency
     fn has_cspell_dependency(&self, json: &Value) -> bool {
         // Check devDependencies
@@ -206,10 +308,14 @@ impl CspellConfigRule {
     }
 
     /// Create a basic cspell.json configuration file
-    fn create_cspell_json(&self, project_dir: &Path) -> std::io::Result<bool> {
+    fn create_cspell_json(
+        &self,
+        project_dir: &Path,
+        context: &RuleContext,
+    ) -> std::io::Result<bool> {
         let cspell_json_path = project_dir.join("cspell.json");
 
-        if cspell_json_path.exists() {
+        if context.file_exists(&cspell_json_path) {
             return Ok(false);
         }
 
@@ -231,7 +337,7 @@ impl CspellConfigRule {
         });
 
         let content = serde_json::to_string_pretty(&default_config)?;
-        std::fs::write(&cspell_json_path, content)?;
+        context.write_file(&cspell_json_path, &content)?;
 
         Ok(true)
     }
@@ -267,7 +373,11 @@ impl CspellConfigRule {
     }
 
     /// Add cspell check to pre-commit hook
-    fn add_cspell_pre_commit(&self, project_dir: &Path) -> std::io::Result<bool> {
+    fn add_cspell_pre_commit(
+        &self,
+        project_dir: &Path,
+        context: &RuleContext,
+    ) -> std::io::Result<bool> {
         let husky_dir = project_dir.join(".husky");
         let pre_commit_path = husky_dir.join("pre-commit");
 
@@ -278,32 +388,24 @@ impl CspellConfigRule {
 
         let cspell_command = "pnpm exec cspell --no-progress \"**/*.{ts,tsx,js,jsx,md,json}\"";
 
-        if pre_commit_path.exists() {
+        if context.file_exists(&pre_commit_path) {
             // Append to existing pre-commit hook
-            let content = std::fs::read_to_string(&pre_commit_path)?;
+            let content = context.read_file(&pre_commit_path)?;
 
             if content.contains("cspell") {
                 return Ok(false); // Already has cspell
             }
 
             let updated_content = format!("{}\n\n# Spell check\n{}\n", content.trim_end(), cspell_command);
-            std::fs::write(&pre_commit_path, updated_content)?;
+            context.write_file(&pre_commit_path, &updated_content)?;
         } else {
-            // Create new pre-commit hook
-            let content = format!(
-                "#!/usr/bin/env sh\n. \"$(dirname -- \"$0\")/_/husky.sh\"\n\n# Spell check\n{}\n",
-                cspell_command
+            // Create new pre-commit hook, with the husky.sh shim only for husky < 9
+            let content = husky::hook_content(
+                context.workspace().husky_version(project_dir),
+                &format!("# Spell check\n{}", cspell_command),
             );
-            std::fs::write(&pre_commit_path, content)?;
-
-            // Make executable on Unix
-            #[cfg(unix)]
-            {
-                use std::os::unix::fs::PermissionsExt;
-                let mut perms = std::fs::metadata(&pre_commit_path)?.permissions();
-                perms.set_mode(0o755);
-                std::fs::set_permissions(&pre_commit_path, perms)?;
-            }
+            context.write_file(&pre_commit_path, &content)?;
+            context.set_executable(&pre_commit_path)?;
         }
 
         Ok(true)
@@ -333,6 +435,10 @@ impl Rule for CspellConfigRule {
         Severity::Warning
     }
 
+    fn tags(&self) -> Vec<&'static str> {
+        vec!["quality"]
+    }
+
     fn checks(&self) -> Vec<CheckEntry> {
         vec![
             CheckEntry::new(
@@ -347,6 +453,18 @@ impl Rule for CspellConfigRule {
                 CHECK_CSPELL_PRE_COMMIT,
                 "Verify cspell check is in pre-commit hook",
             ),
+            CheckEntry::new(
+                CHECK_CSPELL_WORDS_BUDGET,
+                "Verify cspell.json 'words' stays within the budget (opt-in via option: maxWords)",
+            ),
+            CheckEntry::new(
+                CHECK_CSPELL_CONFIG_EXPORTS,
+                "Verify cspell.config.js/cjs exports a configuration (module.exports or export default)",
+            ),
+            CheckEntry::new(
+                CHECK_CSPELL_LANGUAGE,
+                "Verify cspell.json sets the required language (opt-in via option: requireLanguage)",
+            ),
         ]
     }
 
@@ -367,6 +485,11 @@ impl Rule for CspellConfigRule {
                 "Add cspell check to pre-commit hook",
                 vec![CHECK_CSPELL_PRE_COMMIT],
             ),
+            FixEntry::new(
+                FIX_SET_CSPELL_LANGUAGE,
+                "Set cspell.json 'language' to the required value (option: requireLanguage)",
+                vec![CHECK_CSPELL_LANGUAGE],
+            ),
         ]
     }
 
@@ -374,10 +497,10 @@ impl Rule for CspellConfigRule {
         let mut results = Vec::new();
 
         // Find all package.json files
-        let package_jsons = self.find_package_jsons(&context.root);
+        let package_jsons = context.package_jsons();
 
         for package_json in package_jsons {
-            results.extend(self.check_project(&package_json));
+            results.extend(self.check_project(&package_json, context));
         }
 
         results
@@ -387,13 +510,13 @@ impl Rule for CspellConfigRule {
         let mut fixed = 0;
 
         // Find all package.json files
-        let package_jsons = self.find_package_jsons(&context.root);
+        let package_jsons = context.package_jsons();
 
         for package_json in package_jsons {
             let project_dir = package_json.parent().unwrap_or(Path::new("."));
 
             // Fix 1: Create cspell.json if missing
-            if self.create_cspell_json(project_dir)? {
+            if self.create_cspell_json(project_dir, context)? {
                 fixed += 1;
             }
 
@@ -403,7 +526,12 @@ impl Rule for CspellConfigRule {
             }
 
             // Fix 3: Add cspell to pre-commit hook
-            if self.add_cspell_pre_commit(project_dir)? {
+            if self.add_cspell_pre_commit(project_dir, context)? {
+                fixed += 1;
+            }
+
+            // Fix 4: Pin the cspell language when required
+            if self.set_language(project_dir, context)? {
                 fixed += 1;
             }
         }
@@ -416,10 +544,11 @@ impl Rule for CspellConfigRule {
 mod tests {
     use super::*;
     use std::fs;
+    use std::path::PathBuf;
     use tempfile::TempDir;
 
     fn create_context(root: PathBuf) -> RuleContext {
-        RuleContext::new(root, true, serde_json::json!({}))
+        RuleContext::new(root, serde_json::json!({}))
     }
 
     #[test]
@@ -703,4 +832,168 @@ mod tests {
         // Should not report errors from node_modules
         assert!(!results.iter().any(|r| r.path.contains("node_modules")));
     }
+
+    #[test]
+    fn test_fix_creates_v8_pre_commit_with_shim() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+
+        fs::write(
+            root.join("package.json"),
+            r#"{"name": "test", "devDependencies": {"cspell": "^8.0.0", "husky": "^8.0.3"}}"#,
+        )
+        .unwrap();
+        fs::write(root.join("cspell.json"), r#"{"version": "0.2"}"#).unwrap();
+        fs::create_dir_all(root.join(".husky")).unwrap();
+
+        let rule = CspellConfigRule::new();
+        let context = create_context(root.clone());
+        rule.fix(&context).unwrap();
+
+        let content = fs::read_to_string(root.join(".husky").join("pre-commit")).unwrap();
+        assert!(content.starts_with("#!/usr/bin/env sh\n"));
+        assert!(content.contains("_/husky.sh"));
+        assert!(content.contains("cspell"));
+    }
+
+    #[test]
+    fn test_fix_creates_v9_pre_commit_without_shim() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+
+        fs::write(
+            root.join("package.json"),
+            r#"{"name": "test", "devDependencies": {"cspell": "^8.0.0", "husky": "^9.1.7"}}"#,
+        )
+        .unwrap();
+        fs::write(root.join("cspell.json"), r#"{"version": "0.2"}"#).unwrap();
+        fs::create_dir_all(root.join(".husky")).unwrap();
+
+        let rule = CspellConfigRule::new();
+        let context = create_context(root.clone());
+        rule.fix(&context).unwrap();
+
+        let content = fs::read_to_string(root.join(".husky").join("pre-commit")).unwrap();
+        assert!(!content.contains("husky.sh"));
+        assert!(content.starts_with("# Spell check\n"));
+        assert!(content.contains("cspell"));
+    }
+
+    fn setup_words(count: usize) -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("package.json"),
+            r#"{"name": "test", "devDependencies": {"cspell": "^8.0.0"}}"#,
+        )
+        .unwrap();
+        let words: Vec<String> = (0..count).map(|i| format!("word{}", i)).collect();
+        fs::write(
+            temp_dir.path().join("cspell.json"),
+            serde_json::json!({"version": "0.2", "words": words}).to_string(),
+        )
+        .unwrap();
+        temp_dir
+    }
+
+    fn words_budget_results(temp_dir: &TempDir, options: Value) -> Vec<LintResult> {
+        let context = RuleContext::new(temp_dir.path().to_path_buf(), options);
+        CspellConfigRule::new()
+            .check(&context)
+            .into_iter()
+            .filter(|r| r.check_id == CHECK_CSPELL_WORDS_BUDGET)
+            .collect()
+    }
+
+    #[test]
+    fn test_words_over_budget_warns() {
+        let temp_dir = setup_words(12);
+
+        let results = words_budget_results(&temp_dir, serde_json::json!({"maxWords": 10}));
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].severity, "warning");
+        assert_eq!(
+            results[0].message,
+            "cspell.json lists 12 words, more than the budget of 10"
+        );
+        assert!(results[0].fixable_by.is_empty());
+    }
+
+    #[test]
+    fn test_words_under_budget_or_without_option_passes() {
+        let temp_dir = setup_words(10);
+        assert!(words_budget_results(&temp_dir, serde_json::json!({"maxWords": 10})).is_empty());
+
+        let temp_dir = setup_words(500);
+        assert!(words_budget_results(&temp_dir, serde_json::json!({})).is_empty());
+    }
+
+    #[test]
+    fn test_required_language_is_flagged_and_fixed() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(
+            root.join("package.json"),
+            r#"{"name": "test", "devDependencies": {"cspell": "^8.0.0"}}"#,
+        )
+        .unwrap();
+        fs::write(
+            root.join("cspell.json"),
+            "{\n  // British spelling for now\n  \"version\": \"0.2\",\n  \"language\": \"en-GB\"\n}\n",
+        )
+        .unwrap();
+        let rule = CspellConfigRule::new();
+        let context = RuleContext::new(
+            root.to_path_buf(),
+            serde_json::json!({"requireLanguage": "en"}),
+        );
+
+        let results: Vec<_> = rule
+            .check(&context)
+            .into_iter()
+            .filter(|r| r.check_id == CHECK_CSPELL_LANGUAGE)
+            .collect();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].message, "cspell.json language is 'en-GB', expected 'en'");
+        assert_eq!(results[0].fixable_by, vec![FIX_SET_CSPELL_LANGUAGE]);
+
+        assert_eq!(rule.fix(&context).unwrap(), 1);
+
+        let content = fs::read_to_string(root.join("cspell.json")).unwrap();
+        assert!(content.contains("// British spelling for now"));
+        assert_eq!(jsonc::parse(&content).unwrap()["language"], "en");
+        assert!(rule.check(&context).is_empty());
+        // Without the option the language is not checked
+        fs::write(root.join("cspell.json"), r#"{"version": "0.2"}"#).unwrap();
+        assert!(rule.check(&create_context(root.to_path_buf())).is_empty());
+    }
+
+    #[test]
+    fn test_empty_cspell_config_js_is_flagged() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::write(
+            root.join("package.json"),
+            r#"{"name": "test", "devDependencies": {"cspell": "^8.0.0"}}"#,
+        )
+        .unwrap();
+        fs::write(root.join("cspell.config.js"), "// TODO: configure cspell\n").unwrap();
+        let rule = CspellConfigRule::new();
+        let context = create_context(root.clone());
+
+        let results = rule.check(&context);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].check_id, CHECK_CSPELL_CONFIG_EXPORTS);
+        assert_eq!(
+            results[0].message,
+            "cspell.config.js does not export a cspell configuration"
+        );
+        assert!(results[0].fixable_by.is_empty());
+
+        fs::write(
+            root.join("cspell.config.js"),
+            "/** @type {import('cspell').CSpellUserSettings} */\nmodule.exports = {\n  words: [],\n};\n",
+        )
+        .unwrap();
+        assert!(rule.check(&context).is_empty());
+    }
 }
@@ -0,0 +1,427 @@
+use crate::rules::{Rule, RuleError};
+use crate::types::{CheckEntry, FixEntry, LintResult, RuleContext, Severity};
+use serde_json::Value;
+use std::path::Path;
+
+// Check IDs
+const CHECK_TSC_REQUIRES_TYPESCRIPT: &str = "tsc-requires-typescript";
+const CHECK_ENTRYPOINTS_EXIST: &str = "entrypoints-exist";
+
+// Fix IDs
+const FIX_ADD_TYPESCRIPT: &str = "add-typescript-dev-dependency";
+
+/// Version range added when no `typescriptVersion` option is configured
+const DEFAULT_TYPESCRIPT_VERSION: &str = "^5.0.0";
+
+/// package.json fields naming a single entry point file
+const ENTRYPOINT_FIELDS: &[&str] = &["main", "module", "types"];
+
+/// Output directories a build step creates when no `buildOutputDirs` option is configured
+const DEFAULT_BUILD_OUTPUT_DIRS: &[&str] = &["dist", "build"];
+
+/// Rule: Ensure package.json declares the dependencies its scripts rely on
+pub struct DependencyHygieneRule;
+
+impl DependencyHygieneRule {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Read the `typescriptVersion` option, falling back to the default range
+    fn typescript_version(&self, context: &RuleContext) -> String {
+        context
+            .config
+            .get("typescriptVersion")
+            .and_then(|v| v.as_str())
+            .unwrap_or(DEFAULT_TYPESCRIPT_VERSION)
+            .to_string()
+    }
+
+    /// Read the `buildOutputDirs` option, falling back to the defaults
+    fn build_output_dirs(&self, context: &RuleContext) -> Vec<String> {
+        match context.config.get("buildOutputDirs").and_then(|v| v.as_array()) {
+            Some(dirs) => dirs.iter().filter_map(|d| d.as_str()).map(String::from).collect(),
+            None => DEFAULT_BUILD_OUTPUT_DIRS.iter().map(|d| d.to_string()).collect(),
+        }
+    }
+
+    fn has_dependency(json: &Value, name: &str) -> bool {
+        ["dependencies", "devDependencies"].iter().any(|key| {
+            json.get(key)
+                .and_then(|d| d.as_object())
+                .is_some_and(|deps| deps.contains_key(name))
+        })
+    }
+
+    /// Check whether a script runs `bin` as a command word, so `tsc --noEmit` and
+    /// `pnpm exec tsc` match but `tsc-alias` or `node scripts/tsconfig.js` don't
+    fn script_invokes(script: &str, bin: &str) -> bool {
+        script
+            .split(|c: char| c.is_whitespace() || matches!(c, ';' | '&' | '|' | '(' | ')'))
+            .any(|token| token == bin)
+    }
+
+    /// Names of the scripts that invoke `bin`
+    fn scripts_invoking<'a>(json: &'a Value, bin: &str) -> Vec<&'a str> {
+        json.get("scripts")
+            .and_then(|s| s.as_object())
+            .map(|scripts| {
+                scripts
+                    .iter()
+                    .filter(|(_, cmd)| cmd.as_str().is_some_and(|c| Self::script_invokes(c, bin)))
+                    .map(|(name, _)| name.as_str())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Whether a package.json between the package and the scan root declares the
+    /// dependency; pnpm puts workspace-root binaries on PATH for package scripts
+    fn ancestor_has_dependency(context: &RuleContext, package_dir: &Path, name: &str) -> bool {
+        package_dir
+            .ancestors()
+            .skip(1)
+            .take_while(|dir| dir.starts_with(&context.root))
+            .map(|dir| dir.join("package.json"))
+            .filter(|path| path.is_file())
+            .filter_map(|path| context.package_json_value(&path))
+            .any(|json| Self::has_dependency(&json, name))
+    }
+
+    /// Whether the package runs `tsc` without typescript being available
+    fn missing_typescript(
+        &self,
+        json: &Value,
+        package_json_path: &Path,
+        context: &RuleContext,
+    ) -> bool {
+        let package_dir = package_json_path.parent().unwrap_or(Path::new("."));
+        !Self::scripts_invoking(json, "tsc").is_empty()
+            && !Self::has_dependency(json, "typescript")
+            && !Self::ancestor_has_dependency(context, package_dir, "typescript")
+    }
+
+    fn check_package_json(
+        &self,
+        package_json_path: &Path,
+        context: &RuleContext,
+    ) -> Option<LintResult> {
+        let json = context.package_json_value(package_json_path)?;
+
+        if !self.missing_typescript(&json, package_json_path, context) {
+            return None;
+        }
+
+        Some(LintResult::new(
+            self.id(),
+            CHECK_TSC_REQUIRES_TYPESCRIPT,
+            self.default_severity(),
+            format!(
+                "Scripts invoke tsc but typescript is not a dependency: {}",
+                Self::scripts_invoking(&json, "tsc").join(", ")
+            ),
+            package_json_path.to_path_buf(),
+            None,
+            Some("Add 'typescript' to devDependencies".into()),
+            vec![FIX_ADD_TYPESCRIPT],
+        ))
+    }
+
+    /// Collect every file target of an `exports` value: a string, an array of
+    /// fallbacks, or an object of subpaths/conditions nested to any depth
+    fn export_targets<'a>(exports: &'a Value, targets: &mut Vec<&'a str>) {
+        match exports {
+            Value::String(target) => targets.push(target),
+            Value::Array(items) => items.iter().for_each(|v| Self::export_targets(v, targets)),
+            Value::Object(map) => map.values().for_each(|v| Self::export_targets(v, targets)),
+            _ => {}
+        }
+    }
+
+    /// Entry point targets as (field, target) pairs, skipping subpath patterns
+    fn entrypoints(json: &Value) -> Vec<(&'static str, &str)> {
+        let mut entrypoints: Vec<(&'static str, &str)> = ENTRYPOINT_FIELDS
+            .iter()
+            .filter_map(|&field| Some((field, json.get(field)?.as_str()?)))
+            .collect();
+        if let Some(exports) = json.get("exports") {
+            let mut targets = Vec::new();
+            Self::export_targets(exports, &mut targets);
+            entrypoints.extend(targets.into_iter().map(|target| ("exports", target)));
+        }
+        entrypoints.retain(|(_, target)| !target.contains('*'));
+        entrypoints
+    }
+
+    /// Whether a missing target lives in a build output directory that hasn't been
+    /// created yet, so a build step presumably generates it
+    fn awaiting_build(package_dir: &Path, relative: &Path, build_dirs: &[String]) -> bool {
+        let Some(first) = relative.components().next() else {
+            return false;
+        };
+        let first = first.as_os_str().to_string_lossy();
+        build_dirs.iter().any(|dir| *dir == first) && !package_dir.join(&*first).exists()
+    }
+
+    /// Report entry points declared in package.json that don't exist on disk.
+    /// Report-only: the file may need writing or the field may be stale.
+    fn check_entrypoints(
+        &self,
+        package_json_path: &Path,
+        json: &Value,
+        context: &RuleContext,
+    ) -> Vec<LintResult> {
+        let package_dir = package_json_path.parent().unwrap_or(Path::new("."));
+        let build_dirs = self.build_output_dirs(context);
+
+        Self::entrypoints(json)
+            .into_iter()
+            .filter(|(_, target)| {
+                let relative = Path::new(target.trim_start_matches("./"));
+                !relative.is_absolute()
+                    && !package_dir.join(relative).exists()
+                    && !Self::awaiting_build(package_dir, relative, &build_dirs)
+            })
+            .map(|(field, target)| {
+                LintResult::new(
+                    self.id(),
+                    CHECK_ENTRYPOINTS_EXIST,
+                    self.default_severity(),
+                    format!("'{}' points at missing file '{}'", field, target),
+                    package_json_path.to_path_buf(),
+                    None,
+                    Some(format!("Create '{}' or update the '{}' field", target, field)),
+                    vec![], // Report-only
+                )
+            })
+            .collect()
+    }
+
+    /// Add typescript to devDependencies in package.json
+    fn add_typescript(
+        &self,
+        package_json_path: &Path,
+        context: &RuleContext,
+    ) -> Result<bool, RuleError> {
+        let content = context.read_file(package_json_path)?;
+        let mut json: Value = serde_json::from_str(&content)?;
+
+        if !self.missing_typescript(&json, package_json_path, context) {
+            return Ok(false);
+        }
+
+        if json.get("devDependencies").is_none() {
+            json["devDependencies"] = serde_json::json!({});
+        }
+        if let Some(dev_deps) = json.get_mut("devDependencies").and_then(|d| d.as_object_mut()) {
+            dev_deps.insert(
+                "typescript".to_string(),
+                Value::String(self.typescript_version(context)),
+            );
+        }
+
+        let updated_content = serde_json::to_string_pretty(&json)?;
+        context.write_file(package_json_path, &updated_content)?;
+
+        Ok(true)
+    }
+}
+
+impl Default for DependencyHygieneRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Rule for DependencyHygieneRule {
+    fn id(&self) -> &'static str {
+        "dependency-hygiene"
+    }
+
+    fn name(&self) -> &'static str {
+        "Dependency Hygiene"
+    }
+
+    fn description(&self) -> &'static str {
+        "Ensures package.json declares the tools its scripts depend on"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn tags(&self) -> Vec<&'static str> {
+        vec!["package-manager"]
+    }
+
+    fn checks(&self) -> Vec<CheckEntry> {
+        vec![
+            CheckEntry::new(
+                CHECK_TSC_REQUIRES_TYPESCRIPT,
+                "Verify packages whose scripts run tsc declare typescript as a dependency",
+            ),
+            CheckEntry::new(
+                CHECK_ENTRYPOINTS_EXIST,
+                "Verify main/module/types/exports targets exist, unless an unbuilt output dir \
+                 holds them (option: buildOutputDirs)",
+            ),
+        ]
+    }
+
+    fn fixes(&self) -> Vec<FixEntry> {
+        vec![FixEntry::new(
+            FIX_ADD_TYPESCRIPT,
+            "Add typescript to devDependencies (option: typescriptVersion)",
+            vec![CHECK_TSC_REQUIRES_TYPESCRIPT],
+        )]
+    }
+
+    fn check(&self, context: &RuleContext) -> Vec<LintResult> {
+        let mut results = Vec::new();
+
+        for path in context.package_jsons() {
+            results.extend(self.check_package_json(&path, context));
+            if let Some(json) = context.package_json_value(&path) {
+                results.extend(self.check_entrypoints(&path, &json, context));
+            }
+        }
+
+        results
+    }
+
+    fn fix(&self, context: &RuleContext) -> Result<u32, RuleError> {
+        let mut fixed = 0;
+
+        for package_json in context.package_jsons() {
+            if self.add_typescript(&package_json, context)? {
+                fixed += 1;
+            }
+        }
+
+        Ok(fixed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    fn create_context(root: PathBuf, options: Value) -> RuleContext {
+        RuleContext::new(root, options)
+    }
+
+    #[test]
+    fn test_flags_tsc_without_typescript() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::write(
+            root.join("package.json"),
+            r#"{"name": "test", "scripts": {"build": "tsc -p .", "lint": "eslint ."}}"#,
+        )
+        .unwrap();
+
+        let rule = DependencyHygieneRule::new();
+        let results = rule.check(&create_context(root, Value::Null));
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].check_id, CHECK_TSC_REQUIRES_TYPESCRIPT);
+        assert!(results[0].message.ends_with(": build"));
+    }
+
+    #[test]
+    fn test_script_invokes_matches_whole_words_only() {
+        assert!(DependencyHygieneRule::script_invokes("tsc --noEmit", "tsc"));
+        assert!(DependencyHygieneRule::script_invokes("rimraf dist&&tsc", "tsc"));
+        assert!(DependencyHygieneRule::script_invokes("pnpm exec tsc -b", "tsc"));
+        assert!(!DependencyHygieneRule::script_invokes("tsc-alias -p tsconfig.json", "tsc"));
+        assert!(!DependencyHygieneRule::script_invokes("node scripts/tsc.js", "tsc"));
+    }
+
+    #[test]
+    fn test_accepts_typescript_from_workspace_root() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::write(
+            root.join("package.json"),
+            r#"{"name": "root", "devDependencies": {"typescript": "^5.4.0"}}"#,
+        )
+        .unwrap();
+        let package_dir = root.join("packages/core");
+        fs::create_dir_all(&package_dir).unwrap();
+        fs::write(
+            package_dir.join("package.json"),
+            r#"{"name": "core", "scripts": {"typecheck": "tsc --noEmit"}}"#,
+        )
+        .unwrap();
+
+        let rule = DependencyHygieneRule::new();
+        assert!(rule.check(&create_context(root, Value::Null)).is_empty());
+    }
+
+    #[test]
+    fn test_flags_missing_main_target() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::create_dir_all(root.join("lib")).unwrap();
+        fs::write(root.join("lib/index.d.ts"), "").unwrap();
+        fs::write(
+            root.join("package.json"),
+            r#"{"name": "test", "main": "./lib/index.js", "types": "lib/index.d.ts"}"#,
+        )
+        .unwrap();
+
+        let rule = DependencyHygieneRule::new();
+        let results = rule.check(&create_context(root, Value::Null));
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].check_id, CHECK_ENTRYPOINTS_EXIST);
+        assert_eq!(results[0].message, "'main' points at missing file './lib/index.js'");
+        assert!(results[0].fixable_by.is_empty());
+    }
+
+    #[test]
+    fn test_entrypoints_in_unbuilt_output_dir_are_skipped() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::write(
+            root.join("package.json"),
+            r#"{"name": "test", "main": "dist/index.js", "exports": {".": {"import": "./out/index.mjs", "types": "./dist/index.d.ts"}, "./*": "./dist/*.js"}}"#,
+        )
+        .unwrap();
+        let rule = DependencyHygieneRule::new();
+
+        let results = rule.check(&create_context(root.clone(), Value::Null));
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].message, "'exports' points at missing file './out/index.mjs'");
+
+        // Once dist exists its targets are expected to be there
+        fs::create_dir_all(root.join("dist")).unwrap();
+        let context = create_context(root, serde_json::json!({"buildOutputDirs": ["out"]}));
+        assert_eq!(rule.check(&context).len(), 2);
+    }
+
+    #[test]
+    fn test_fix_adds_configured_typescript_version() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::write(
+            root.join("package.json"),
+            r#"{"name": "test", "scripts": {"build": "tsc"}, "devDependencies": {"vitest": "^1.0.0"}}"#,
+        )
+        .unwrap();
+
+        let rule = DependencyHygieneRule::new();
+        let context = create_context(root.clone(), serde_json::json!({"typescriptVersion": "~5.4.5"}));
+
+        assert_eq!(rule.fix(&context).unwrap(), 1);
+        let json: Value =
+            serde_json::from_str(&fs::read_to_string(root.join("package.json")).unwrap()).unwrap();
+        assert_eq!(json["devDependencies"]["typescript"], "~5.4.5");
+        assert_eq!(json["devDependencies"]["vitest"], "^1.0.0");
+        assert!(rule.check(&context).is_empty());
+        assert_eq!(rule.fix(&context).unwrap(), 0);
+    }
+}
@@ -0,0 +1,214 @@
+use crate::rules::Rule;
+use crate::types::{CheckEntry, FixEntry, LintResult, RuleContext, Severity};
+use crate::walk;
+use std::path::{Path, PathBuf};
+
+// Check IDs
+const CHECK_PINNED_BASE: &str = "pinned-base-image";
+
+/// Directories that never contain first-party Dockerfiles
+const IGNORED_DIRS: &[&str] = &["node_modules", ".git"];
+
+/// Rule: Ensure Dockerfiles build from pinned base images
+pub struct DockerfilePinnedBaseRule;
+
+impl DockerfilePinnedBaseRule {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Matches `Dockerfile`, `Dockerfile.prod` and `api.Dockerfile`
+    fn is_dockerfile(path: &Path) -> bool {
+        path.file_name()
+            .and_then(|n| n.to_str())
+            .is_some_and(|name| {
+                name == "Dockerfile" || name.starts_with("Dockerfile.") || name.ends_with(".Dockerfile")
+            })
+    }
+
+    fn find_dockerfiles(&self, context: &RuleContext) -> Vec<PathBuf> {
+        walk::entries(&context.root, context.follow_symlinks, IGNORED_DIRS)
+            .into_iter()
+            .filter(|e| e.path().is_file() && Self::is_dockerfile(e.path()))
+            .map(|e| e.into_path())
+            .collect()
+    }
+
+    /// Whether an image reference is pinned to a digest or an explicit, non-latest tag
+    fn is_pinned(image: &str) -> bool {
+        if image.contains('@') {
+            return true;
+        }
+        // The tag follows the last ':' after the final '/' (a registry port may precede it)
+        let name = image.rsplit('/').next().unwrap_or(image);
+        match name.split_once(':') {
+            Some((_, tag)) => !tag.is_empty() && tag != "latest",
+            None => false,
+        }
+    }
+
+    /// Return `(line, image)` for each FROM instruction with an unpinned base image.
+    /// References to earlier build stages, `scratch`, and images built from ARG
+    /// variables are skipped since they aren't pulled as written.
+    fn unpinned_bases(content: &str) -> Vec<(u32, String)> {
+        let mut stages: Vec<String> = Vec::new();
+        let mut unpinned = Vec::new();
+
+        for (i, line) in content.lines().enumerate() {
+            let mut tokens = line.split_whitespace();
+            if !tokens.next().is_some_and(|t| t.eq_ignore_ascii_case("FROM")) {
+                continue;
+            }
+
+            let mut tokens = tokens.skip_while(|t| t.starts_with("--"));
+            let Some(image) = tokens.next() else {
+                continue;
+            };
+            let alias = match (tokens.next(), tokens.next()) {
+                (Some(kw), Some(alias)) if kw.eq_ignore_ascii_case("AS") => {
+                    Some(alias.to_lowercase())
+                }
+                _ => None,
+            };
+
+            let lowered = image.to_lowercase();
+            let skip = lowered == "scratch" || image.contains('$') || stages.contains(&lowered);
+            if !skip && !Self::is_pinned(image) {
+                unpinned.push((i as u32 + 1, image.to_string()));
+            }
+
+            if let Some(alias) = alias {
+                stages.push(alias);
+            }
+        }
+
+        unpinned
+    }
+
+    fn check_file(&self, path: &Path) -> Vec<LintResult> {
+        let Ok(content) = std::fs::read_to_string(path) else {
+            return Vec::new();
+        };
+
+        Self::unpinned_bases(&content)
+            .into_iter()
+            .map(|(line, image)| {
+                LintResult::new(
+                    self.id(),
+                    CHECK_PINNED_BASE,
+                    self.default_severity(),
+                    format!("Base image '{}' is not pinned to a version", image),
+                    path.to_path_buf(),
+                    Some(line),
+                    Some(format!(
+                        "Pin '{}' to a specific tag (e.g. node:20.11.0) or digest (@sha256:...)",
+                        image.split(':').next().unwrap_or(&image)
+                    )),
+                    vec![], // Report-only: no safe way to choose a version
+                )
+            })
+            .collect()
+    }
+}
+
+impl Default for DockerfilePinnedBaseRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Rule for DockerfilePinnedBaseRule {
+    fn id(&self) -> &'static str {
+        "dockerfile-pinned-base"
+    }
+
+    fn name(&self) -> &'static str {
+        "Dockerfile Pinned Base Image"
+    }
+
+    fn description(&self) -> &'static str {
+        "Ensures Dockerfiles use base images pinned to a tag or digest instead of latest"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn tags(&self) -> Vec<&'static str> {
+        vec!["docker"]
+    }
+
+    fn checks(&self) -> Vec<CheckEntry> {
+        vec![CheckEntry::new(
+            CHECK_PINNED_BASE,
+            "Verify every FROM instruction uses a pinned tag or digest (not :latest or tagless)",
+        )]
+    }
+
+    fn fixes(&self) -> Vec<FixEntry> {
+        vec![]
+    }
+
+    fn check(&self, context: &RuleContext) -> Vec<LintResult> {
+        self.find_dockerfiles(context)
+            .iter()
+            .flat_map(|path| self.check_file(path))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn check_dockerfile(content: &str) -> Vec<LintResult> {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("Dockerfile"), content).unwrap();
+        let context = RuleContext::new(temp_dir.path().to_path_buf(), serde_json::json!({}));
+        DockerfilePinnedBaseRule::new().check(&context)
+    }
+
+    #[test]
+    fn test_flags_latest_tag() {
+        let results = check_dockerfile("FROM node:latest\nRUN pnpm install\n");
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].check_id, CHECK_PINNED_BASE);
+        assert_eq!(results[0].line, Some(1));
+        assert!(results[0].message.contains("node:latest"));
+    }
+
+    #[test]
+    fn test_accepts_pinned_tag() {
+        assert!(check_dockerfile("FROM node:20.11.0\nRUN pnpm install\n").is_empty());
+    }
+
+    #[test]
+    fn test_multi_stage_build() {
+        let results = check_dockerfile(
+            "FROM --platform=linux/amd64 node:20.11.0-alpine AS build\n\
+             RUN pnpm build\n\
+             FROM build AS test\n\
+             FROM nginx\n\
+             COPY --from=build /app/dist /usr/share/nginx/html\n",
+        );
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].line, Some(4));
+        assert!(results[0].message.contains("'nginx'"));
+    }
+
+    #[test]
+    fn test_is_pinned() {
+        assert!(DockerfilePinnedBaseRule::is_pinned("node@sha256:abc123"));
+        assert!(DockerfilePinnedBaseRule::is_pinned("localhost:5000/node:20"));
+        assert!(!DockerfilePinnedBaseRule::is_pinned("localhost:5000/node"));
+        assert!(!DockerfilePinnedBaseRule::is_pinned("ghcr.io/org/app:latest"));
+    }
+}
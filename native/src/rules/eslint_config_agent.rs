@@ -1,9 +1,11 @@
-use crate::rules::{Rule, RuleError};
+use crate::gitignore;
+use crate::rules::{file_too_large_result, Rule, RuleError};
 use crate::types::{CheckEntry, FixEntry, LintResult, RuleContext, Severity};
+use regex::Regex;
+use semver::{Version, VersionReq};
 use serde_json::Value;
-use std::path::{Path, PathBuf};
-use std::process::Command;
-use walkdir::WalkDir;
+use std::path::Path;
+use std::sync::LazyLock;
 
 // Check IDs
 const CHECK_DEPENDENCY_EXISTS: &str = "eslint-config-agent-dependency";
@@ -11,11 +13,36 @@ const CHECK_CONFIG_FILE_EXISTS: &str = "eslint-config-mjs-exists";
 const CHECK_CONFIG_USES_AGENT: &str = "eslint-config-uses-agent";
 const CHECK_NO_OVERRIDES: &str = "no-custom-overrides";
 const CHECK_NO_LEGACY_CONFIG: &str = "no-legacy-eslint-config";
+const CHECK_PRETTIER_COMPATIBLE: &str = "prettier-compatible";
+const CHECK_CONFIG_NOT_IGNORED: &str = "eslint-config-not-gitignored";
+const CHECK_AGENT_VERSION: &str = "eslint-config-agent-version";
+const CHECK_SINGLE_FLAT_CONFIG: &str = "single-flat-config";
 
 // Fix IDs
 const FIX_INSTALL_DEPENDENCY: &str = "install-eslint-config-agent";
 const FIX_CREATE_CONFIG: &str = "create-eslint-config-mjs";
 const FIX_REMOVE_LEGACY: &str = "remove-legacy-eslint-configs";
+const FIX_KEEP_SINGLE_FLAT_CONFIG: &str = "keep-single-flat-config";
+
+/// Extensions ESLint loads `eslint.config.*` flat configs from, in lookup order
+const FLAT_CONFIG_EXTENSIONS: &[&str] = &["js", "mjs", "cjs", "ts", "mts", "cts"];
+
+/// Module specifiers of the import and re-export statements in a JS module.
+/// Comments are dropped first so a commented-out import doesn't count.
+fn import_specifiers(content: &str) -> Vec<String> {
+    static COMMENT: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"(?s)/\*.*?\*/|//[^\n]*").unwrap());
+    static IMPORT: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r#"(?m)(?:^|;)\s*(?:import\s*|(?:import|export)\b[^;'"]*?\bfrom\s*)["']([^"']+)["']"#)
+            .unwrap()
+    });
+
+    let code = COMMENT.replace_all(content, "");
+    IMPORT
+        .captures_iter(&code)
+        .map(|captures| captures[1].to_string())
+        .collect()
+}
 
 /// Rule: Ensure projects use eslint-config-agent as the only ESLint configuration
 pub struct EslintConfigAgentRule;
@@ -25,60 +52,242 @@ impl EslintConfigAgentRule {
         Self
     }
 
-    /// Find all package.json files in the given root (excluding node_modules)
-    fn find_package_jsons(&self, root: &Path) -> Vec<PathBuf> {
-        let mut package_jsons = Vec::new();
+    /// Check if a package.json represents a JavaScript/TypeScript project that should have ESLint
+    fn is_js_project(&self, json: &Value) -> bool {
+        // Check for common JS indicators
+        let has_deps = json.get("dependencies").is_some() || json.get("devDependencies").is_some();
+        let has_scripts = json.get("scripts").is_some();
+        has_deps || has_scripts
+    }
 
-        for entry in WalkDir::new(root)
-            .follow_links(false)
-            .into_iter()
-            .filter_map(|e| e.ok())
-        {
-            let path = entry.path();
+    /// Check if eslint-config-agent is in dependencies
+    fn has_eslint_config_agent(&self, json: &Value) -> bool {
+        let check_deps = |deps_key: &str| -> bool {
+            json.get(deps_key)
+                .and_then(|d| d.as_object())
+                .is_some_and(|deps| deps.contains_key("eslint-config-agent"))
+        };
 
-            // Skip node_modules directories
-            if path
-                .components()
-                .any(|c| c.as_os_str() == "node_modules")
-            {
-                continue;
-            }
+        check_deps("dependencies") || check_deps("devDependencies")
+    }
 
-            if path.is_file() && path.file_name().is_some_and(|n| n == "package.json") {
-                package_jsons.push(path.to_path_buf());
-            }
+    /// Whether the `assumeInstalled` option is set, meaning eslint-config-agent is
+    /// provided some other way (vendored, workspace catalog) and must not be installed
+    fn assume_installed(&self, context: &RuleContext) -> bool {
+        context
+            .config
+            .get("assumeInstalled")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false)
+    }
+
+    /// Read the `minAgentVersion` option; `2` and `2.1` are padded to full versions
+    fn min_agent_version(&self, context: &RuleContext) -> Option<Version> {
+        let raw = context.config.get("minAgentVersion")?.as_str()?.trim();
+        let raw = raw.trim_start_matches('v');
+        let padding = 2usize.saturating_sub(raw.matches('.').count());
+        Version::parse(&format!("{}{}", raw, ".0".repeat(padding))).ok()
+    }
+
+    /// Extension of the flat config to keep when several exist, from the
+    /// `preferredConfigExtension` option (default "mjs")
+    fn preferred_extension(context: &RuleContext) -> String {
+        context
+            .config
+            .get("preferredConfigExtension")
+            .and_then(|v| v.as_str())
+            .map(|ext| ext.trim_start_matches('.').to_string())
+            .unwrap_or_else(|| "mjs".to_string())
+    }
+
+    /// File names of the flat configs present in a directory
+    fn flat_configs(parent_dir: &Path, context: &RuleContext) -> Vec<String> {
+        FLAT_CONFIG_EXTENSIONS
+            .iter()
+            .map(|ext| format!("eslint.config.{}", ext))
+            .filter(|name| context.file_exists(&parent_dir.join(name)))
+            .collect()
+    }
+
+    /// The flat config to keep among several: the preferred one if present,
+    /// otherwise the first in ESLint's lookup order
+    fn flat_config_to_keep(configs: &[String], context: &RuleContext) -> Option<String> {
+        let preferred = format!("eslint.config.{}", Self::preferred_extension(context));
+        if configs.contains(&preferred) {
+            Some(preferred)
+        } else {
+            configs.first().cloned()
         }
+    }
 
-        package_jsons
+    /// Flag directories holding more than one `eslint.config.*`, since only the
+    /// first ESLint finds is used
+    fn check_single_flat_config(&self, parent_dir: &Path, context: &RuleContext) -> Option<LintResult> {
+        let configs = Self::flat_configs(parent_dir, context);
+        if configs.len() < 2 {
+            return None;
+        }
+        let keep = Self::flat_config_to_keep(&configs, context)?;
+
+        Some(LintResult::new(
+            self.id(),
+            CHECK_SINGLE_FLAT_CONFIG,
+            Severity::Warning,
+            format!("Found multiple ESLint flat configs: {}", configs.join(", ")),
+            parent_dir.join(&keep),
+            None,
+            Some(format!("Consolidate into {} and remove the others", keep)),
+            vec![FIX_KEEP_SINGLE_FLAT_CONFIG],
+        ))
     }
 
-    /// Check if a package.json represents a JavaScript/TypeScript project that should have ESLint
-    fn is_js_project(&self, package_json_path: &Path) -> bool {
-        if let Ok(content) = std::fs::read_to_string(package_json_path) {
-            if let Ok(json) = serde_json::from_str::<Value>(&content) {
-                // Check for common JS indicators
-                let has_deps = json.get("dependencies").is_some()
-                    || json.get("devDependencies").is_some();
-                let has_scripts = json.get("scripts").is_some();
-                return has_deps || has_scripts;
+    /// The version range declared for eslint-config-agent, if any
+    fn declared_agent_range(json: &Value) -> Option<&str> {
+        ["dependencies", "devDependencies"]
+            .iter()
+            .find_map(|key| json.get(key)?.get("eslint-config-agent")?.as_str())
+    }
+
+    /// Translate one npm range alternative (no `||`) into Cargo's comma-separated
+    /// syntax: `>= 1.2 <3` becomes `>=1.2, <3`, a hyphen range `1 - 2` becomes
+    /// `>=1, <=2`, and a bare version is exact as in npm
+    fn npm_alternative_to_req(alternative: &str) -> Option<VersionReq> {
+        let tokens: Vec<&str> = alternative.split_whitespace().collect();
+        if let [low, "-", high] = tokens.as_slice() {
+            return VersionReq::parse(&format!(">={}, <={}", low, high)).ok();
+        }
+
+        let mut comparators: Vec<String> = Vec::new();
+        let mut pending_op = String::new();
+        for token in tokens {
+            if token.chars().all(|c| matches!(c, '<' | '>' | '=' | '~' | '^')) {
+                pending_op = token.to_string();
+                continue;
             }
+            let comparator = format!("{}{}", std::mem::take(&mut pending_op), token);
+            let bare = comparator.starts_with(|c: char| c.is_ascii_digit() || c == 'v');
+            let exact = bare && !comparator.contains(['x', 'X', '*']);
+            let comparator = comparator.trim_start_matches('v').to_string();
+            comparators.push(if exact { format!("={}", comparator) } else { comparator });
+        }
+        if comparators.is_empty() {
+            comparators.push("*".into());
         }
-        false
+        VersionReq::parse(&comparators.join(", ")).ok()
     }
 
-    /// Check if eslint-config-agent is in dependencies
-    fn has_eslint_config_agent(&self, json: &Value) -> bool {
-        let check_deps = |deps_key: &str| -> bool {
-            json.get(deps_key)
+    /// Whether an npm range allows some version at or above `min`. None when the
+    /// range isn't a semver range (`workspace:*`, `latest`, git URLs, ...).
+    fn range_reaches(range: &str, min: &Version) -> Option<bool> {
+        let alternatives = range
+            .split("||")
+            .map(Self::npm_alternative_to_req)
+            .collect::<Option<Vec<VersionReq>>>()?;
+
+        Some(alternatives.iter().any(|req| {
+            // The minimum itself, or the lowest version a comparator names above it
+            req.matches(min)
+                || req.comparators.iter().any(|c| {
+                    let mut version =
+                        Version::new(c.major, c.minor.unwrap_or(0), c.patch.unwrap_or(0));
+                    version.pre = c.pre.clone();
+                    version >= *min && req.matches(&version)
+                })
+        }))
+    }
+
+    /// Warn when the declared eslint-config-agent range can't reach the configured
+    /// `minAgentVersion`. Report-only: bumping a major version is a deliberate change.
+    fn check_agent_version(
+        &self,
+        json: &Value,
+        package_json_path: &Path,
+        context: &RuleContext,
+    ) -> Option<LintResult> {
+        let min = self.min_agent_version(context)?;
+        let range = Self::declared_agent_range(json)?;
+        if Self::range_reaches(range, &min)? {
+            return None;
+        }
+
+        Some(LintResult::new(
+            self.id(),
+            CHECK_AGENT_VERSION,
+            Severity::Warning,
+            format!(
+                "eslint-config-agent range '{}' can't satisfy the minimum version {}",
+                range, min
+            ),
+            package_json_path.to_path_buf(),
+            None,
+            Some(format!("Upgrade with 'pnpm add -D eslint-config-agent@^{}'", min)),
+            vec![], // Report-only
+        ))
+    }
+
+    /// Check if a package is declared in dependencies or devDependencies
+    fn has_dependency(json: &Value, name: &str) -> bool {
+        ["dependencies", "devDependencies"].iter().any(|key| {
+            json.get(key)
                 .and_then(|d| d.as_object())
-                .is_some_and(|deps| deps.contains_key("eslint-config-agent"))
-        };
+                .is_some_and(|deps| deps.contains_key(name))
+        })
+    }
 
-        check_deps("dependencies") || check_deps("devDependencies")
+    /// Whether formatting conflicts with Prettier are already handled, either by
+    /// the project depending on eslint-config-prettier or by the installed
+    /// eslint-config-agent bringing it in itself
+    fn handles_prettier(&self, json: &Value, parent_dir: &Path) -> bool {
+        if Self::has_dependency(json, "eslint-config-prettier") {
+            return true;
+        }
+
+        let agent_package_json = parent_dir
+            .join("node_modules")
+            .join("eslint-config-agent")
+            .join("package.json");
+        std::fs::read_to_string(agent_package_json)
+            .ok()
+            .and_then(|content| serde_json::from_str::<Value>(&content).ok())
+            .is_some_and(|agent| {
+                ["dependencies", "peerDependencies"].iter().any(|key| {
+                    agent
+                        .get(key)
+                        .and_then(|d| d.as_object())
+                        .is_some_and(|deps| deps.contains_key("eslint-config-prettier"))
+                })
+            })
+    }
+
+    /// Warn when .gitignore would keep eslint.config.mjs out of the repository
+    fn check_config_not_ignored(
+        &self,
+        eslint_config_path: &Path,
+        context: &RuleContext,
+    ) -> Option<LintResult> {
+        let repo_root = gitignore::repo_root(eslint_config_path.parent()?, &context.root);
+        let relative = eslint_config_path.strip_prefix(&repo_root).ok()?;
+        if !gitignore::is_ignored(&repo_root, relative, false) {
+            return None;
+        }
+
+        Some(LintResult::new(
+            self.id(),
+            CHECK_CONFIG_NOT_IGNORED,
+            Severity::Warning,
+            "eslint.config.mjs is excluded by .gitignore, so it won't be committed".into(),
+            eslint_config_path.to_path_buf(),
+            None,
+            Some(format!(
+                "Add '!{}' to .gitignore or narrow the pattern that matches it",
+                relative.display()
+            )),
+            vec![], // Report-only: the matching pattern may be intentional elsewhere
+        ))
     }
 
     /// Check eslint.config.mjs content
-    fn check_eslint_config(&self, parent_dir: &Path) -> Vec<LintResult> {
+    fn check_eslint_config(&self, parent_dir: &Path, context: &RuleContext) -> Vec<LintResult> {
         let mut results = Vec::new();
         let eslint_config_path = parent_dir.join("eslint.config.mjs");
 
@@ -99,16 +308,31 @@ impl EslintConfigAgentRule {
             return results;
         }
 
+        results.extend(self.check_config_not_ignored(&eslint_config_path, context));
+
         // Read and check content
-        match std::fs::read_to_string(&eslint_config_path) {
+        match context.read_file_capped(&eslint_config_path) {
             Ok(content) => {
-                // Check if it imports from eslint-config-agent
-                if !content.contains("eslint-config-agent") {
+                // Check that it imports the eslint-config-agent package itself, not a
+                // vendored local copy or a mention in a comment
+                let specifiers = import_specifiers(&content);
+                let uses_package = specifiers
+                    .iter()
+                    .any(|s| s == "eslint-config-agent" || s.starts_with("eslint-config-agent/"));
+                if !uses_package {
+                    let local = specifiers.iter().find(|s| s.starts_with('.') || s.starts_with('/'));
+                    let message = match local {
+                        Some(local) => format!(
+                            "eslint.config.mjs imports '{}' instead of the eslint-config-agent package",
+                            local
+                        ),
+                        None => "eslint.config.mjs does not use eslint-config-agent".into(),
+                    };
                     results.push(LintResult::new(
                         self.id(),
                         CHECK_CONFIG_USES_AGENT,
                         self.default_severity(),
-                        "eslint.config.mjs does not use eslint-config-agent".into(),
+                        message,
                         eslint_config_path.clone(),
                         None,
                         Some(
@@ -141,6 +365,9 @@ impl EslintConfigAgentRule {
                     ));
                 }
             }
+            Err(e) if e.kind() == std::io::ErrorKind::FileTooLarge => {
+                results.push(file_too_large_result(self, &eslint_config_path, &e));
+            }
             Err(e) => {
                 results.push(LintResult::new(
                     self.id(),
@@ -159,81 +386,81 @@ impl EslintConfigAgentRule {
     }
 
     /// Check a single package.json and its ESLint configuration
-    fn check_package_json(&self, package_json_path: &Path) -> Vec<LintResult> {
+    fn check_package_json(&self, package_json_path: &Path, context: &RuleContext) -> Vec<LintResult> {
+        let assume_installed = self.assume_installed(context);
         let mut results = Vec::new();
         let parent_dir = package_json_path.parent().unwrap_or(Path::new("."));
 
+        // Parse package.json
+        let Some(json) = context.package_json_value(package_json_path) else {
+            return results;
+        };
+
         // Only check JS/TS projects
-        if !self.is_js_project(package_json_path) {
+        if !self.is_js_project(&json) {
             return results;
         }
 
-        // Parse package.json
-        match std::fs::read_to_string(package_json_path) {
-            Ok(content) => match serde_json::from_str::<Value>(&content) {
-                Ok(json) => {
-                    // Check for eslint-config-agent dependency
-                    if !self.has_eslint_config_agent(&json) {
-                        results.push(LintResult::new(
-                            self.id(),
-                            CHECK_DEPENDENCY_EXISTS,
-                            self.default_severity(),
-                            "Missing eslint-config-agent in devDependencies".into(),
-                            package_json_path.to_path_buf(),
-                            None,
-                            Some("Install eslint-config-agent using 'pnpm add -D eslint-config-agent@latest'".into()),
-                            vec![FIX_INSTALL_DEPENDENCY],
-                        ));
-                    }
-
-                    // Check for old ESLint config files that should be removed
-                    let old_configs = [".eslintrc", ".eslintrc.js", ".eslintrc.json", ".eslintrc.yml", ".eslintrc.yaml", "eslint.config.js"];
-                    for old_config in old_configs {
-                        let old_path = parent_dir.join(old_config);
-                        if old_path.exists() {
-                            results.push(LintResult::new(
-                                self.id(),
-                                CHECK_NO_LEGACY_CONFIG,
-                                Severity::Warning,
-                                format!("Found legacy ESLint config file: {}", old_config),
-                                old_path,
-                                None,
-                                Some(format!("Remove {} and use eslint.config.mjs with eslint-config-agent", old_config)),
-                                vec![FIX_REMOVE_LEGACY],
-                            ));
-                        }
-                    }
-
-                    // Check eslint.config.mjs
-                    results.extend(self.check_eslint_config(parent_dir));
-                }
-                Err(e) => {
-                    results.push(LintResult::new(
-                        self.id(),
-                        CHECK_DEPENDENCY_EXISTS,
-                        Severity::Error,
-                        format!("Invalid JSON in package.json: {}", e),
-                        package_json_path.to_path_buf(),
-                        None,
-                        Some("Fix JSON syntax errors".into()),
-                        vec![], // Cannot auto-fix invalid JSON
-                    ));
-                }
-            },
-            Err(e) => {
+        // Check for eslint-config-agent dependency
+        if !assume_installed && !self.has_eslint_config_agent(&json) {
+            results.push(LintResult::new(
+                self.id(),
+                CHECK_DEPENDENCY_EXISTS,
+                self.default_severity(),
+                "Missing eslint-config-agent in devDependencies".into(),
+                package_json_path.to_path_buf(),
+                None,
+                Some("Install eslint-config-agent using 'pnpm add -D eslint-config-agent@latest'".into()),
+                vec![FIX_INSTALL_DEPENDENCY],
+            ));
+        }
+
+        results.extend(self.check_agent_version(&json, package_json_path, context));
+
+        // Check for old ESLint config files that should be removed
+        let old_configs = [".eslintrc", ".eslintrc.js", ".eslintrc.json", ".eslintrc.yml", ".eslintrc.yaml", "eslint.config.js"];
+        for old_config in old_configs {
+            let old_path = parent_dir.join(old_config);
+            if old_path.exists() {
                 results.push(LintResult::new(
                     self.id(),
-                    CHECK_DEPENDENCY_EXISTS,
-                    Severity::Error,
-                    format!("Cannot read package.json: {}", e),
-                    package_json_path.to_path_buf(),
-                    None,
+                    CHECK_NO_LEGACY_CONFIG,
+                    Severity::Warning,
+                    format!("Found legacy ESLint config file: {}", old_config),
+                    old_path,
                     None,
-                    vec![], // Cannot auto-fix read errors
+                    Some(format!("Remove {} and use eslint.config.mjs with eslint-config-agent", old_config)),
+                    vec![FIX_REMOVE_LEGACY],
                 ));
             }
         }
 
+        results.extend(self.check_single_flat_config(parent_dir, context));
+
+        // Advise on ESLint/Prettier formatting conflicts
+        if !context.quiet
+            && Self::has_dependency(&json, "prettier")
+            && !self.handles_prettier(&json, parent_dir)
+        {
+            results.push(LintResult::new(
+                self.id(),
+                CHECK_PRETTIER_COMPATIBLE,
+                Severity::Info,
+                "Prettier is installed but ESLint formatting rules may conflict with it"
+                    .into(),
+                package_json_path.to_path_buf(),
+                None,
+                Some(
+                    "Add 'eslint-config-prettier' to disable ESLint rules that conflict with Prettier"
+                        .into(),
+                ),
+                vec![], // Advisory only
+            ));
+        }
+
+        // Check eslint.config.mjs
+        results.extend(self.check_eslint_config(parent_dir, context));
+
         results
     }
 
@@ -252,28 +479,28 @@ export default config;
         let parent_dir = package_json_path.parent().unwrap_or(Path::new("."));
 
         // Only fix JS/TS projects
-        if !self.is_js_project(package_json_path) {
+        let Some(json) = context.package_json_value(package_json_path) else {
+            return Ok(0);
+        };
+        if !self.is_js_project(&json) {
             return Ok(0);
         }
 
         // Check if we need to install eslint-config-agent
-        let content = context.read_file(package_json_path)?;
-        let json: Value = serde_json::from_str(&content)?;
-
-        if !self.has_eslint_config_agent(&json) {
+        if !self.assume_installed(context) && !self.has_eslint_config_agent(&json) {
             // Install eslint-config-agent using pnpm
-            let install_result = Command::new("pnpm")
-                .args(["add", "-D", "eslint-config-agent@latest"])
-                .current_dir(parent_dir)
-                .output();
+            let install_result = context.run_command(
+                "pnpm",
+                &["add", "-D", "eslint-config-agent@latest"],
+                parent_dir,
+            );
 
             match install_result {
                 Ok(output) if output.status.success() => {
                     fixed += 1;
                 }
                 Ok(output) => {
-                    return Err(RuleError::Io(std::io::Error::new(
-                        std::io::ErrorKind::Other,
+                    return Err(RuleError::Io(std::io::Error::other(
                         format!(
                             "Failed to install eslint-config-agent: {}",
                             String::from_utf8_lossy(&output.stderr)
@@ -290,17 +517,27 @@ export default config;
         let old_configs = [".eslintrc", ".eslintrc.js", ".eslintrc.json", ".eslintrc.yml", ".eslintrc.yaml", "eslint.config.js"];
         for old_config in old_configs {
             let old_path = parent_dir.join(old_config);
-            if old_path.exists() {
-                std::fs::remove_file(&old_path)?;
+            if context.file_exists(&old_path) {
+                context.remove_file(&old_path)?;
                 fixed += 1;
             }
         }
 
+        // Keep a single flat config among those left
+        let configs = Self::flat_configs(parent_dir, context);
+        if configs.len() > 1 {
+            let keep = Self::flat_config_to_keep(&configs, context);
+            for config in configs.iter().filter(|c| Some(*c) != keep.as_ref()) {
+                context.remove_file(&parent_dir.join(config))?;
+            }
+            fixed += 1;
+        }
+
         // Create or update eslint.config.mjs
         let eslint_config_path = parent_dir.join("eslint.config.mjs");
         let expected_content = self.get_eslint_config_content();
 
-        let needs_update = if eslint_config_path.exists() {
+        let needs_update = if context.file_exists(&eslint_config_path) {
             let current_content = context.read_file(&eslint_config_path)?;
             // Check if current content differs from expected
             current_content.trim() != expected_content.trim()
@@ -340,6 +577,10 @@ impl Rule for EslintConfigAgentRule {
         Severity::Error
     }
 
+    fn tags(&self) -> Vec<&'static str> {
+        vec!["agent", "quality"]
+    }
+
     fn checks(&self) -> Vec<CheckEntry> {
         vec![
             CheckEntry::new(
@@ -352,7 +593,7 @@ impl Rule for EslintConfigAgentRule {
             ),
             CheckEntry::new(
                 CHECK_CONFIG_USES_AGENT,
-                "Verify eslint.config.mjs imports from eslint-config-agent",
+                "Verify eslint.config.mjs imports the eslint-config-agent package, not a local copy",
             ),
             CheckEntry::new(
                 CHECK_NO_OVERRIDES,
@@ -362,6 +603,22 @@ impl Rule for EslintConfigAgentRule {
                 CHECK_NO_LEGACY_CONFIG,
                 "Verify no legacy ESLint config files exist (.eslintrc, etc.)",
             ),
+            CheckEntry::new(
+                CHECK_PRETTIER_COMPATIBLE,
+                "Suggest eslint-config-prettier when Prettier is used alongside ESLint",
+            ),
+            CheckEntry::new(
+                CHECK_CONFIG_NOT_IGNORED,
+                "Verify eslint.config.mjs is not excluded by .gitignore",
+            ),
+            CheckEntry::new(
+                CHECK_AGENT_VERSION,
+                "Verify the eslint-config-agent range can reach minAgentVersion (opt-in)",
+            ),
+            CheckEntry::new(
+                CHECK_SINGLE_FLAT_CONFIG,
+                "Verify a directory has at most one eslint.config.* file",
+            ),
         ]
     }
 
@@ -382,17 +639,29 @@ impl Rule for EslintConfigAgentRule {
                 "Remove legacy ESLint config files (.eslintrc, .eslintrc.js, etc.)",
                 vec![CHECK_NO_LEGACY_CONFIG],
             ),
+            FixEntry::new(
+                FIX_KEEP_SINGLE_FLAT_CONFIG,
+                "Keep eslint.config.mjs (option: preferredConfigExtension) and remove other flat configs",
+                vec![CHECK_SINGLE_FLAT_CONFIG],
+            ),
         ]
     }
 
+    fn fix_requirements(&self, fix_id: &str) -> Vec<&'static str> {
+        match fix_id {
+            FIX_INSTALL_DEPENDENCY => vec!["pnpm"],
+            _ => vec![],
+        }
+    }
+
     fn check(&self, context: &RuleContext) -> Vec<LintResult> {
         let mut results = Vec::new();
 
         // Find all package.json files
-        let package_jsons = self.find_package_jsons(&context.root);
+        let package_jsons = context.package_jsons();
 
         for package_json in package_jsons {
-            results.extend(self.check_package_json(&package_json));
+            results.extend(self.check_package_json(&package_json, context));
         }
 
         results
@@ -402,7 +671,7 @@ impl Rule for EslintConfigAgentRule {
         let mut fixed = 0;
 
         // Find all package.json files
-        let package_jsons = self.find_package_jsons(&context.root);
+        let package_jsons = context.package_jsons();
 
         for package_json in package_jsons {
             fixed += self.fix_package(&package_json, context)?;
@@ -415,11 +684,14 @@ impl Rule for EslintConfigAgentRule {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::command::RecordingCommandRunner;
     use std::fs;
+    use std::path::PathBuf;
+    use std::sync::Arc;
     use tempfile::TempDir;
 
     fn create_context(root: PathBuf) -> RuleContext {
-        RuleContext::new(root, true, serde_json::json!({}))
+        RuleContext::new(root, serde_json::json!({}))
     }
 
     #[test]
@@ -492,6 +764,59 @@ mod tests {
             .any(|r| r.message.contains("does not use eslint-config-agent")));
     }
 
+    #[test]
+    fn test_import_specifiers_skip_comments() {
+        let content = r#"// Based on eslint-config-agent
+/* import config from "eslint-config-agent"; */
+import {
+  base,
+  strict,
+} from "eslint-config-agent/presets"; import "./setup.js";
+export * from '../shared/eslint.js';
+export default { files: ["**/*.ts"] };
+"#;
+
+        assert_eq!(
+            import_specifiers(content),
+            vec!["eslint-config-agent/presets", "./setup.js", "../shared/eslint.js"]
+        );
+    }
+
+    #[test]
+    fn test_detects_relative_import_of_vendored_config() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::write(
+            root.join("package.json"),
+            r#"{"name": "test", "devDependencies": {"eslint-config-agent": "^1.0.0"}}"#,
+        )
+        .unwrap();
+        fs::write(
+            root.join("eslint.config.mjs"),
+            "// Vendored copy of eslint-config-agent\nimport config from \"./eslint-agent.js\";\n\nexport default config;\n",
+        )
+        .unwrap();
+        let rule = EslintConfigAgentRule::new();
+        let context = create_context(root.clone());
+
+        let results: Vec<_> = rule
+            .check(&context)
+            .into_iter()
+            .filter(|r| r.check_id == CHECK_CONFIG_USES_AGENT)
+            .collect();
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].message,
+            "eslint.config.mjs imports './eslint-agent.js' instead of the eslint-config-agent package"
+        );
+
+        fs::write(root.join("eslint.config.mjs"), rule.get_eslint_config_content()).unwrap();
+        assert!(!rule
+            .check(&context)
+            .iter()
+            .any(|r| r.check_id == CHECK_CONFIG_USES_AGENT));
+    }
+
     #[test]
     fn test_detects_custom_overrides() {
         let temp_dir = TempDir::new().unwrap();
@@ -564,6 +889,49 @@ export default config;
             .any(|r| r.message.contains("legacy ESLint config")));
     }
 
+    #[test]
+    fn test_flags_and_fixes_multiple_flat_configs() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::write(
+            root.join("package.json"),
+            r#"{"name": "test", "devDependencies": {"eslint-config-agent": "^1.0.0"}}"#,
+        )
+        .unwrap();
+        let agent_config = "import config from \"eslint-config-agent\";\n\nexport default config;\n";
+        fs::write(root.join("eslint.config.mjs"), agent_config).unwrap();
+        fs::write(root.join("eslint.config.js"), "export default [];\n").unwrap();
+        let rule = EslintConfigAgentRule::new();
+
+        let results = rule.check(&create_context(root.clone()));
+        let flagged: Vec<_> =
+            results.iter().filter(|r| r.check_id == CHECK_SINGLE_FLAT_CONFIG).collect();
+        assert_eq!(flagged.len(), 1);
+        assert_eq!(
+            flagged[0].message,
+            "Found multiple ESLint flat configs: eslint.config.js, eslint.config.mjs"
+        );
+        assert!(flagged[0].path.ends_with("eslint.config.mjs"));
+        assert_eq!(flagged[0].fixable_by, vec![FIX_KEEP_SINGLE_FLAT_CONFIG]);
+
+        // A preferred extension decides which file the finding keeps
+        fs::write(root.join("eslint.config.ts"), "export default [];\n").unwrap();
+        let options = serde_json::json!({"preferredConfigExtension": ".ts"});
+        let results = rule.check(&RuleContext::new(root.clone(), options));
+        let flagged = results.iter().find(|r| r.check_id == CHECK_SINGLE_FLAT_CONFIG).unwrap();
+        assert_eq!(
+            flagged.suggestion.as_deref(),
+            Some("Consolidate into eslint.config.ts and remove the others")
+        );
+        fs::remove_file(root.join("eslint.config.ts")).unwrap();
+
+        let context = create_context(root.clone());
+        assert!(rule.fix(&context).unwrap() > 0);
+        assert!(!root.join("eslint.config.js").exists());
+        assert_eq!(fs::read_to_string(root.join("eslint.config.mjs")).unwrap(), agent_config);
+        assert!(rule.check(&context).is_empty());
+    }
+
     #[test]
     fn test_accepts_correct_configuration() {
         let temp_dir = TempDir::new().unwrap();
@@ -594,6 +962,80 @@ export default config;
         assert!(results.is_empty());
     }
 
+    #[test]
+    fn test_detects_gitignored_eslint_config() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::create_dir_all(root.join(".git")).unwrap();
+        fs::write(root.join(".gitignore"), "node_modules\n*.mjs\n").unwrap();
+        fs::write(
+            root.join("package.json"),
+            r#"{"name": "test", "devDependencies": {"eslint-config-agent": "^1.0.0"}}"#,
+        )
+        .unwrap();
+        fs::write(
+            root.join("eslint.config.mjs"),
+            "import config from \"eslint-config-agent\";\n\nexport default config;\n",
+        )
+        .unwrap();
+
+        let rule = EslintConfigAgentRule::new();
+        let results = rule.check(&create_context(root.clone()));
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].check_id, CHECK_CONFIG_NOT_IGNORED);
+        assert!(results[0].fixable_by.is_empty());
+
+        fs::write(root.join(".gitignore"), "*.mjs\n!eslint.config.mjs\n").unwrap();
+        assert!(rule.check(&create_context(root)).is_empty());
+    }
+
+    fn check_agent_range(range: &str) -> Vec<LintResult> {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::write(
+            root.join("package.json"),
+            format!(r#"{{"name": "test", "devDependencies": {{"eslint-config-agent": "{}"}}}}"#, range),
+        )
+        .unwrap();
+        let context = RuleContext::new(root, serde_json::json!({"minAgentVersion": "2.0"}));
+
+        EslintConfigAgentRule::new()
+            .check(&context)
+            .into_iter()
+            .filter(|r| r.check_id == CHECK_AGENT_VERSION)
+            .collect()
+    }
+
+    #[test]
+    fn test_flags_agent_range_below_minimum() {
+        let results = check_agent_range("^0.5.0");
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].message,
+            "eslint-config-agent range '^0.5.0' can't satisfy the minimum version 2.0.0"
+        );
+        assert!(results[0].fixable_by.is_empty());
+        assert_eq!(check_agent_range("~1.4.2 || 1.9.0").len(), 1);
+    }
+
+    #[test]
+    fn test_accepts_agent_range_reaching_minimum() {
+        for range in ["^2.1.0", ">= 1.0.0", "^1.0.0 || ^2.0.0", "2.x", "workspace:*"] {
+            assert!(check_agent_range(range).is_empty(), "{}", range);
+        }
+        // Without the option the check doesn't run
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("package.json"),
+            r#"{"name": "test", "devDependencies": {"eslint-config-agent": "^0.5.0"}}"#,
+        )
+        .unwrap();
+        let results = EslintConfigAgentRule::new().check(&create_context(temp_dir.path().into()));
+        assert!(!results.iter().any(|r| r.check_id == CHECK_AGENT_VERSION));
+    }
+
     #[test]
     fn test_skips_non_js_projects() {
         let temp_dir = TempDir::new().unwrap();
@@ -728,4 +1170,124 @@ export default config;
         assert!(content.contains("eslint-config-agent"));
         assert!(content.contains("export default config"));
     }
+
+    #[test]
+    fn test_suggests_eslint_config_prettier_when_prettier_present() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+
+        fs::write(
+            root.join("package.json"),
+            r#"{"name": "test", "devDependencies": {"eslint-config-agent": "^1.0.0", "prettier": "^3.0.0"}}"#,
+        )
+        .unwrap();
+        fs::write(
+            root.join("eslint.config.mjs"),
+            "import config from \"eslint-config-agent\";\n\nexport default config;\n",
+        )
+        .unwrap();
+
+        let rule = EslintConfigAgentRule::new();
+        let results = rule.check(&create_context(root));
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].check_id, CHECK_PRETTIER_COMPATIBLE);
+        assert_eq!(results[0].severity, "info");
+        assert!(results[0]
+            .suggestion
+            .as_ref()
+            .unwrap()
+            .contains("eslint-config-prettier"));
+        assert!(results[0].fixable_by.is_empty());
+    }
+
+    #[test]
+    fn test_no_prettier_suggestion_when_agent_covers_it() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+
+        fs::write(
+            root.join("package.json"),
+            r#"{"name": "test", "devDependencies": {"eslint-config-agent": "^1.0.0", "prettier": "^3.0.0"}}"#,
+        )
+        .unwrap();
+        fs::write(
+            root.join("eslint.config.mjs"),
+            "import config from \"eslint-config-agent\";\n\nexport default config;\n",
+        )
+        .unwrap();
+        let agent_dir = root.join("node_modules").join("eslint-config-agent");
+        fs::create_dir_all(&agent_dir).unwrap();
+        fs::write(
+            agent_dir.join("package.json"),
+            r#"{"name": "eslint-config-agent", "dependencies": {"eslint-config-prettier": "^9.0.0"}}"#,
+        )
+        .unwrap();
+
+        let rule = EslintConfigAgentRule::new();
+        let results = rule.check(&create_context(root));
+
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn test_fix_with_assume_installed_runs_no_command() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+
+        // eslint-config-agent comes from a workspace catalog, not this package.json
+        fs::write(
+            root.join("package.json"),
+            r#"{"name": "test", "devDependencies": {"eslint": "^9.0.0"}}"#,
+        )
+        .unwrap();
+        fs::write(root.join(".eslintrc.json"), "{}").unwrap();
+
+        let runner = Arc::new(RecordingCommandRunner::new());
+        let rule = EslintConfigAgentRule::new();
+        let context = RuleContext::new(root.clone(), serde_json::json!({"assumeInstalled": true}))
+            .with_command_runner(runner.clone());
+
+        let fixed = rule.fix(&context).unwrap();
+
+        assert_eq!(fixed, 2);
+        assert!(runner.commands().is_empty());
+        assert!(root.join("eslint.config.mjs").exists());
+        assert!(!root.join(".eslintrc.json").exists());
+        assert!(rule.check(&context).is_empty());
+    }
+
+    #[test]
+    fn test_fix_installs_missing_dependency_with_pnpm() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+
+        fs::write(
+            root.join("package.json"),
+            r#"{"name": "test", "devDependencies": {"eslint": "^9.0.0"}}"#,
+        )
+        .unwrap();
+
+        let runner = Arc::new(RecordingCommandRunner::new());
+        let rule = EslintConfigAgentRule::new();
+        let context = create_context(root).with_command_runner(runner.clone());
+
+        rule.fix(&context).unwrap();
+
+        assert_eq!(runner.commands(), vec!["pnpm add -D eslint-config-agent@latest"]);
+    }
+
+    #[test]
+    fn test_describe_check_links_addressing_fixes() {
+        let rule = EslintConfigAgentRule::new();
+
+        let description = rule.describe_check(CHECK_DEPENDENCY_EXISTS).unwrap();
+        assert_eq!(description.rule_id, "eslint-config-agent");
+        assert!(description.fixable);
+        let fix_ids: Vec<&str> = description.fixes.iter().map(|f| f.id.as_str()).collect();
+        assert!(fix_ids.contains(&FIX_INSTALL_DEPENDENCY));
+        assert!(!fix_ids.contains(&FIX_REMOVE_LEGACY));
+
+        assert!(rule.describe_check("no-such-check").is_none());
+    }
 }
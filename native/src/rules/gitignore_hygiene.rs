@@ -0,0 +1,201 @@
+use crate::gitignore;
+use crate::rules::{Rule, RuleError};
+use crate::types::{CheckEntry, FixEntry, LintResult, RuleContext, Severity};
+use std::path::Path;
+
+// Check IDs
+const CHECK_COVERS_OS_CRUFT: &str = "covers-os-cruft";
+
+// Fix IDs
+const FIX_APPEND_OS_CRUFT: &str = "append-os-cruft";
+
+/// Comment heading the block the fix appends
+const OS_CRUFT_HEADER: &str = "OS/editor";
+
+/// Patterns for OS and editor noise, each with a sample path it should ignore
+const OS_CRUFT: &[(&str, &str)] = &[
+    (".DS_Store", ".DS_Store"),
+    ("Thumbs.db", "Thumbs.db"),
+    ("*.log", "debug.log"),
+    ("*.swp", "file.swp"),
+];
+
+/// Rule: Ensure .gitignore keeps common OS and editor files out of the repository
+pub struct GitignoreHygieneRule;
+
+impl GitignoreHygieneRule {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Patterns whose sample path the repository's .gitignore doesn't already ignore
+    fn missing_patterns(&self, repo_root: &Path) -> Vec<&'static str> {
+        OS_CRUFT
+            .iter()
+            .filter(|(_, sample)| !gitignore::is_ignored(repo_root, Path::new(sample), false))
+            .map(|(pattern, _)| *pattern)
+            .collect()
+    }
+}
+
+impl Default for GitignoreHygieneRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Rule for GitignoreHygieneRule {
+    fn id(&self) -> &'static str {
+        "gitignore-hygiene"
+    }
+
+    fn name(&self) -> &'static str {
+        "Gitignore Hygiene"
+    }
+
+    fn description(&self) -> &'static str {
+        "Ensures .gitignore excludes common OS and editor files such as .DS_Store and *.log"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Info
+    }
+
+    fn enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn tags(&self) -> Vec<&'static str> {
+        vec!["git"]
+    }
+
+    fn checks(&self) -> Vec<CheckEntry> {
+        vec![CheckEntry::new(
+            CHECK_COVERS_OS_CRUFT,
+            "Verify .gitignore covers OS/editor files (.DS_Store, Thumbs.db, *.log, *.swp)",
+        )]
+    }
+
+    fn fixes(&self) -> Vec<FixEntry> {
+        vec![FixEntry::new(
+            FIX_APPEND_OS_CRUFT,
+            "Append the missing patterns to .gitignore under an '# OS/editor' comment",
+            vec![CHECK_COVERS_OS_CRUFT],
+        )]
+    }
+
+    fn check(&self, context: &RuleContext) -> Vec<LintResult> {
+        context
+            .git_repos()
+            .into_iter()
+            .filter_map(|repo| {
+                let missing = self.missing_patterns(&repo);
+                if missing.is_empty() {
+                    return None;
+                }
+                Some(LintResult::new(
+                    self.id(),
+                    CHECK_COVERS_OS_CRUFT,
+                    self.default_severity(),
+                    format!(".gitignore does not exclude: {}", missing.join(", ")),
+                    repo.join(".gitignore"),
+                    None,
+                    Some("Add the missing patterns to .gitignore".into()),
+                    vec![FIX_APPEND_OS_CRUFT],
+                ))
+            })
+            .collect()
+    }
+
+    fn fix(&self, context: &RuleContext) -> Result<u32, RuleError> {
+        let mut fixed = 0;
+
+        for repo in context.git_repos() {
+            let missing = self.missing_patterns(&repo);
+            if !missing.is_empty()
+                && gitignore::append_block(context, &repo, OS_CRUFT_HEADER, &missing)?
+            {
+                fixed += 1;
+            }
+        }
+
+        Ok(fixed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    fn setup_repo(gitignore: Option<&str>) -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir_all(temp_dir.path().join(".git")).unwrap();
+        if let Some(content) = gitignore {
+            fs::write(temp_dir.path().join(".gitignore"), content).unwrap();
+        }
+        temp_dir
+    }
+
+    fn create_context(root: PathBuf) -> RuleContext {
+        RuleContext::new(root, serde_json::json!({}))
+    }
+
+    #[test]
+    fn test_reports_missing_patterns() {
+        let temp_dir = setup_repo(Some("node_modules\n**/.DS_Store\n"));
+        let rule = GitignoreHygieneRule::new();
+        let results = rule.check(&create_context(temp_dir.path().to_path_buf()));
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].severity, "info");
+        assert_eq!(
+            results[0].message,
+            ".gitignore does not exclude: Thumbs.db, *.log, *.swp"
+        );
+    }
+
+    #[test]
+    fn test_fix_appends_only_missing_entries() {
+        let temp_dir = setup_repo(Some("node_modules\n.DS_Store\n*.log\n"));
+        let root = temp_dir.path().to_path_buf();
+        let rule = GitignoreHygieneRule::new();
+        let context = create_context(root.clone());
+
+        assert_eq!(rule.fix(&context).unwrap(), 1);
+        assert_eq!(
+            fs::read_to_string(root.join(".gitignore")).unwrap(),
+            "node_modules\n.DS_Store\n*.log\n\n# OS/editor\nThumbs.db\n*.swp\n"
+        );
+        assert!(rule.check(&context).is_empty());
+        assert_eq!(rule.fix(&context).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_fix_extends_existing_block() {
+        let temp_dir = setup_repo(Some("# OS/editor\n.DS_Store\n\n# Build\ndist/\n"));
+        let root = temp_dir.path().to_path_buf();
+        let rule = GitignoreHygieneRule::new();
+
+        assert_eq!(rule.fix(&create_context(root.clone())).unwrap(), 1);
+        assert_eq!(
+            fs::read_to_string(root.join(".gitignore")).unwrap(),
+            "# OS/editor\n.DS_Store\nThumbs.db\n*.log\n*.swp\n\n# Build\ndist/\n"
+        );
+    }
+
+    #[test]
+    fn test_fix_creates_gitignore() {
+        let temp_dir = setup_repo(None);
+        let root = temp_dir.path().to_path_buf();
+        let rule = GitignoreHygieneRule::new();
+
+        assert_eq!(rule.fix(&create_context(root.clone())).unwrap(), 1);
+        assert_eq!(
+            fs::read_to_string(root.join(".gitignore")).unwrap(),
+            "# OS/editor\n.DS_Store\nThumbs.db\n*.log\n*.swp\n"
+        );
+    }
+}
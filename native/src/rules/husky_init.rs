@@ -1,19 +1,39 @@
+use crate::gitignore;
+use crate::husky;
 use crate::rules::{Rule, RuleError};
 use crate::types::{CheckEntry, FixEntry, LintResult, RuleContext, Severity};
 use serde_json::Value;
 use std::path::{Path, PathBuf};
-use std::process::Command;
-use walkdir::WalkDir;
 
 // Check IDs
 const CHECK_HUSKY_DIR_EXISTS: &str = "husky-dir-exists";
 const CHECK_JS_PREPARE_SCRIPT: &str = "js-prepare-script";
 const CHECK_RUST_HUSKY_RS_DEP: &str = "rust-husky-rs-dependency";
+const CHECK_RUST_PRE_COMMIT_SOURCE: &str = "husky-rs-pre-commit-source";
 const CHECK_HOOKS_EXIST: &str = "hooks-exist";
+const CHECK_NO_OBSOLETE_SHIM: &str = "no-obsolete-husky-shim";
+const CHECK_NO_HOOK_BYPASS: &str = "no-hook-bypass";
+const CHECK_HOOK_NOT_EMPTY: &str = "hook-not-empty";
+const CHECK_HOOK_LF_ENDINGS: &str = "hook-lf-line-endings";
+const CHECK_PRE_PUSH_EXISTS: &str = "pre-push-hook-exists";
+const CHECK_HUSKY_NOT_IGNORED: &str = "husky-not-gitignored";
 
 // Fix IDs
 const FIX_INIT_HUSKY_JS: &str = "init-husky-js";
 const FIX_INIT_HUSKY_RS: &str = "init-husky-rs";
+const FIX_SCAFFOLD_RS_PRE_COMMIT: &str = "scaffold-husky-rs-pre-commit";
+const FIX_ADD_PRE_PUSH: &str = "add-pre-push-hook";
+const FIX_NORMALIZE_LINE_ENDINGS: &str = "normalize-hook-line-endings";
+const FIX_UNIGNORE_HUSKY: &str = "unignore-husky-dir";
+
+/// .gitignore negation that re-includes the hooks directory
+const HUSKY_NEGATION: &str = "!.husky/";
+
+/// Command the generated pre-push hook runs when `prePushCommand` isn't set
+const DEFAULT_PRE_PUSH_COMMAND: &str = "pnpm test";
+
+/// Command the scaffolded husky-rs pre-commit hook runs when `rustPreCommitCommand` isn't set
+const DEFAULT_RUST_PRE_COMMIT_COMMAND: &str = "cargo fmt --check && cargo clippy";
 
 /// Project type detection for Husky initialization strategy
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -24,20 +44,31 @@ enum ProjectType {
 
 /// Strategy trait for project-specific Husky initialization
 trait HuskyStrategy: Send + Sync {
-    fn project_type(&self) -> ProjectType;
-    fn check(&self, repo_root: &Path, rule_id: &str) -> Vec<LintResult>;
-    fn fix(&self, repo_root: &Path) -> Result<bool, RuleError>;
+    fn check(&self, repo_root: &Path, rule_id: &str, context: &RuleContext) -> Vec<LintResult>;
+    fn fix(&self, repo_root: &Path, context: &RuleContext) -> Result<bool, RuleError>;
+}
+
+/// List the hook scripts directly inside a .husky directory (skipping the `_` helper dir)
+fn hook_files(husky_dir: &Path) -> Vec<PathBuf> {
+    let mut hooks: Vec<PathBuf> = husky_dir
+        .read_dir()
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .map(|e| e.path())
+                .filter(|p| p.is_file())
+                .collect()
+        })
+        .unwrap_or_default();
+    hooks.sort();
+    hooks
 }
 
 /// JavaScript/TypeScript Husky strategy
 struct JsHuskyStrategy;
 
 impl HuskyStrategy for JsHuskyStrategy {
-    fn project_type(&self) -> ProjectType {
-        ProjectType::JavaScript
-    }
-
-    fn check(&self, repo_root: &Path, rule_id: &str) -> Vec<LintResult> {
+    fn check(&self, repo_root: &Path, rule_id: &str, context: &RuleContext) -> Vec<LintResult> {
         let mut results = Vec::new();
         let husky_dir = repo_root.join(".husky");
         let package_json_path = repo_root.join("package.json");
@@ -61,45 +92,24 @@ impl HuskyStrategy for JsHuskyStrategy {
         }
 
         // Check if package.json has prepare script with husky
-        if package_json_path.exists() {
-            match std::fs::read_to_string(&package_json_path) {
-                Ok(content) => {
-                    if let Ok(json) = serde_json::from_str::<Value>(&content) {
-                        let has_prepare_script = json
-                            .get("scripts")
-                            .and_then(|s| s.get("prepare"))
-                            .and_then(|p| p.as_str())
-                            .is_some_and(|s| s.contains("husky"));
-
-                        if !has_prepare_script {
-                            results.push(LintResult::new(
-                                rule_id,
-                                CHECK_JS_PREPARE_SCRIPT,
-                                Severity::Warning,
-                                "Missing 'prepare' script with Husky in package.json".into(),
-                                package_json_path.clone(),
-                                None,
-                                Some(
-                                    "Add '\"prepare\": \"husky\"' to scripts in package.json"
-                                        .into(),
-                                ),
-                                vec![FIX_INIT_HUSKY_JS],
-                            ));
-                        }
-                    }
-                }
-                Err(e) => {
-                    results.push(LintResult::new(
-                        rule_id,
-                        CHECK_JS_PREPARE_SCRIPT,
-                        Severity::Error,
-                        format!("Cannot read package.json: {}", e),
-                        package_json_path,
-                        None,
-                        None,
-                        vec![],
-                    ));
-                }
+        if let Some(json) = context.package_json_value(&package_json_path) {
+            let has_prepare_script = json
+                .get("scripts")
+                .and_then(|s| s.get("prepare"))
+                .and_then(|p| p.as_str())
+                .is_some_and(|s| s.contains("husky"));
+
+            if !has_prepare_script {
+                results.push(LintResult::new(
+                    rule_id,
+                    CHECK_JS_PREPARE_SCRIPT,
+                    Severity::Warning,
+                    "Missing 'prepare' script with Husky in package.json".into(),
+                    package_json_path.clone(),
+                    None,
+                    Some("Add '\"prepare\": \"husky\"' to scripts in package.json".into()),
+                    vec![FIX_INIT_HUSKY_JS],
+                ));
             }
         }
 
@@ -125,17 +135,41 @@ impl HuskyStrategy for JsHuskyStrategy {
                 CHECK_HOOKS_EXIST,
                 Severity::Info,
                 "No git hooks found in .husky directory".into(),
-                husky_dir,
+                husky_dir.clone(),
                 None,
                 Some("Add hooks like 'pnpm dlx husky add .husky/pre-commit \"npm test\"'".into()),
                 vec![], // Manual fix required
             ));
         }
 
+        // Husky v9+ warns about hooks that still source the v8 husky.sh shim
+        if !husky::requires_shim(context.workspace().husky_version(repo_root)) {
+            for hook in hook_files(&husky_dir) {
+                let has_shim = std::fs::read_to_string(&hook)
+                    .is_ok_and(|content| husky::has_obsolete_shim(&content));
+                if has_shim {
+                    results.push(LintResult::new(
+                        rule_id,
+                        CHECK_NO_OBSOLETE_SHIM,
+                        Severity::Warning,
+                        "Hook sources the obsolete husky.sh shim, which Husky v9+ deprecates"
+                            .into(),
+                        hook,
+                        None,
+                        Some(
+                            "Remove the shebang and '. \"$(dirname -- \"$0\")/_/husky.sh\"' lines"
+                                .into(),
+                        ),
+                        vec![], // Manual fix required
+                    ));
+                }
+            }
+        }
+
         results
     }
 
-    fn fix(&self, repo_root: &Path) -> Result<bool, RuleError> {
+    fn fix(&self, repo_root: &Path, context: &RuleContext) -> Result<bool, RuleError> {
         let husky_dir = repo_root.join(".husky");
         let package_json_path = repo_root.join("package.json");
 
@@ -144,16 +178,13 @@ impl HuskyStrategy for JsHuskyStrategy {
         }
 
         // Try to initialize Husky using pnpm dlx
-        let init_result = Command::new("pnpm")
-            .args(["dlx", "husky", "init"])
-            .current_dir(repo_root)
-            .output();
+        let init_result = context.run_command("pnpm", &["dlx", "husky", "init"], repo_root);
 
         match init_result {
             Ok(output) if output.status.success() => {
                 // Update package.json prepare script if needed
-                if package_json_path.exists() {
-                    if let Ok(content) = std::fs::read_to_string(&package_json_path) {
+                if context.file_exists(&package_json_path) {
+                    if let Ok(content) = context.read_file(&package_json_path) {
                         if let Ok(mut json) = serde_json::from_str::<Value>(&content) {
                             let scripts = json
                                 .as_object_mut()
@@ -164,7 +195,7 @@ impl HuskyStrategy for JsHuskyStrategy {
                                 if !scripts.contains_key("prepare") {
                                     scripts.insert("prepare".into(), Value::String("husky".into()));
                                     if let Ok(updated) = serde_json::to_string_pretty(&json) {
-                                        let _ = std::fs::write(&package_json_path, updated);
+                                        let _ = context.write_file(&package_json_path, &updated);
                                     }
                                 }
                             }
@@ -173,8 +204,7 @@ impl HuskyStrategy for JsHuskyStrategy {
                 }
                 Ok(true)
             }
-            Ok(output) => Err(RuleError::Io(std::io::Error::new(
-                std::io::ErrorKind::Other,
+            Ok(output) => Err(RuleError::Io(std::io::Error::other(
                 format!(
                     "Husky init failed: {}",
                     String::from_utf8_lossy(&output.stderr)
@@ -188,12 +218,25 @@ impl HuskyStrategy for JsHuskyStrategy {
 /// Rust husky-rs strategy
 struct RustHuskyStrategy;
 
-impl HuskyStrategy for RustHuskyStrategy {
-    fn project_type(&self) -> ProjectType {
-        ProjectType::Rust
+impl RustHuskyStrategy {
+    /// husky-rs installs hooks from source files under `.husky/hooks` when the
+    /// crate builds; `cargo husky-rs init` doesn't write any hook content
+    fn pre_commit_source(repo_root: &Path) -> PathBuf {
+        repo_root.join(".husky").join("hooks").join("pre-commit")
     }
 
-    fn check(&self, repo_root: &Path, rule_id: &str) -> Vec<LintResult> {
+    /// The command a scaffolded pre-commit hook runs (option: rustPreCommitCommand)
+    fn pre_commit_command(context: &RuleContext) -> &str {
+        context
+            .config
+            .get("rustPreCommitCommand")
+            .and_then(|v| v.as_str())
+            .unwrap_or(DEFAULT_RUST_PRE_COMMIT_COMMAND)
+    }
+}
+
+impl HuskyStrategy for RustHuskyStrategy {
+    fn check(&self, repo_root: &Path, rule_id: &str, _context: &RuleContext) -> Vec<LintResult> {
         let mut results = Vec::new();
         let husky_dir = repo_root.join(".husky");
         let cargo_toml_path = repo_root.join("Cargo.toml");
@@ -279,33 +322,56 @@ impl HuskyStrategy for RustHuskyStrategy {
             ));
         }
 
+        let pre_commit = Self::pre_commit_source(repo_root);
+        if !pre_commit.exists() {
+            results.push(LintResult::new(
+                rule_id,
+                CHECK_RUST_PRE_COMMIT_SOURCE,
+                Severity::Warning,
+                "Missing .husky/hooks/pre-commit - husky-rs has no pre-commit hook to install"
+                    .into(),
+                pre_commit,
+                None,
+                Some(format!(
+                    "Create .husky/hooks/pre-commit running '{}'",
+                    DEFAULT_RUST_PRE_COMMIT_COMMAND
+                )),
+                vec![FIX_SCAFFOLD_RS_PRE_COMMIT],
+            ));
+        }
+
         results
     }
 
-    fn fix(&self, repo_root: &Path) -> Result<bool, RuleError> {
-        let husky_dir = repo_root.join(".husky");
+    fn fix(&self, repo_root: &Path, context: &RuleContext) -> Result<bool, RuleError> {
+        let mut changed = false;
 
-        if husky_dir.exists() {
-            return Ok(false); // Already initialized
-        }
+        if !repo_root.join(".husky").exists() {
+            // Try to initialize husky-rs using cargo
+            let init_result = context.run_command("cargo", &["husky-rs", "init"], repo_root);
 
-        // Try to initialize husky-rs using cargo
-        let init_result = Command::new("cargo")
-            .args(["husky-rs", "init"])
-            .current_dir(repo_root)
-            .output();
+            match init_result {
+                Ok(output) if output.status.success() => changed = true,
+                Ok(output) => {
+                    return Err(RuleError::Io(std::io::Error::other(format!(
+                        "husky-rs init failed: {}",
+                        String::from_utf8_lossy(&output.stderr)
+                    ))))
+                }
+                Err(e) => return Err(RuleError::Io(e)),
+            }
+        }
 
-        match init_result {
-            Ok(output) if output.status.success() => Ok(true),
-            Ok(output) => Err(RuleError::Io(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "husky-rs init failed: {}",
-                    String::from_utf8_lossy(&output.stderr)
-                ),
-            ))),
-            Err(e) => Err(RuleError::Io(e)),
+        // Scaffold the hook source that init leaves out
+        let pre_commit = Self::pre_commit_source(repo_root);
+        if !context.file_exists(&pre_commit) {
+            let content = format!("#!/bin/sh\n{}\n", Self::pre_commit_command(context));
+            context.write_file(&pre_commit, &content)?;
+            context.set_executable(&pre_commit)?;
+            changed = true;
         }
+
+        Ok(changed)
     }
 }
 
@@ -317,26 +383,6 @@ impl HuskyInitRule {
         Self
     }
 
-    /// Find all git repositories in the given root
-    fn find_git_repos(&self, root: &Path) -> Vec<PathBuf> {
-        let mut repos = Vec::new();
-
-        for entry in WalkDir::new(root)
-            .follow_links(false)
-            .into_iter()
-            .filter_map(|e| e.ok())
-        {
-            let path = entry.path();
-            if path.is_dir() && path.file_name().is_some_and(|n| n == ".git") {
-                if let Some(parent) = path.parent() {
-                    repos.push(parent.to_path_buf());
-                }
-            }
-        }
-
-        repos
-    }
-
     /// Detect project type based on manifest files
     fn detect_project_type(&self, repo_root: &Path) -> Option<ProjectType> {
         let has_package_json = repo_root.join("package.json").exists();
@@ -361,11 +407,15 @@ impl HuskyInitRule {
     }
 
     /// Check a single repository
-    fn check_repo(&self, repo_root: &Path) -> Vec<LintResult> {
+    fn check_repo(&self, repo_root: &Path, context: &RuleContext) -> Vec<LintResult> {
         match self.detect_project_type(repo_root) {
             Some(project_type) => {
                 let strategy = self.get_strategy(project_type);
-                strategy.check(repo_root, self.id())
+                let mut results = strategy.check(repo_root, self.id(), context);
+                results.extend(self.check_hook_bypasses(repo_root));
+                results.extend(self.check_empty_hooks(repo_root));
+                results.extend(self.check_line_endings(repo_root));
+                results
             }
             None => {
                 // Skip repositories without package.json or Cargo.toml
@@ -374,12 +424,192 @@ impl HuskyInitRule {
         }
     }
 
+    /// Check hook scripts for patterns that undermine them. Report-only, since
+    /// rewriting arbitrary hook scripts is too risky to automate.
+    fn check_hook_bypasses(&self, repo_root: &Path) -> Vec<LintResult> {
+        hook_files(&repo_root.join(".husky"))
+            .into_iter()
+            .filter_map(|hook| {
+                let content = std::fs::read_to_string(&hook).ok()?;
+                let reason = husky::bypass_reason(&content)?;
+                let name = hook.file_name()?.to_string_lossy().into_owned();
+                Some(LintResult::new(
+                    self.id(),
+                    CHECK_NO_HOOK_BYPASS,
+                    Severity::Warning,
+                    format!("Hook '{}' {}", name, reason),
+                    hook,
+                    None,
+                    Some("Remove the bypass so the hook enforces its checks".into()),
+                    vec![], // Manual fix required
+                ))
+            })
+            .collect()
+    }
+
+    /// Check hook scripts that exist but run nothing (only a shebang, comments or
+    /// the husky.sh shim). Report-only, since the intended command is unknown.
+    fn check_empty_hooks(&self, repo_root: &Path) -> Vec<LintResult> {
+        hook_files(&repo_root.join(".husky"))
+            .into_iter()
+            .filter_map(|hook| {
+                let content = std::fs::read_to_string(&hook).ok()?;
+                if !husky::effective_commands(&content).is_empty() {
+                    return None;
+                }
+                let name = hook.file_name()?.to_string_lossy().into_owned();
+                Some(LintResult::new(
+                    self.id(),
+                    CHECK_HOOK_NOT_EMPTY,
+                    Severity::Warning,
+                    format!("Hook '{}' exists but runs no commands", name),
+                    hook,
+                    None,
+                    Some("Add the command the hook should run, or delete the hook".into()),
+                    vec![], // Manual fix required
+                ))
+            })
+            .collect()
+    }
+
+    /// The command a pre-push hook should run, or None unless the opt-in
+    /// `prePush` option is enabled
+    fn pre_push_command<'a>(&self, context: &'a RuleContext) -> Option<&'a str> {
+        if context.config.get("prePush").and_then(|v| v.as_bool()) != Some(true) {
+            return None;
+        }
+        Some(
+            context
+                .config
+                .get("prePushCommand")
+                .and_then(|v| v.as_str())
+                .unwrap_or(DEFAULT_PRE_PUSH_COMMAND),
+        )
+    }
+
+    /// Whether a repo with Husky set up has a `test` script but no pre-push hook
+    fn missing_pre_push(&self, repo_root: &Path, context: &RuleContext) -> bool {
+        let husky_dir = repo_root.join(".husky");
+        husky_dir.is_dir()
+            && !context.file_exists(&husky_dir.join("pre-push"))
+            && context
+                .package_json_value(&repo_root.join("package.json"))
+                .is_some_and(|json| json.get("scripts").and_then(|s| s.get("test")).is_some())
+    }
+
+    fn check_pre_push(&self, repo_root: &Path, context: &RuleContext) -> Option<LintResult> {
+        let command = self.pre_push_command(context)?;
+        if !self.missing_pre_push(repo_root, context) {
+            return None;
+        }
+
+        Some(LintResult::new(
+            self.id(),
+            CHECK_PRE_PUSH_EXISTS,
+            Severity::Warning,
+            "Missing .husky/pre-push hook although the project has a test script".into(),
+            repo_root.join(".husky"),
+            None,
+            Some(format!("Create .husky/pre-push running '{}'", command)),
+            vec![FIX_ADD_PRE_PUSH],
+        ))
+    }
+
+    /// Create .husky/pre-push running the configured command
+    fn fix_pre_push(&self, repo_root: &Path, context: &RuleContext) -> Result<bool, RuleError> {
+        let Some(command) = self.pre_push_command(context) else {
+            return Ok(false);
+        };
+        if !self.missing_pre_push(repo_root, context) {
+            return Ok(false);
+        }
+
+        let hook = repo_root.join(".husky").join("pre-push");
+        let content = husky::hook_content(context.workspace().husky_version(repo_root), command);
+        context.write_file(&hook, &content)?;
+        context.set_executable(&hook)?;
+
+        Ok(true)
+    }
+
+    /// Hook scripts containing CRLF line endings, which `sh` can't run
+    fn crlf_hooks(&self, repo_root: &Path) -> Vec<PathBuf> {
+        hook_files(&repo_root.join(".husky"))
+            .into_iter()
+            .filter(|hook| {
+                std::fs::read(hook).is_ok_and(|bytes| bytes.windows(2).any(|w| w == b"\r\n"))
+            })
+            .collect()
+    }
+
+    fn check_line_endings(&self, repo_root: &Path) -> Vec<LintResult> {
+        self.crlf_hooks(repo_root)
+            .into_iter()
+            .filter_map(|hook| {
+                let name = hook.file_name()?.to_string_lossy().into_owned();
+                Some(LintResult::new(
+                    self.id(),
+                    CHECK_HOOK_LF_ENDINGS,
+                    Severity::Warning,
+                    format!("Hook '{}' has CRLF line endings and will fail under sh", name),
+                    hook,
+                    None,
+                    Some("Convert the hook to LF line endings".into()),
+                    vec![FIX_NORMALIZE_LINE_ENDINGS],
+                ))
+            })
+            .collect()
+    }
+
+    /// Rewrite CRLF hooks with LF endings and restore their executable bit
+    fn fix_line_endings(&self, repo_root: &Path, context: &RuleContext) -> Result<u32, RuleError> {
+        let mut fixed = 0;
+        for hook in self.crlf_hooks(repo_root) {
+            let content = context.read_file(&hook)?;
+            context.write_file(&hook, &content.replace("\r\n", "\n"))?;
+            context.set_executable(&hook)?;
+            fixed += 1;
+        }
+        Ok(fixed)
+    }
+
+    /// Whether the repository's .gitignore excludes .husky, e.g. via a broad `.*`
+    fn husky_ignored(&self, repo_root: &Path) -> bool {
+        self.detect_project_type(repo_root).is_some()
+            && gitignore::is_ignored(repo_root, Path::new(".husky"), true)
+    }
+
+    fn check_husky_not_ignored(&self, repo_root: &Path) -> Option<LintResult> {
+        if !self.husky_ignored(repo_root) {
+            return None;
+        }
+
+        Some(LintResult::new(
+            self.id(),
+            CHECK_HUSKY_NOT_IGNORED,
+            Severity::Warning,
+            ".husky is excluded by .gitignore, so hooks won't be shared with the team".into(),
+            repo_root.join(".gitignore"),
+            None,
+            Some(format!("Add '{}' to .gitignore", HUSKY_NEGATION)),
+            vec![FIX_UNIGNORE_HUSKY],
+        ))
+    }
+
+    /// Append the `!.husky/` negation to the repository's .gitignore
+    fn fix_husky_not_ignored(&self, repo_root: &Path, context: &RuleContext) -> Result<bool, RuleError> {
+        if !self.husky_ignored(repo_root) {
+            return Ok(false);
+        }
+        Ok(gitignore::append_entry(context, repo_root, HUSKY_NEGATION)?)
+    }
+
     /// Fix a single repository
-    fn fix_repo(&self, repo_root: &Path) -> Result<bool, RuleError> {
+    fn fix_repo(&self, repo_root: &Path, context: &RuleContext) -> Result<bool, RuleError> {
         match self.detect_project_type(repo_root) {
             Some(project_type) => {
                 let strategy = self.get_strategy(project_type);
-                strategy.fix(repo_root)
+                strategy.fix(repo_root, context)
             }
             None => Ok(false),
         }
@@ -409,6 +639,10 @@ impl Rule for HuskyInitRule {
         Severity::Warning
     }
 
+    fn tags(&self) -> Vec<&'static str> {
+        vec!["git-hooks"]
+    }
+
     fn checks(&self) -> Vec<CheckEntry> {
         vec![
             CheckEntry::new(
@@ -423,10 +657,38 @@ impl Rule for HuskyInitRule {
                 CHECK_RUST_HUSKY_RS_DEP,
                 "Verify Cargo.toml has husky-rs as dev-dependency (Rust projects)",
             ),
+            CheckEntry::new(
+                CHECK_RUST_PRE_COMMIT_SOURCE,
+                "Verify .husky/hooks/pre-commit exists for husky-rs to install (Rust projects)",
+            ),
             CheckEntry::new(
                 CHECK_HOOKS_EXIST,
                 "Verify at least one git hook file exists in .husky directory",
             ),
+            CheckEntry::new(
+                CHECK_NO_OBSOLETE_SHIM,
+                "Verify hooks don't source the husky.sh shim deprecated in Husky v9",
+            ),
+            CheckEntry::new(
+                CHECK_NO_HOOK_BYPASS,
+                "Verify hooks don't bypass themselves with --no-verify or an early 'exit 0'",
+            ),
+            CheckEntry::new(
+                CHECK_HOOK_NOT_EMPTY,
+                "Verify hooks run at least one command beyond the shebang, comments or shim",
+            ),
+            CheckEntry::new(
+                CHECK_HOOK_LF_ENDINGS,
+                "Verify hooks use LF line endings, since sh can't run CRLF scripts",
+            ),
+            CheckEntry::new(
+                CHECK_PRE_PUSH_EXISTS,
+                "Verify projects with a test script have a .husky/pre-push hook (opt-in: prePush)",
+            ),
+            CheckEntry::new(
+                CHECK_HUSKY_NOT_IGNORED,
+                "Verify .gitignore doesn't exclude the .husky directory",
+            ),
         ]
     }
 
@@ -442,16 +704,46 @@ impl Rule for HuskyInitRule {
                 "Initialize husky-rs for Rust projects using 'cargo husky-rs init'",
                 vec![CHECK_HUSKY_DIR_EXISTS, CHECK_RUST_HUSKY_RS_DEP],
             ),
+            FixEntry::new(
+                FIX_SCAFFOLD_RS_PRE_COMMIT,
+                "Create .husky/hooks/pre-commit (option: rustPreCommitCommand)",
+                vec![CHECK_RUST_PRE_COMMIT_SOURCE],
+            ),
+            FixEntry::new(
+                FIX_ADD_PRE_PUSH,
+                "Create .husky/pre-push running the configured command (default: pnpm test)",
+                vec![CHECK_PRE_PUSH_EXISTS],
+            ),
+            FixEntry::new(
+                FIX_NORMALIZE_LINE_ENDINGS,
+                "Convert CRLF line endings in .husky hooks to LF and keep them executable",
+                vec![CHECK_HOOK_LF_ENDINGS],
+            ),
+            FixEntry::new(
+                FIX_UNIGNORE_HUSKY,
+                "Append a '!.husky/' negation to .gitignore",
+                vec![CHECK_HUSKY_NOT_IGNORED],
+            ),
         ]
     }
 
+    fn fix_requirements(&self, fix_id: &str) -> Vec<&'static str> {
+        match fix_id {
+            FIX_INIT_HUSKY_JS => vec!["pnpm"],
+            FIX_INIT_HUSKY_RS => vec!["cargo"],
+            _ => vec![],
+        }
+    }
+
     fn check(&self, context: &RuleContext) -> Vec<LintResult> {
         let mut results = Vec::new();
 
-        let repos = self.find_git_repos(&context.root);
+        let repos = context.git_repos();
 
         for repo in repos {
-            results.extend(self.check_repo(&repo));
+            results.extend(self.check_repo(&repo, context));
+            results.extend(self.check_pre_push(&repo, context));
+            results.extend(self.check_husky_not_ignored(&repo));
         }
 
         results
@@ -460,14 +752,289 @@ impl Rule for HuskyInitRule {
     fn fix(&self, context: &RuleContext) -> Result<u32, RuleError> {
         let mut fixed = 0;
 
-        let repos = self.find_git_repos(&context.root);
+        let repos = context.git_repos();
 
         for repo in repos {
-            if self.fix_repo(&repo)? {
+            if self.fix_repo(&repo, context)? {
+                fixed += 1;
+            }
+            if self.fix_pre_push(&repo, context)? {
+                fixed += 1;
+            }
+            if self.fix_husky_not_ignored(&repo, context)? {
                 fixed += 1;
             }
+            // Mirrors check_repo, which only inspects hooks of detected projects
+            if self.detect_project_type(&repo).is_some() {
+                fixed += self.fix_line_endings(&repo, context)?;
+            }
         }
 
         Ok(fixed)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::RecordingCommandRunner;
+    use std::fs;
+    use std::sync::Arc;
+    use tempfile::TempDir;
+
+    fn setup_js_repo(husky_version: &str, pre_commit: &str) -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join(".git")).unwrap();
+        fs::write(
+            root.join("package.json"),
+            format!(
+                r#"{{"name": "test", "scripts": {{"prepare": "husky"}}, "devDependencies": {{"husky": "{}"}}}}"#,
+                husky_version
+            ),
+        )
+        .unwrap();
+        fs::create_dir_all(root.join(".husky")).unwrap();
+        fs::write(root.join(".husky").join("pre-commit"), pre_commit).unwrap();
+        temp_dir
+    }
+
+    fn create_context(root: PathBuf) -> RuleContext {
+        RuleContext::new(root, serde_json::json!({}))
+    }
+
+    const V8_HOOK: &str = "#!/usr/bin/env sh\n. \"$(dirname -- \"$0\")/_/husky.sh\"\n\npnpm test\n";
+
+    #[test]
+    fn test_flags_obsolete_shim_for_husky_v9() {
+        let temp_dir = setup_js_repo("^9.1.7", V8_HOOK);
+        let rule = HuskyInitRule::new();
+        let results = rule.check(&create_context(temp_dir.path().to_path_buf()));
+
+        assert!(results.iter().any(|r| r.check_id == CHECK_NO_OBSOLETE_SHIM));
+    }
+
+    #[test]
+    fn test_accepts_shim_for_husky_v8() {
+        let temp_dir = setup_js_repo("^8.0.3", V8_HOOK);
+        let rule = HuskyInitRule::new();
+        let results = rule.check(&create_context(temp_dir.path().to_path_buf()));
+
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn test_accepts_modern_hook_for_husky_v9() {
+        let temp_dir = setup_js_repo("^9.1.7", "pnpm test\n");
+        let rule = HuskyInitRule::new();
+        let results = rule.check(&create_context(temp_dir.path().to_path_buf()));
+
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn test_flags_hook_with_no_verify() {
+        let temp_dir = setup_js_repo("^9.1.7", "pnpm test\ngit commit --amend --no-edit --no-verify\n");
+        let rule = HuskyInitRule::new();
+        let results = rule.check(&create_context(temp_dir.path().to_path_buf()));
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].check_id, CHECK_NO_HOOK_BYPASS);
+        assert_eq!(results[0].severity, "warning");
+        assert!(results[0].message.contains("pre-commit"));
+        assert!(results[0].fixable_by.is_empty());
+    }
+
+    #[test]
+    fn test_flags_hook_exiting_early() {
+        let temp_dir = setup_js_repo("^9.1.7", "exit 0\npnpm test\n");
+        let rule = HuskyInitRule::new();
+        let results = rule.check(&create_context(temp_dir.path().to_path_buf()));
+
+        assert!(results.iter().any(|r| r.check_id == CHECK_NO_HOOK_BYPASS));
+    }
+
+    #[test]
+    fn test_flags_hook_without_commands() {
+        let hook = format!("#!/usr/bin/env sh\n{}\n\n# TODO\n", husky::HUSKY_V8_SHIM);
+        let temp_dir = setup_js_repo("^8.0.3", &hook);
+        let rule = HuskyInitRule::new();
+        let results = rule.check(&create_context(temp_dir.path().to_path_buf()));
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].check_id, CHECK_HOOK_NOT_EMPTY);
+        assert_eq!(results[0].message, "Hook 'pre-commit' exists but runs no commands");
+        assert!(results[0].fixable_by.is_empty());
+        assert!(!results.iter().any(|r| r.check_id == CHECK_HOOKS_EXIST));
+    }
+
+    #[test]
+    fn test_accepts_hook_with_real_command() {
+        let temp_dir = setup_js_repo("^9.1.7", "# lint staged files\npnpm lint-staged\n");
+        let rule = HuskyInitRule::new();
+        let results = rule.check(&create_context(temp_dir.path().to_path_buf()));
+
+        assert!(!results.iter().any(|r| r.check_id == CHECK_HOOK_NOT_EMPTY));
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn test_fix_normalizes_crlf_hook() {
+        let temp_dir = setup_js_repo("^9.1.7", "pnpm lint\r\npnpm test\r\n");
+        let hook = temp_dir.path().join(".husky/pre-commit");
+        let rule = HuskyInitRule::new();
+        let context = RuleContext::new(temp_dir.path().to_path_buf(), serde_json::json!({}));
+
+        let results = rule.check(&context);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].check_id, CHECK_HOOK_LF_ENDINGS);
+        assert_eq!(results[0].fixable_by, vec![FIX_NORMALIZE_LINE_ENDINGS]);
+
+        assert_eq!(rule.fix(&context).unwrap(), 1);
+        assert_eq!(fs::read_to_string(&hook).unwrap(), "pnpm lint\npnpm test\n");
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            assert_eq!(fs::metadata(&hook).unwrap().permissions().mode() & 0o777, 0o755);
+        }
+        assert!(rule.check(&context).is_empty());
+    }
+
+    #[test]
+    fn test_fix_unignores_husky_dir() {
+        let temp_dir = setup_js_repo("^9.0.0", "pnpm test\n");
+        let root = temp_dir.path().to_path_buf();
+        fs::write(root.join(".gitignore"), "node_modules\n.*\n!.gitignore\n").unwrap();
+        let rule = HuskyInitRule::new();
+        let context = create_context(root.clone());
+
+        let results = rule.check(&context);
+        let ignored: Vec<_> =
+            results.iter().filter(|r| r.check_id == CHECK_HUSKY_NOT_IGNORED).collect();
+        assert_eq!(ignored.len(), 1);
+        assert_eq!(
+            ignored[0].message,
+            ".husky is excluded by .gitignore, so hooks won't be shared with the team"
+        );
+        assert_eq!(ignored[0].fixable_by, vec![FIX_UNIGNORE_HUSKY]);
+
+        assert_eq!(rule.fix(&context).unwrap(), 1);
+        assert_eq!(
+            fs::read_to_string(root.join(".gitignore")).unwrap(),
+            "node_modules\n.*\n!.gitignore\n!.husky/\n"
+        );
+        assert!(rule.check(&context).is_empty());
+        assert_eq!(rule.fix(&context).unwrap(), 0);
+    }
+
+    fn add_test_script(root: &Path, husky_version: &str) {
+        fs::write(
+            root.join("package.json"),
+            format!(
+                r#"{{"name": "test", "scripts": {{"prepare": "husky", "test": "vitest"}}, "devDependencies": {{"husky": "{}"}}}}"#,
+                husky_version
+            ),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_flags_missing_pre_push_when_enabled() {
+        let temp_dir = setup_js_repo("^9.1.7", "pnpm lint\n");
+        add_test_script(temp_dir.path(), "^9.1.7");
+        let rule = HuskyInitRule::new();
+
+        // Opt-in: nothing is reported without the prePush option
+        assert!(rule.check(&create_context(temp_dir.path().to_path_buf())).is_empty());
+
+        let context = RuleContext::new(
+            temp_dir.path().to_path_buf(),
+            serde_json::json!({"prePush": true}),
+        );
+        let results = rule.check(&context);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].check_id, CHECK_PRE_PUSH_EXISTS);
+        assert_eq!(results[0].fixable_by, vec![FIX_ADD_PRE_PUSH]);
+    }
+
+    #[test]
+    fn test_skips_pre_push_without_test_script() {
+        let temp_dir = setup_js_repo("^9.1.7", "pnpm lint\n");
+        let context = RuleContext::new(
+            temp_dir.path().to_path_buf(),
+            serde_json::json!({"prePush": true}),
+        );
+
+        assert!(HuskyInitRule::new().check(&context).is_empty());
+    }
+
+    #[test]
+    fn test_fix_creates_pre_push_with_configured_command() {
+        let temp_dir = setup_js_repo("^8.0.3", V8_HOOK);
+        add_test_script(temp_dir.path(), "^8.0.3");
+        let rule = HuskyInitRule::new();
+        let context = RuleContext::new(
+            temp_dir.path().to_path_buf(),
+            serde_json::json!({"prePush": true, "prePushCommand": "pnpm test:ci"}),
+        );
+
+        assert_eq!(rule.fix(&context).unwrap(), 1);
+
+        let hook = fs::read_to_string(temp_dir.path().join(".husky/pre-push")).unwrap();
+        assert_eq!(hook, husky::hook_content(Some(8), "pnpm test:ci"));
+        assert!(hook.contains("_/husky.sh"));
+        assert!(rule.check(&context).is_empty());
+    }
+
+    fn setup_rust_repo() -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join(".git")).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[package]\nname = \"test\"\n\n[dev-dependencies]\nhusky-rs = \"0.1\"\n",
+        )
+        .unwrap();
+        temp_dir
+    }
+
+    #[test]
+    fn test_fix_runs_husky_rs_init_and_scaffolds_pre_commit() {
+        let temp_dir = setup_rust_repo();
+        let runner = Arc::new(RecordingCommandRunner::new());
+        let context = RuleContext::new(temp_dir.path().to_path_buf(), serde_json::json!({}))
+            .with_command_runner(runner.clone());
+
+        assert_eq!(HuskyInitRule::new().fix(&context).unwrap(), 1);
+
+        assert_eq!(runner.commands(), vec!["cargo husky-rs init"]);
+        let hook = fs::read_to_string(temp_dir.path().join(".husky/hooks/pre-commit")).unwrap();
+        assert_eq!(hook, format!("#!/bin/sh\n{}\n", DEFAULT_RUST_PRE_COMMIT_COMMAND));
+    }
+
+    #[test]
+    fn test_fix_scaffolds_configured_pre_commit_for_initialized_repo() {
+        let temp_dir = setup_rust_repo();
+        fs::create_dir_all(temp_dir.path().join(".husky")).unwrap();
+        let runner = Arc::new(RecordingCommandRunner::new());
+        let rule = HuskyInitRule::new();
+        let context = RuleContext::new(
+            temp_dir.path().to_path_buf(),
+            serde_json::json!({"rustPreCommitCommand": "cargo test"}),
+        )
+        .with_command_runner(runner.clone());
+        let has_missing_source = |context: &RuleContext| {
+            rule.check(context)
+                .iter()
+                .any(|r| r.check_id == CHECK_RUST_PRE_COMMIT_SOURCE)
+        };
+        assert!(has_missing_source(&context));
+
+        assert_eq!(rule.fix(&context).unwrap(), 1);
+
+        assert!(runner.commands().is_empty());
+        let hook = fs::read_to_string(temp_dir.path().join(".husky/hooks/pre-commit")).unwrap();
+        assert_eq!(hook, "#!/bin/sh\ncargo test\n");
+        assert!(!has_missing_source(&context));
+    }
+}
@@ -0,0 +1,244 @@
+use crate::rules::{Rule, RuleError};
+use crate::types::{CheckEntry, FixEntry, LintResult, RuleContext, Severity};
+use crate::walk;
+use serde::Serialize;
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+// Check IDs
+const CHECK_CANONICAL_FORMAT: &str = "canonical-format";
+
+// Fix IDs
+const FIX_REFORMAT: &str = "reformat-json";
+
+/// Default number of spaces per indentation level
+const DEFAULT_INDENT: u64 = 2;
+
+/// Config files whose formatting is checked
+const JSON_CONFIGS: &[&str] = &["package.json", "tsconfig.json", "cspell.json"];
+
+/// Directories that never contain first-party config
+const IGNORED_DIRS: &[&str] = &["node_modules", ".git"];
+
+/// Rule: Keep committed JSON configs in one canonical indentation
+pub struct JsonFormatRule;
+
+impl JsonFormatRule {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Read the `indent` option, falling back to the default
+    fn indent(&self, context: &RuleContext) -> usize {
+        context
+            .config
+            .get("indent")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(DEFAULT_INDENT) as usize
+    }
+
+    fn find_json_configs(&self, context: &RuleContext) -> Vec<PathBuf> {
+        walk::entries(&context.root, context.follow_symlinks, IGNORED_DIRS)
+            .into_iter()
+            .filter(|e| {
+                e.file_name()
+                    .to_str()
+                    .is_some_and(|name| JSON_CONFIGS.contains(&name))
+                    && e.path().is_file()
+            })
+            .map(|e| e.into_path())
+            .collect()
+    }
+
+    /// Re-serialize `content` with the given indent, keeping key order.
+    /// Returns None when the file isn't plain JSON (e.g. a tsconfig with comments).
+    fn canonical_form(content: &str, indent: usize) -> Option<String> {
+        let json: Value = serde_json::from_str(content).ok()?;
+        let indent = " ".repeat(indent);
+        let formatter = serde_json::ser::PrettyFormatter::with_indent(indent.as_bytes());
+        let mut buf = Vec::new();
+        let mut serializer = serde_json::Serializer::with_formatter(&mut buf, formatter);
+        json.serialize(&mut serializer).ok()?;
+        let mut formatted = String::from_utf8(buf).ok()?;
+        formatted.push('\n');
+        Some(formatted)
+    }
+
+    /// Whether the on-disk content differs from the canonical form.
+    /// A missing trailing newline alone isn't reported.
+    fn differs(content: &str, canonical: &str) -> bool {
+        content.trim_end() != canonical.trim_end()
+    }
+
+    fn check_file(&self, path: &Path, indent: usize) -> Option<LintResult> {
+        let content = std::fs::read_to_string(path).ok()?;
+        let canonical = Self::canonical_form(&content, indent)?;
+        if !Self::differs(&content, &canonical) {
+            return None;
+        }
+
+        Some(LintResult::new(
+            self.id(),
+            CHECK_CANONICAL_FORMAT,
+            self.default_severity(),
+            format!(
+                "{} is not formatted with {}-space indentation",
+                path.file_name().unwrap_or_default().to_string_lossy(),
+                indent
+            ),
+            path.to_path_buf(),
+            None,
+            Some(format!("Reformat the file with {}-space indentation", indent)),
+            vec![FIX_REFORMAT],
+        ))
+    }
+}
+
+impl Default for JsonFormatRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Rule for JsonFormatRule {
+    fn id(&self) -> &'static str {
+        "json-format"
+    }
+
+    fn name(&self) -> &'static str {
+        "JSON Format"
+    }
+
+    fn description(&self) -> &'static str {
+        "Ensures committed JSON configs (package.json, tsconfig.json, cspell.json) use consistent indentation"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn tags(&self) -> Vec<&'static str> {
+        vec!["formatting"]
+    }
+
+    fn checks(&self) -> Vec<CheckEntry> {
+        vec![CheckEntry::new(
+            CHECK_CANONICAL_FORMAT,
+            "Verify JSON configs match their canonical form (option: indent)",
+        )]
+    }
+
+    fn fixes(&self) -> Vec<FixEntry> {
+        vec![FixEntry::new(
+            FIX_REFORMAT,
+            "Rewrite JSON configs in canonical form, preserving key order",
+            vec![CHECK_CANONICAL_FORMAT],
+        )]
+    }
+
+    fn check(&self, context: &RuleContext) -> Vec<LintResult> {
+        let indent = self.indent(context);
+
+        self.find_json_configs(context)
+            .iter()
+            .filter_map(|path| self.check_file(path, indent))
+            .collect()
+    }
+
+    fn fix(&self, context: &RuleContext) -> Result<u32, RuleError> {
+        let indent = self.indent(context);
+        let mut fixed = 0;
+
+        for path in self.find_json_configs(context) {
+            let content = context.read_file(&path)?;
+            let Some(canonical) = Self::canonical_form(&content, indent) else {
+                continue; // Leave files we can't parse untouched
+            };
+            if Self::differs(&content, &canonical) {
+                context.write_file(&path, &canonical)?;
+                fixed += 1;
+            }
+        }
+
+        Ok(fixed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    const FOUR_SPACE: &str = "{\n    \"name\": \"test\",\n    \"version\": \"1.0.0\",\n    \"scripts\": {\n        \"test\": \"vitest\"\n    }\n}\n";
+    const TWO_SPACE: &str = "{\n  \"name\": \"test\",\n  \"version\": \"1.0.0\",\n  \"scripts\": {\n    \"test\": \"vitest\"\n  }\n}\n";
+
+    fn create_context(root: PathBuf, options: Value) -> RuleContext {
+        RuleContext::new(root, options)
+    }
+
+    #[test]
+    fn test_flags_four_space_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::write(root.join("package.json"), FOUR_SPACE).unwrap();
+
+        let rule = JsonFormatRule::new();
+        let results = rule.check(&create_context(root, Value::Null));
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].check_id, CHECK_CANONICAL_FORMAT);
+        assert!(results[0].message.contains("2-space"));
+    }
+
+    #[test]
+    fn test_fix_reformats_to_two_space_preserving_key_order() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::write(root.join("package.json"), FOUR_SPACE).unwrap();
+
+        let rule = JsonFormatRule::new();
+        let context = create_context(root.clone(), Value::Null);
+
+        assert_eq!(rule.fix(&context).unwrap(), 1);
+        assert_eq!(fs::read_to_string(root.join("package.json")).unwrap(), TWO_SPACE);
+        assert!(rule.check(&context).is_empty());
+    }
+
+    #[test]
+    fn test_respects_configured_indent() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::write(root.join("cspell.json"), FOUR_SPACE).unwrap();
+
+        let rule = JsonFormatRule::new();
+
+        assert!(rule
+            .check(&create_context(root.clone(), serde_json::json!({"indent": 4})))
+            .is_empty());
+        assert_eq!(rule.check(&create_context(root, Value::Null)).len(), 1);
+    }
+
+    #[test]
+    fn test_skips_unparseable_and_unlisted_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        // tsconfig with comments is JSONC, not plain JSON
+        fs::write(
+            root.join("tsconfig.json"),
+            "{\n    // strict mode\n    \"compilerOptions\": {}\n}\n",
+        )
+        .unwrap();
+        fs::write(root.join("data.json"), FOUR_SPACE).unwrap();
+
+        let rule = JsonFormatRule::new();
+        let context = create_context(root, Value::Null);
+
+        assert!(rule.check(&context).is_empty());
+        assert_eq!(rule.fix(&context).unwrap(), 0);
+    }
+}
@@ -0,0 +1,206 @@
+use crate::gitignore;
+use crate::rules::Rule;
+use crate::types::{CheckEntry, FixEntry, LintResult, RuleContext, Severity};
+use crate::walk;
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use std::path::Path;
+
+// Check IDs
+const CHECK_FILE_SIZE_LIMIT: &str = "file-size-limit";
+
+/// Default largest file size, in bytes, allowed in the repository (5 MiB)
+const DEFAULT_MAX_BYTES: u64 = 5 * 1024 * 1024;
+
+/// Directories holding dependencies or build output rather than committed files
+const IGNORED_DIRS: &[&str] = &[
+    "node_modules",
+    ".git",
+    "dist",
+    "build",
+    "coverage",
+    "target",
+];
+
+/// Rule: Warn about large files that probably shouldn't be committed
+pub struct LargeFileRule;
+
+impl LargeFileRule {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Read the `maxBytes` option, falling back to the default
+    fn max_bytes(&self, context: &RuleContext) -> u64 {
+        context
+            .config
+            .get("maxBytes")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(DEFAULT_MAX_BYTES)
+    }
+
+    /// Build the `allow` option (globs relative to the root) into a matcher.
+    /// Invalid patterns are skipped.
+    fn allowlist(&self, context: &RuleContext) -> GlobSet {
+        let mut builder = GlobSetBuilder::new();
+        let patterns = context.config.get("allow").and_then(|v| v.as_array());
+        for pattern in patterns.into_iter().flatten().filter_map(|p| p.as_str()) {
+            let pattern = pattern.trim_start_matches("./");
+            if let Ok(glob) = GlobBuilder::new(pattern).literal_separator(true).build() {
+                builder.add(glob);
+            }
+        }
+        builder.build().unwrap_or_else(|_| GlobSet::empty())
+    }
+
+    /// Relative path as a '/'-separated string, matching how `allow` globs are written
+    fn relative_path(path: &Path, root: &Path) -> Option<String> {
+        let relative = path.strip_prefix(root).ok()?;
+        let parts: Vec<_> = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect();
+        Some(parts.join("/"))
+    }
+
+    /// Human-readable size, e.g. `7.3 MiB`
+    fn format_size(bytes: u64) -> String {
+        const KIB: f64 = 1024.0;
+        let bytes_f = bytes as f64;
+        if bytes_f >= KIB * KIB {
+            format!("{:.1} MiB", bytes_f / (KIB * KIB))
+        } else if bytes_f >= KIB {
+            format!("{:.1} KiB", bytes_f / KIB)
+        } else {
+            format!("{} B", bytes)
+        }
+    }
+}
+
+impl Default for LargeFileRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Rule for LargeFileRule {
+    fn id(&self) -> &'static str {
+        "large-file"
+    }
+
+    fn name(&self) -> &'static str {
+        "Large Files"
+    }
+
+    fn description(&self) -> &'static str {
+        "Warns about files over a size limit that should live in Git LFS or be gitignored"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn tags(&self) -> Vec<&'static str> {
+        vec!["git"]
+    }
+
+    fn checks(&self) -> Vec<CheckEntry> {
+        vec![CheckEntry::new(
+            CHECK_FILE_SIZE_LIMIT,
+            "Verify no file outside build/dependency dirs exceeds maxBytes (option: allow)",
+        )]
+    }
+
+    fn fixes(&self) -> Vec<FixEntry> {
+        vec![]
+    }
+
+    fn check(&self, context: &RuleContext) -> Vec<LintResult> {
+        let max_bytes = self.max_bytes(context);
+        let allowlist = self.allowlist(context);
+
+        walk::entries(&context.root, context.follow_symlinks, IGNORED_DIRS)
+            .into_iter()
+            .filter(|e| e.path().is_file())
+            .filter_map(|e| {
+                let size = e.metadata().ok()?.len();
+                if size <= max_bytes {
+                    return None;
+                }
+                let relative = Self::relative_path(e.path(), &context.root)?;
+                if allowlist.is_match(&relative)
+                    || gitignore::is_ignored(&context.root, Path::new(&relative), false)
+                {
+                    return None;
+                }
+                Some(LintResult::new(
+                    self.id(),
+                    CHECK_FILE_SIZE_LIMIT,
+                    self.default_severity(),
+                    format!(
+                        "File is {}, over the {} limit",
+                        Self::format_size(size),
+                        Self::format_size(max_bytes)
+                    ),
+                    e.into_path(),
+                    None,
+                    Some(
+                        "Track it with Git LFS, add it to .gitignore, or list it in `allow`"
+                            .into(),
+                    ),
+                    vec![], // Report-only: deleting files is never automatic
+                ))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn check(temp_dir: &TempDir, options: Value) -> Vec<LintResult> {
+        let context = RuleContext::new(temp_dir.path().to_path_buf(), options);
+        LargeFileRule::new().check(&context)
+    }
+
+    #[test]
+    fn test_flags_file_over_limit() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir_all(temp_dir.path().join("assets")).unwrap();
+        fs::write(temp_dir.path().join("assets/video.mp4"), vec![0u8; 2048]).unwrap();
+        fs::write(temp_dir.path().join("README.md"), "# Small\n").unwrap();
+
+        let results = check(&temp_dir, serde_json::json!({"maxBytes": 1024}));
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].check_id, CHECK_FILE_SIZE_LIMIT);
+        assert_eq!(results[0].message, "File is 2.0 KiB, over the 1.0 KiB limit");
+        assert!(results[0].path.ends_with("assets/video.mp4"));
+        assert!(results[0].fixable_by.is_empty());
+    }
+
+    #[test]
+    fn test_accepts_files_under_limit_allowed_or_ignored() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(root.join("small.bin"), vec![0u8; 512]).unwrap();
+        fs::create_dir_all(root.join("fixtures")).unwrap();
+        fs::write(root.join("fixtures/big.bin"), vec![0u8; 2048]).unwrap();
+        fs::write(root.join(".gitignore"), "*.log\n").unwrap();
+        fs::write(root.join("debug.log"), vec![b'x'; 2048]).unwrap();
+        fs::create_dir_all(root.join("node_modules/pkg")).unwrap();
+        fs::write(root.join("node_modules/pkg/blob.bin"), vec![0u8; 2048]).unwrap();
+
+        let options = serde_json::json!({"maxBytes": 1024, "allow": ["fixtures/**"]});
+        assert!(check(&temp_dir, options).is_empty());
+        // The default limit is far above every file here
+        assert!(check(&temp_dir, Value::Null).is_empty());
+    }
+}
@@ -0,0 +1,224 @@
+use crate::gitignore;
+use crate::rules::{file_too_large_result, Rule};
+use crate::types::{CheckEntry, FixEntry, LintResult, RuleContext, Severity};
+use crate::walk;
+use std::path::{Path, PathBuf};
+
+// Check IDs
+const CHECK_RELATIVE_LINK_EXISTS: &str = "relative-link-exists";
+
+/// Directories that never contain first-party docs
+const IGNORED_DIRS: &[&str] = &["node_modules", ".git", "dist", "build", "coverage", "target"];
+
+/// Rule: Report relative links in markdown files that point at missing files
+pub struct MarkdownHygieneRule;
+
+impl MarkdownHygieneRule {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Markdown files under the root, skipping build output and gitignored files
+    fn find_markdown_files(&self, context: &RuleContext) -> Vec<PathBuf> {
+        walk::entries(&context.root, context.follow_symlinks, IGNORED_DIRS)
+            .into_iter()
+            .filter(|e| e.path().is_file())
+            .filter(|e| e.path().extension().is_some_and(|ext| ext == "md"))
+            .map(|e| e.into_path())
+            .filter(|path| match path.strip_prefix(&context.root) {
+                Ok(relative) => !gitignore::is_ignored(&context.root, relative, false),
+                Err(_) => true,
+            })
+            .collect()
+    }
+
+    /// Link targets on one line: inline `[text](target)` / `![alt](target)`
+    /// and reference definitions `[label]: target`. Inline code is skipped.
+    fn link_targets(line: &str) -> Vec<&str> {
+        let trimmed = line.trim_start();
+        if let Some(rest) = trimmed.strip_prefix('[') {
+            if let Some((_, target)) = rest.split_once("]:") {
+                return target.split_whitespace().next().into_iter().collect();
+            }
+        }
+
+        let mut targets = Vec::new();
+        let mut in_code = false;
+        let mut rest = line;
+        while let Some(pos) = rest.find(['`', ']']) {
+            let (marker, after) = (rest.as_bytes()[pos], &rest[pos + 1..]);
+            rest = after;
+            if marker == b'`' {
+                in_code = !in_code;
+            } else if !in_code {
+                if let Some(inner) = after.strip_prefix('(') {
+                    if let Some(end) = inner.find(')') {
+                        targets.push(inner[..end].split_whitespace().next().unwrap_or(""));
+                        rest = &inner[end + 1..];
+                    }
+                }
+            }
+        }
+        targets
+    }
+
+    /// The local file a link points at, or None for URLs, anchors and site-absolute paths
+    fn local_target(markdown_dir: &Path, target: &str) -> Option<PathBuf> {
+        let target = target.trim_start_matches('<').trim_end_matches('>');
+        if target.is_empty() || target.starts_with('#') || target.starts_with('/') {
+            return None;
+        }
+        if target.contains("://") || target.starts_with("mailto:") {
+            return None;
+        }
+
+        let path = target.split(['#', '?']).next()?;
+        if path.is_empty() {
+            return None;
+        }
+        Some(markdown_dir.join(path.replace("%20", " ")))
+    }
+
+    fn check_file(&self, path: &Path, context: &RuleContext) -> Vec<LintResult> {
+        let content = match context.read_file_capped(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::FileTooLarge => {
+                return vec![file_too_large_result(self, path, &e)];
+            }
+            Err(_) => return Vec::new(),
+        };
+        let markdown_dir = path.parent().unwrap_or(Path::new("."));
+
+        let mut results = Vec::new();
+        let mut in_fence = false;
+        for (index, line) in content.lines().enumerate() {
+            if line.trim_start().starts_with("```") {
+                in_fence = !in_fence;
+                continue;
+            }
+            if in_fence {
+                continue;
+            }
+
+            for target in Self::link_targets(line) {
+                let Some(resolved) = Self::local_target(markdown_dir, target) else {
+                    continue;
+                };
+                if resolved.exists() {
+                    continue;
+                }
+                results.push(LintResult::new(
+                    self.id(),
+                    CHECK_RELATIVE_LINK_EXISTS,
+                    self.default_severity(),
+                    format!("Broken relative link '{}'", target),
+                    path.to_path_buf(),
+                    Some(index as u32 + 1),
+                    Some("Fix the link target or remove the link".into()),
+                    vec![], // Report-only
+                ));
+            }
+        }
+        results
+    }
+}
+
+impl Default for MarkdownHygieneRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Rule for MarkdownHygieneRule {
+    fn id(&self) -> &'static str {
+        "markdown-hygiene"
+    }
+
+    fn name(&self) -> &'static str {
+        "Markdown Hygiene"
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports relative links in markdown files that point at files which don't exist"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn tags(&self) -> Vec<&'static str> {
+        vec!["docs"]
+    }
+
+    fn checks(&self) -> Vec<CheckEntry> {
+        vec![CheckEntry::new(
+            CHECK_RELATIVE_LINK_EXISTS,
+            "Verify relative links in .md files resolve to existing files",
+        )]
+    }
+
+    fn fixes(&self) -> Vec<FixEntry> {
+        vec![]
+    }
+
+    fn check(&self, context: &RuleContext) -> Vec<LintResult> {
+        self.find_markdown_files(context)
+            .iter()
+            .flat_map(|path| self.check_file(path, context))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn check(temp_dir: &TempDir) -> Vec<LintResult> {
+        let context =
+            RuleContext::new(temp_dir.path().to_path_buf(), serde_json::Value::Null);
+        MarkdownHygieneRule::new().check(&context)
+    }
+
+    #[test]
+    fn test_reports_broken_relative_link_with_line() {
+        let temp_dir = TempDir::new().unwrap();
+        let docs = temp_dir.path().join("docs");
+        fs::create_dir_all(&docs).unwrap();
+        fs::write(docs.join("setup.md"), "# Setup\n").unwrap();
+        fs::write(
+            docs.join("index.md"),
+            "# Docs\n\nSee [setup](./setup.md#install) and [usage](usage.md).\n\
+             Visit [site](https://example.com) or [top](#docs).\n",
+        )
+        .unwrap();
+
+        let results = check(&temp_dir);
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].message, "Broken relative link 'usage.md'");
+        assert_eq!(results[0].line, Some(3));
+        assert!(results[0].path.ends_with("docs/index.md"));
+    }
+
+    #[test]
+    fn test_ignores_code_and_gitignored_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(
+            root.join("README.md"),
+            "Use `[x](missing.md)` syntax.\n\n```md\n[y](missing.md)\n```\n\n[ok]: ./README.md\n",
+        )
+        .unwrap();
+        fs::write(root.join(".gitignore"), "notes/\n").unwrap();
+        fs::create_dir_all(root.join("notes")).unwrap();
+        fs::write(root.join("notes/todo.md"), "[gone](nowhere.md)\n").unwrap();
+
+        assert!(check(&temp_dir).is_empty());
+    }
+}
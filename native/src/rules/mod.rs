@@ -1,13 +1,40 @@
 pub mod claude_settings;
 pub mod cspell_config;
+pub mod dependency_hygiene;
+pub mod dockerfile_pinned_base;
 pub mod eslint_config_agent;
+pub mod gitignore_hygiene;
 pub mod husky_init;
+pub mod json_format;
+pub mod large_file;
+pub mod markdown_hygiene;
+pub mod module_type;
+pub mod package_name_convention;
 pub mod pnpm_usage;
+pub mod pr_template;
+pub mod publish_hygiene;
+pub mod repo_governance;
+pub mod repo_hygiene;
+pub mod scripts_hygiene;
+pub mod secret_scan;
+pub mod todo_budget;
+pub mod tsconfig;
+pub mod vscode_recommendations;
+pub mod vscode_settings;
+pub mod workspace_dep_consistency;
+pub mod workspace_engines_consistency;
+pub mod workspace_membership;
 
-use crate::types::{CheckEntry, FixEntry, LintResult, RuleContext, RuleInfo, Severity};
+use crate::types::{
+    CheckDescription, CheckEntry, Config, FixEntry, LintResult, RuleContext, RuleInfo, Severity,
+};
 use std::collections::HashMap;
+use std::path::Path;
 use std::sync::Arc;
 
+/// Check id for the note a rule emits when it skips a file over `maxFileSize`
+pub const CHECK_FILE_TOO_LARGE: &str = "file-too-large";
+
 /// Error type for rule operations
 #[derive(Debug, thiserror::Error)]
 pub enum RuleError {
@@ -37,6 +64,17 @@ pub trait Rule: Send + Sync {
     /// Default severity level
     fn default_severity(&self) -> Severity;
 
+    /// Whether the rule runs when the config doesn't mention it.
+    /// Opt-in rules override this to return false.
+    fn enabled_by_default(&self) -> bool {
+        true
+    }
+
+    /// Group tags; a config's `enabledTags` runs only rules carrying one of them
+    fn tags(&self) -> Vec<&'static str> {
+        vec![]
+    }
+
     // ─────────────────────────────────────────────────────────────────────────
     // Declarative Entries - What checks/fixes does this rule provide?
     // ─────────────────────────────────────────────────────────────────────────
@@ -49,6 +87,13 @@ pub trait Rule: Send + Sync {
     /// Each fix can address one or more check failures.
     fn fixes(&self) -> Vec<FixEntry>;
 
+    /// Globs, relative to the scan root, matching every file the checks read.
+    /// Cached runs reuse the previous results while those files are unchanged;
+    /// rules that declare none are always re-run.
+    fn inputs(&self) -> Vec<&'static str> {
+        vec![]
+    }
+
     // ─────────────────────────────────────────────────────────────────────────
     // Execution - Run checks or fixes
     // ─────────────────────────────────────────────────────────────────────────
@@ -56,6 +101,11 @@ pub trait Rule: Send + Sync {
     /// Execute all checks and return results
     fn check(&self, context: &RuleContext) -> Vec<LintResult>;
 
+    /// External programs a fix needs on PATH (e.g. `pnpm`); empty if it only edits files
+    fn fix_requirements(&self, _fix_id: &str) -> Vec<&'static str> {
+        vec![]
+    }
+
     /// Apply all applicable fixes, returns count of fixes applied
     fn fix(&self, _context: &RuleContext) -> Result<u32, RuleError> {
         Err(RuleError::FixNotSupported)
@@ -70,6 +120,24 @@ pub trait Rule: Send + Sync {
         !self.fixes().is_empty()
     }
 
+    /// Describe one check together with the fixes that address it
+    fn describe_check(&self, check_id: &str) -> Option<CheckDescription> {
+        let check = self.checks().into_iter().find(|c| c.id == check_id)?;
+        let fixes: Vec<FixEntry> = self
+            .fixes()
+            .into_iter()
+            .filter(|f| f.addresses.iter().any(|a| a == check_id))
+            .collect();
+
+        Some(CheckDescription {
+            rule_id: self.id().to_string(),
+            check_id: check.id,
+            description: check.description,
+            fixable: !fixes.is_empty(),
+            fixes,
+        })
+    }
+
     /// Get complete rule info for listing/introspection
     fn info(&self) -> RuleInfo {
         RuleInfo {
@@ -78,12 +146,166 @@ pub trait Rule: Send + Sync {
             description: self.description().to_string(),
             default_severity: self.default_severity().to_string(),
             can_fix: self.can_fix(),
+            enabled_by_default: self.enabled_by_default(),
+            tags: self.tags().into_iter().map(String::from).collect(),
             checks: self.checks(),
             fixes: self.fixes(),
         }
     }
 }
 
+/// Info note for a file `rule` skipped because `read_file_capped` refused it
+pub fn file_too_large_result(rule: &dyn Rule, path: &Path, error: &std::io::Error) -> LintResult {
+    LintResult::new(
+        rule.id(),
+        CHECK_FILE_TOO_LARGE,
+        Severity::Info,
+        format!("Skipped file too large to scan ({})", error),
+        path.to_path_buf(),
+        None,
+        Some("Raise the `maxFileSize` option to scan this file".into()),
+        vec![], // Report-only
+    )
+}
+
+/// Builds a rule on demand, so a registry can skip rules that will never run
+#[derive(Clone, Copy)]
+pub struct RuleFactory {
+    pub id: &'static str,
+    pub enabled_by_default: bool,
+    pub tags: &'static [&'static str],
+    pub build: fn() -> Arc<dyn Rule>,
+}
+
+impl RuleFactory {
+    fn of<R: Rule + Default + 'static>(
+        id: &'static str,
+        enabled_by_default: bool,
+        tags: &'static [&'static str],
+    ) -> Self {
+        Self {
+            id,
+            enabled_by_default,
+            tags,
+            build: || Arc::new(R::default()),
+        }
+    }
+
+    /// Whether `config` enables the rule (see `rule_enabled`)
+    fn enabled_in(&self, config: &Config) -> bool {
+        rule_enabled(config, self.id, self.enabled_by_default, self.tags)
+    }
+}
+
+/// Whether `config` enables a rule. An explicit `rules.<id>.enabled` wins; otherwise,
+/// when `enabledTags` is set, exactly the rules carrying one of those tags run, and
+/// without it the rule's own default applies.
+pub fn rule_enabled(config: &Config, id: &str, enabled_by_default: bool, tags: &[&str]) -> bool {
+    if let Some(rule_config) = config.rules.get(id) {
+        return rule_config.enabled;
+    }
+    match &config.enabled_tags {
+        Some(enabled_tags) => tags.iter().any(|tag| enabled_tags.iter().any(|t| t == tag)),
+        None => enabled_by_default,
+    }
+}
+
+/// The built-in rules in execution order. `id`, `enabled_by_default` and `tags` mirror
+/// the rule so disabled rules can be skipped without constructing them.
+fn builtin_rules() -> Vec<RuleFactory> {
+    vec![
+        RuleFactory::of::<claude_settings::ClaudeSettingsRule>(
+            "claude-settings-hooks",
+            true,
+            &["agent"],
+        ),
+        RuleFactory::of::<eslint_config_agent::EslintConfigAgentRule>(
+            "eslint-config-agent",
+            true,
+            &["agent", "quality"],
+        ),
+        RuleFactory::of::<husky_init::HuskyInitRule>("husky-init", true, &["git-hooks"]),
+        // cspell-config runs after husky-init so .husky exists in a single fix pass;
+        // with `fixIterations` > 1 a later pass also picks it up
+        RuleFactory::of::<cspell_config::CspellConfigRule>("cspell-config", true, &["quality"]),
+        RuleFactory::of::<pnpm_usage::PnpmUsageRule>("pnpm-usage", true, &["package-manager"]),
+        RuleFactory::of::<todo_budget::TodoBudgetRule>("todo-budget", false, &["quality"]),
+        RuleFactory::of::<repo_governance::RepoGovernanceRule>(
+            "repo-governance",
+            true,
+            &["governance"],
+        ),
+        RuleFactory::of::<repo_hygiene::RepoHygieneRule>("repo-hygiene", true, &["git"]),
+        RuleFactory::of::<pr_template::PrTemplateRule>("pr-template", false, &["governance"]),
+        RuleFactory::of::<publish_hygiene::PublishHygieneRule>(
+            "publish-hygiene",
+            true,
+            &["governance"],
+        ),
+        RuleFactory::of::<vscode_recommendations::VscodeRecommendationsRule>(
+            "vscode-recommendations",
+            false,
+            &["editor"],
+        ),
+        RuleFactory::of::<vscode_settings::VscodeSettingsRule>(
+            "vscode-settings",
+            false,
+            &["editor"],
+        ),
+        RuleFactory::of::<workspace_membership::WorkspaceMembershipRule>(
+            "workspace-membership",
+            true,
+            &["package-manager", "workspace"],
+        ),
+        RuleFactory::of::<workspace_engines_consistency::WorkspaceEnginesConsistencyRule>(
+            "workspace-engines-consistency",
+            true,
+            &["workspace"],
+        ),
+        RuleFactory::of::<workspace_dep_consistency::WorkspaceDepConsistencyRule>(
+            "workspace-dep-consistency",
+            true,
+            &["workspace"],
+        ),
+        RuleFactory::of::<dockerfile_pinned_base::DockerfilePinnedBaseRule>(
+            "dockerfile-pinned-base",
+            false,
+            &["docker"],
+        ),
+        RuleFactory::of::<dependency_hygiene::DependencyHygieneRule>(
+            "dependency-hygiene",
+            true,
+            &["package-manager"],
+        ),
+        RuleFactory::of::<gitignore_hygiene::GitignoreHygieneRule>(
+            "gitignore-hygiene",
+            false,
+            &["git"],
+        ),
+        RuleFactory::of::<large_file::LargeFileRule>("large-file", false, &["git"]),
+        RuleFactory::of::<secret_scan::SecretScanRule>("secret-scan", false, &["git"]),
+        RuleFactory::of::<markdown_hygiene::MarkdownHygieneRule>(
+            "markdown-hygiene",
+            false,
+            &["docs"],
+        ),
+        RuleFactory::of::<package_name_convention::PackageNameConventionRule>(
+            "package-name-convention",
+            false,
+            &["workspace"],
+        ),
+        RuleFactory::of::<tsconfig::TsconfigRule>("tsconfig", true, &["typescript"]),
+        RuleFactory::of::<module_type::ModuleTypeRule>("module-type", true, &["quality"]),
+        RuleFactory::of::<scripts_hygiene::ScriptsHygieneRule>(
+            "scripts-hygiene",
+            false,
+            &["package-manager"],
+        ),
+        // json-format runs last so files written by other fixes are formatted too
+        RuleFactory::of::<json_format::JsonFormatRule>("json-format", false, &["formatting"]),
+    ]
+}
+
 /// Registry holding all available rules
 /// Rules are stored in insertion order for deterministic fix execution
 pub struct RuleRegistry {
@@ -93,21 +315,38 @@ pub struct RuleRegistry {
 
 impl RuleRegistry {
     pub fn new() -> Self {
-        let mut registry = Self {
+        Self::from_factories(&builtin_rules(), |_| true)
+    }
+
+    /// A registry with only the built-in rules `config` enables; the others are
+    /// never constructed
+    pub fn for_config(config: &Config) -> Self {
+        Self::from_factories(&builtin_rules(), |factory| factory.enabled_in(config))
+    }
+
+    /// A registry without the built-in rules
+    pub fn empty() -> Self {
+        Self {
             rules: HashMap::new(),
             order: Vec::new(),
-        };
-        registry.register_builtin_rules();
+        }
+    }
+
+    /// Build and register the rules `include` accepts, in the given order
+    fn from_factories(factories: &[RuleFactory], include: impl Fn(&RuleFactory) -> bool) -> Self {
+        let mut registry = Self::empty();
+        for factory in factories.iter().filter(|f| include(f)) {
+            registry.register((factory.build)());
+        }
         registry
     }
 
-    fn register_builtin_rules(&mut self) {
-        self.register(Arc::new(claude_settings::ClaudeSettingsRule::new()));
-        self.register(Arc::new(eslint_config_agent::EslintConfigAgentRule::new()));
-        self.register(Arc::new(husky_init::HuskyInitRule::new()));
-        // cspell-config must run after husky-init so .husky directory exists
-        self.register(Arc::new(cspell_config::CspellConfigRule::new()));
-        self.register(Arc::new(pnpm_usage::PnpmUsageRule::new()));
+    /// Construct a single built-in rule by id
+    pub fn builtin(id: &str) -> Option<Arc<dyn Rule>> {
+        builtin_rules()
+            .into_iter()
+            .find(|factory| factory.id == id)
+            .map(|factory| (factory.build)())
     }
 
     pub fn register(&mut self, rule: Arc<dyn Rule>) {
@@ -120,6 +359,11 @@ impl RuleRegistry {
         self.rules.get(id).cloned()
     }
 
+    /// Rule ids in execution order (the order fixes are applied in)
+    pub fn order(&self) -> &[String] {
+        &self.order
+    }
+
     /// Returns rules in registration order for deterministic execution
     pub fn all(&self) -> Vec<Arc<dyn Rule>> {
         self.order
@@ -134,3 +378,86 @@ impl Default for RuleRegistry {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::RuleConfig;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static BUILT: AtomicUsize = AtomicUsize::new(0);
+
+    /// Counts how often it is constructed
+    struct CountingRule;
+
+    impl Default for CountingRule {
+        fn default() -> Self {
+            BUILT.fetch_add(1, Ordering::SeqCst);
+            Self
+        }
+    }
+
+    impl Rule for CountingRule {
+        fn id(&self) -> &'static str {
+            "counting"
+        }
+        fn name(&self) -> &'static str {
+            "Counting"
+        }
+        fn description(&self) -> &'static str {
+            "Counts constructions"
+        }
+        fn default_severity(&self) -> Severity {
+            Severity::Info
+        }
+        fn checks(&self) -> Vec<CheckEntry> {
+            vec![]
+        }
+        fn fixes(&self) -> Vec<FixEntry> {
+            vec![]
+        }
+        fn check(&self, _context: &RuleContext) -> Vec<LintResult> {
+            vec![]
+        }
+    }
+
+    #[test]
+    fn test_builtin_factories_match_their_rules() {
+        for factory in builtin_rules() {
+            let rule = (factory.build)();
+            assert_eq!(rule.id(), factory.id);
+            assert_eq!(rule.enabled_by_default(), factory.enabled_by_default, "{}", factory.id);
+            assert_eq!(rule.tags(), factory.tags, "{}", factory.id);
+        }
+    }
+
+    #[test]
+    fn test_disabled_rules_are_not_constructed() {
+        let factories = [RuleFactory::of::<CountingRule>("counting", true, &[])];
+        let mut config = Config::default();
+        config.rules.insert(
+            "counting".into(),
+            RuleConfig {
+                enabled: false,
+                ..RuleConfig::default()
+            },
+        );
+        let registry = RuleRegistry::from_factories(&factories, |f| f.enabled_in(&config));
+        assert!(registry.get("counting").is_none());
+        assert_eq!(BUILT.load(Ordering::SeqCst), 0);
+
+        let registry = RuleRegistry::from_factories(&factories, |_| true);
+        assert!(registry.get("counting").is_some());
+        assert_eq!(BUILT.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_for_config_loads_only_enabled_builtins() {
+        let registry = RuleRegistry::for_config(&Config::default());
+
+        assert!(registry.get("husky-init").is_some());
+        assert!(registry.get("todo-budget").is_none());
+        assert!(RuleRegistry::builtin("todo-budget").is_some());
+        assert!(registry.order().len() < RuleRegistry::new().order().len());
+    }
+}
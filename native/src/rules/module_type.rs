@@ -0,0 +1,168 @@
+use crate::rules::Rule;
+use crate::types::{CheckEntry, FixEntry, LintResult, RuleContext, Severity};
+use std::path::Path;
+
+// Check IDs
+const CHECK_COMMONJS_CONFIG_EXTENSION: &str = "commonjs-config-extension";
+
+/// `.js` config files located by eslint-config-agent and cspell-config. Node loads
+/// them as ES modules when the nearest package.json sets `"type": "module"`.
+const JS_CONFIG_FILES: &[&str] = &[".eslintrc.js", "eslint.config.js", "cspell.config.js"];
+
+/// Rule: Ensure CommonJS config files load in ES module packages
+pub struct ModuleTypeRule;
+
+impl ModuleTypeRule {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Whether JS source assigns `module.exports` or `exports.x`, ignoring comment
+    /// lines. A heuristic, since the file isn't run.
+    fn uses_commonjs_exports(content: &str) -> bool {
+        content
+            .lines()
+            .map(str::trim_start)
+            .filter(|line| !["//", "/*", "*"].iter().any(|c| line.starts_with(c)))
+            .any(|line| line.contains("module.exports") || line.contains("exports."))
+    }
+
+    fn check_package(&self, package_json_path: &Path, context: &RuleContext) -> Vec<LintResult> {
+        let Some(json) = context.package_json_value(package_json_path) else {
+            return Vec::new();
+        };
+        if json.get("type").and_then(|t| t.as_str()) != Some("module") {
+            return Vec::new();
+        }
+        let project_dir = package_json_path.parent().unwrap_or(Path::new("."));
+
+        JS_CONFIG_FILES
+            .iter()
+            .filter_map(|name| {
+                let config_path = project_dir.join(name);
+                let content = context.read_file(&config_path).ok()?;
+                if !Self::uses_commonjs_exports(&content) {
+                    return None;
+                }
+                let renamed = format!("{}.cjs", name.trim_end_matches(".js"));
+                Some(LintResult::new(
+                    self.id(),
+                    CHECK_COMMONJS_CONFIG_EXTENSION,
+                    self.default_severity(),
+                    format!(
+                        "{} uses module.exports but package.json sets \"type\": \"module\", \
+                         so it fails to load",
+                        name
+                    ),
+                    config_path,
+                    None,
+                    Some(format!("Rename {} to {}", name, renamed)),
+                    vec![], // Report-only: renaming may break references to the file
+                ))
+            })
+            .collect()
+    }
+}
+
+impl Default for ModuleTypeRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Rule for ModuleTypeRule {
+    fn id(&self) -> &'static str {
+        "module-type"
+    }
+
+    fn name(&self) -> &'static str {
+        "Module Type"
+    }
+
+    fn description(&self) -> &'static str {
+        "Ensures config files using module.exports are named .cjs in \"type\": \"module\" packages"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn tags(&self) -> Vec<&'static str> {
+        vec!["quality"]
+    }
+
+    fn checks(&self) -> Vec<CheckEntry> {
+        vec![CheckEntry::new(
+            CHECK_COMMONJS_CONFIG_EXTENSION,
+            "Verify ESLint and cspell .js configs in ES module packages don't use module.exports",
+        )]
+    }
+
+    fn fixes(&self) -> Vec<FixEntry> {
+        vec![]
+    }
+
+    fn inputs(&self) -> Vec<&'static str> {
+        vec!["**/package.json", "**/.eslintrc.js", "**/eslint.config.js", "**/cspell.config.js"]
+    }
+
+    fn check(&self, context: &RuleContext) -> Vec<LintResult> {
+        context
+            .package_jsons()
+            .iter()
+            .flat_map(|path| self.check_package(path, context))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn check(temp_dir: &TempDir) -> Vec<LintResult> {
+        let context =
+            RuleContext::new(temp_dir.path().to_path_buf(), serde_json::Value::Null);
+        ModuleTypeRule::new().check(&context)
+    }
+
+    #[test]
+    fn test_flags_commonjs_config_in_esm_package() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(root.join("package.json"), r#"{"name": "app", "type": "module"}"#).unwrap();
+        fs::write(root.join(".eslintrc.js"), "module.exports = {\n  root: true,\n};\n").unwrap();
+        fs::write(
+            root.join("cspell.config.js"),
+            "// module.exports would fail here\nexport default { words: [] };\n",
+        )
+        .unwrap();
+
+        let results = check(&temp_dir);
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].check_id, CHECK_COMMONJS_CONFIG_EXTENSION);
+        assert_eq!(
+            results[0].message,
+            ".eslintrc.js uses module.exports but package.json sets \"type\": \"module\", \
+             so it fails to load"
+        );
+        assert_eq!(results[0].suggestion.as_deref(), Some("Rename .eslintrc.js to .eslintrc.cjs"));
+        assert_eq!(results[0].severity, "error");
+        assert!(results[0].fixable_by.is_empty());
+    }
+
+    #[test]
+    fn test_accepts_commonjs_packages_and_cjs_configs() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(root.join("package.json"), r#"{"name": "app"}"#).unwrap();
+        fs::write(root.join(".eslintrc.js"), "module.exports = {};\n").unwrap();
+        assert!(check(&temp_dir).is_empty());
+
+        fs::write(root.join("package.json"), r#"{"name": "app", "type": "module"}"#).unwrap();
+        fs::rename(root.join(".eslintrc.js"), root.join(".eslintrc.cjs")).unwrap();
+        assert!(check(&temp_dir).is_empty());
+    }
+}
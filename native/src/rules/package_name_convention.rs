@@ -0,0 +1,179 @@
+use crate::rules::Rule;
+use crate::types::{CheckEntry, FixEntry, LintResult, RuleContext, Severity};
+use std::path::Path;
+
+// Check IDs
+const CHECK_NAME_MATCHES_PATTERN: &str = "name-matches-pattern";
+
+/// Template used when no `pattern` option is configured
+const DEFAULT_PATTERN: &str = "{dir}";
+
+/// Rule: Ensure package names follow a naming template based on their directory
+pub struct PackageNameConventionRule;
+
+impl PackageNameConventionRule {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Read the `pattern` option, e.g. `@acme/{dir}`
+    fn pattern<'a>(&self, context: &'a RuleContext) -> &'a str {
+        context
+            .config
+            .get("pattern")
+            .and_then(|v| v.as_str())
+            .unwrap_or(DEFAULT_PATTERN)
+    }
+
+    /// The name the template expects for a package in `package_dir`
+    fn expected_name(pattern: &str, package_dir: &Path) -> Option<String> {
+        let dir = package_dir.file_name()?.to_str()?;
+        Some(pattern.replace("{dir}", dir))
+    }
+
+    /// A workspace root's package.json names the repository, not a package
+    fn is_workspace_root(package_dir: &Path) -> bool {
+        package_dir.join("pnpm-workspace.yaml").is_file()
+    }
+
+    fn check_package_json(
+        &self,
+        package_json_path: &Path,
+        context: &RuleContext,
+    ) -> Option<LintResult> {
+        let package_dir = package_json_path.parent()?;
+        if Self::is_workspace_root(package_dir) {
+            return None;
+        }
+
+        let json = context.package_json_value(package_json_path)?;
+        let name = json.get("name").and_then(|v| v.as_str())?;
+        let expected = Self::expected_name(self.pattern(context), package_dir)?;
+        if name == expected {
+            return None;
+        }
+
+        Some(LintResult::new(
+            self.id(),
+            CHECK_NAME_MATCHES_PATTERN,
+            self.default_severity(),
+            format!("Package name '{}' does not match expected '{}'", name, expected),
+            package_json_path.to_path_buf(),
+            None,
+            Some(format!(
+                "Rename the package to '{}' or move it to a matching directory",
+                expected
+            )),
+            vec![], // Report-only: renaming a package breaks its dependents
+        ))
+    }
+}
+
+impl Default for PackageNameConventionRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Rule for PackageNameConventionRule {
+    fn id(&self) -> &'static str {
+        "package-name-convention"
+    }
+
+    fn name(&self) -> &'static str {
+        "Package Name Convention"
+    }
+
+    fn description(&self) -> &'static str {
+        "Ensures package.json names follow a configured template such as @scope/{dir}"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn tags(&self) -> Vec<&'static str> {
+        vec!["workspace"]
+    }
+
+    fn checks(&self) -> Vec<CheckEntry> {
+        vec![CheckEntry::new(
+            CHECK_NAME_MATCHES_PATTERN,
+            "Verify each package name matches the `pattern` option, where {dir} is the package directory name",
+        )]
+    }
+
+    fn fixes(&self) -> Vec<FixEntry> {
+        vec![]
+    }
+
+    fn check(&self, context: &RuleContext) -> Vec<LintResult> {
+        context
+            .package_jsons()
+            .iter()
+            .filter_map(|path| self.check_package_json(path, context))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn setup_monorepo(packages: &[(&str, &str)]) -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(root.join("pnpm-workspace.yaml"), "packages:\n  - 'packages/*'\n").unwrap();
+        fs::write(root.join("package.json"), r#"{"name": "monorepo"}"#).unwrap();
+        for (dir, name) in packages {
+            let package_dir = root.join("packages").join(dir);
+            fs::create_dir_all(&package_dir).unwrap();
+            fs::write(
+                package_dir.join("package.json"),
+                format!(r#"{{"name": "{}"}}"#, name),
+            )
+            .unwrap();
+        }
+        temp_dir
+    }
+
+    fn check(temp_dir: &TempDir, options: serde_json::Value) -> Vec<LintResult> {
+        let context = RuleContext::new(temp_dir.path().to_path_buf(), options);
+        PackageNameConventionRule::new().check(&context)
+    }
+
+    #[test]
+    fn test_matching_names_pass() {
+        let temp_dir = setup_monorepo(&[("core", "@acme/core"), ("cli", "@acme/cli")]);
+
+        assert!(check(&temp_dir, serde_json::json!({"pattern": "@acme/{dir}"})).is_empty());
+    }
+
+    #[test]
+    fn test_mismatching_name_warns() {
+        let temp_dir = setup_monorepo(&[("core", "@acme/core"), ("cli", "acme-cli")]);
+
+        let results = check(&temp_dir, serde_json::json!({"pattern": "@acme/{dir}"}));
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].message,
+            "Package name 'acme-cli' does not match expected '@acme/cli'"
+        );
+        assert!(results[0].fixable_by.is_empty());
+    }
+
+    #[test]
+    fn test_default_pattern_is_directory_name() {
+        let temp_dir = setup_monorepo(&[("core", "core"), ("utils", "@acme/utils")]);
+
+        let results = check(&temp_dir, serde_json::Value::Null);
+        assert_eq!(results.len(), 1);
+        assert!(results[0].path.ends_with("utils/package.json"));
+    }
+}
@@ -1,23 +1,44 @@
 use crate::rules::{Rule, RuleError};
+use crate::shell::command_positions;
 use crate::types::{CheckEntry, FixEntry, LintResult, RuleContext, Severity};
 use serde_json::Value;
+use std::io::BufRead;
 use std::path::{Path, PathBuf};
-use walkdir::WalkDir;
 
 // Check IDs
 const CHECK_YARN_LOCK_EXISTS: &str = "yarn-lock-exists";
+const CHECK_YARNRC_EXISTS: &str = "yarnrc-exists";
 const CHECK_PACKAGE_LOCK_EXISTS: &str = "package-lock-exists";
 const CHECK_PACKAGE_MANAGER_FIELD: &str = "package-manager-field";
 const CHECK_PNPM_SETUP: &str = "pnpm-setup";
+const CHECK_PACKAGE_MANAGER_MISSING: &str = "package-manager-missing";
 const CHECK_SCRIPTS_NPM: &str = "scripts-use-npm";
 const CHECK_SCRIPTS_YARN: &str = "scripts-use-yarn";
+const CHECK_SCRIPTS_NPX: &str = "scripts-use-npx";
 const CHECK_ENGINES_NPM: &str = "engines-npm";
 const CHECK_ENGINES_YARN: &str = "engines-yarn";
+const CHECK_LOCKFILE_VERSION: &str = "lockfile-version";
+const CHECK_WORKSPACE_PROTOCOL: &str = "workspace-protocol-valid";
+const CHECK_PACKAGE_MANAGER_LOCKFILE: &str = "packagemanager-lockfile-match";
+const CHECK_HOOKS_PNPM: &str = "hooks-use-pnpm";
 
 // Fix IDs
 const FIX_REMOVE_YARN_LOCK: &str = "remove-yarn-lock";
+const FIX_REMOVE_YARNRC: &str = "remove-yarnrc";
 const FIX_REMOVE_PACKAGE_LOCK: &str = "remove-package-lock";
 const FIX_UPDATE_PACKAGE_MANAGER: &str = "update-package-manager";
+const FIX_ADD_PACKAGE_MANAGER: &str = "add-package-manager";
+const FIX_REPLACE_NPX: &str = "replace-npx-with-pnpm-exec";
+const FIX_REWRITE_HOOKS: &str = "rewrite-hooks-to-pnpm";
+
+/// Yarn config files: Berry's .yarnrc.yml and classic .yarnrc
+const YARNRC_FILES: &[&str] = &[".yarnrc.yml", ".yarnrc"];
+
+/// Package managers whose invocations in Husky hooks are rewritten to pnpm
+const FOREIGN_PACKAGE_MANAGERS: &[&str] = &["npm", "yarn"];
+
+/// Lockfile format major written by each pnpm major we know, oldest first
+const PNPM_LOCKFILE_MAJORS: &[(u64, u64)] = &[(8, 6), (9, 9), (10, 9)];
 
 /// Rule: Ensure projects use pnpm instead of npm or yarn
 pub struct PnpmUsageRule;
@@ -58,35 +79,265 @@ impl PnpmUsageRule {
         false
     }
 
-    /// Find all package.json files in the given root
-    fn find_package_jsons(&self, root: &Path) -> Vec<PathBuf> {
-        let mut package_jsons = Vec::new();
+    /// Rewrite every `npx <bin>` in a script to `pnpm exec <bin>`. Returns None when
+    /// an invocation passes npx flags (`-y`, `--package`), which have no direct
+    /// `pnpm exec` equivalent.
+    fn rewrite_npx(script: &str) -> Option<String> {
+        let positions = command_positions(script, "npx");
+        if positions.is_empty() {
+            return None;
+        }
 
-        for entry in WalkDir::new(root)
-            .follow_links(false)
-            .into_iter()
-            .filter_map(|e| e.ok())
-        {
-            let path = entry.path();
-
-            // Skip node_modules directories
-            if path
-                .components()
-                .any(|c| c.as_os_str() == "node_modules")
-            {
-                continue;
+        let mut rewritten = script.to_string();
+        for &i in positions.iter().rev() {
+            let next = script[i + 3..].split_whitespace().next()?;
+            if next.starts_with('-') {
+                return None;
             }
+            rewritten.replace_range(i..i + 3, "pnpm exec");
+        }
+        Some(rewritten)
+    }
+
+    /// Byte offsets and names of npm/yarn invocations in a shell line, sorted
+    fn package_manager_invocations(line: &str) -> Vec<(usize, &'static str)> {
+        let mut invocations: Vec<(usize, &'static str)> = FOREIGN_PACKAGE_MANAGERS
+            .iter()
+            .flat_map(|cmd| command_positions(line, cmd).into_iter().map(move |i| (i, *cmd)))
+            .collect();
+        invocations.sort();
+        invocations
+    }
+
+    /// Rewrite npm/yarn invocations in a hook script to pnpm, leaving comments
+    /// alone. `npm ci` has no pnpm counterpart and becomes a frozen install, and a
+    /// bare `yarn` (an install) becomes `pnpm install`.
+    /// Returns None when nothing needs rewriting.
+    fn rewrite_hook(content: &str) -> Option<String> {
+        let mut changed = false;
+        let rewritten: String = content
+            .split_inclusive('\n')
+            .map(|line| {
+                let invocations = if line.trim_start().starts_with('#') {
+                    Vec::new()
+                } else {
+                    Self::package_manager_invocations(line)
+                };
+                let mut line = line.to_string();
+                for &(i, cmd) in invocations.iter().rev() {
+                    let rest = &line[i + cmd.len()..];
+                    let subcommand = rest.split_whitespace().next();
+                    if cmd == "npm" && subcommand == Some("ci") {
+                        let ci_end = i + cmd.len() + rest.find("ci").unwrap_or(0) + 2;
+                        line.replace_range(i..ci_end, "pnpm install --frozen-lockfile");
+                    } else if cmd == "yarn"
+                        && subcommand.is_none_or(|word| word.starts_with([';', '&', '|', ')']))
+                    {
+                        line.replace_range(i..i + cmd.len(), "pnpm install");
+                    } else {
+                        line.replace_range(i..i + cmd.len(), "pnpm");
+                    }
+                    changed = true;
+                }
+                line
+            })
+            .collect();
+        changed.then_some(rewritten)
+    }
+
+    /// Whether a package uses pnpm: packageManager declares it or pnpm-lock.yaml exists
+    fn uses_pnpm(package_dir: &Path, json: &Value) -> bool {
+        package_dir.join("pnpm-lock.yaml").exists()
+            || json
+                .get("packageManager")
+                .and_then(|v| v.as_str())
+                .is_some_and(|pm| pm.starts_with("pnpm@"))
+    }
+
+    /// Hook scripts directly in .husky/, skipping husky's own `_` directory and dotfiles
+    fn husky_hooks(package_dir: &Path, context: &RuleContext) -> Vec<PathBuf> {
+        context
+            .list_files(&package_dir.join(".husky"))
+            .into_iter()
+            .filter(|path| !path.file_name().is_some_and(|n| n.to_string_lossy().starts_with('.')))
+            .collect()
+    }
+
+    /// Warn about Husky hooks that run npm or yarn in a pnpm package
+    fn check_hooks(
+        &self,
+        package_dir: &Path,
+        json: &Value,
+        context: &RuleContext,
+    ) -> Vec<LintResult> {
+        if !Self::uses_pnpm(package_dir, json) {
+            return Vec::new();
+        }
+
+        Self::husky_hooks(package_dir, context)
+            .into_iter()
+            .filter_map(|hook| {
+                let content = context.read_file(&hook).ok()?;
+                let (line_number, invocations) =
+                    content.lines().enumerate().find_map(|(i, line)| {
+                        let invocations = Self::package_manager_invocations(line);
+                        (!line.trim_start().starts_with('#') && !invocations.is_empty())
+                            .then_some((i + 1, invocations))
+                    })?;
+                let hook_name = hook.file_name()?.to_string_lossy().into_owned();
+                Some(LintResult::new(
+                    self.id(),
+                    CHECK_HOOKS_PNPM,
+                    Severity::Warning,
+                    format!(
+                        "Hook '.husky/{}' runs {} but the project uses pnpm",
+                        hook_name, invocations[0].1
+                    ),
+                    hook,
+                    Some(line_number as u32),
+                    Some("Replace 'npm'/'yarn' with 'pnpm' in the hook".into()),
+                    vec![FIX_REWRITE_HOOKS],
+                ))
+            })
+            .collect()
+    }
 
-            if path.is_file() && path.file_name().is_some_and(|n| n == "package.json") {
-                package_jsons.push(path.to_path_buf());
+    /// Rewrite npm/yarn invocations in Husky hooks of a pnpm package
+    fn fix_hooks(&self, package_json_path: &Path, context: &RuleContext) -> Result<u32, RuleError> {
+        let package_dir = package_json_path.parent().unwrap_or(Path::new("."));
+        // Read fresh, since the packageManager fix may have just switched it to pnpm
+        let json: Value = serde_json::from_str(&context.read_file(package_json_path)?)?;
+        if !Self::uses_pnpm(package_dir, &json) {
+            return Ok(0);
+        }
+
+        let mut fixed = 0;
+        for hook in Self::husky_hooks(package_dir, context) {
+            if let Some(rewritten) = Self::rewrite_hook(&context.read_file(&hook)?) {
+                context.write_file(&hook, &rewritten)?;
+                fixed += 1;
             }
         }
+        Ok(fixed)
+    }
+
+    /// Lockfile format major written by a given pnpm major, for the versions we know
+    fn expected_lockfile_major(pnpm_major: u64) -> Option<u64> {
+        PNPM_LOCKFILE_MAJORS
+            .iter()
+            .find(|(pnpm, _)| *pnpm == pnpm_major)
+            .map(|(_, lockfile)| *lockfile)
+    }
+
+    /// Dependencies declared with the `workspace:` protocol, as `name@spec`
+    fn workspace_protocol_deps(json: &Value) -> Vec<String> {
+        ["dependencies", "devDependencies", "peerDependencies", "optionalDependencies"]
+            .iter()
+            .filter_map(|key| json.get(key).and_then(|d| d.as_object()))
+            .flatten()
+            .filter_map(|(name, spec)| {
+                spec.as_str()
+                    .filter(|spec| spec.starts_with("workspace:"))
+                    .map(|spec| format!("{}@{}", name, spec))
+            })
+            .collect()
+    }
+
+    /// Whether the package dir or any ancestor holds a pnpm-workspace.yaml
+    fn in_pnpm_workspace(package_dir: &Path) -> bool {
+        package_dir
+            .ancestors()
+            .any(|dir| dir.join("pnpm-workspace.yaml").is_file())
+    }
+
+    /// Read `lockfileVersion` from the top of pnpm-lock.yaml without parsing the whole file
+    fn read_lockfile_version(pnpm_lock: &Path) -> Option<String> {
+        let file = std::fs::File::open(pnpm_lock).ok()?;
+        std::io::BufReader::new(file)
+            .lines()
+            .take(10)
+            .map_while(Result::ok)
+            .find_map(|line| {
+                line.strip_prefix("lockfileVersion:")
+                    .map(|v| v.trim().trim_matches(|c| c == '\'' || c == '"').to_string())
+            })
+    }
+
+    /// Version to pin in packageManager: the `pnpmVersion` option, or the first
+    /// release of the pnpm major that writes the lockfile's format
+    fn pinned_pnpm_version(context: &RuleContext, pnpm_lock: &Path) -> Option<String> {
+        if let Some(version) = context.config.get("pnpmVersion").and_then(|v| v.as_str()) {
+            return Some(version.trim_start_matches("pnpm@").to_string());
+        }
+        let lockfile_version = Self::read_lockfile_version(pnpm_lock)?;
+        let lockfile_major: u64 = lockfile_version.split('.').next()?.parse().ok()?;
+        PNPM_LOCKFILE_MAJORS
+            .iter()
+            .find(|(_, lockfile)| *lockfile == lockfile_major)
+            .map(|(pnpm, _)| format!("{}.0.0", pnpm))
+    }
+
+    /// Warn when pnpm-lock.yaml was written by a different pnpm major than the pinned one
+    fn check_lockfile_version(
+        &self,
+        pkg_manager: &str,
+        pnpm_lock: &Path,
+        package_json_path: &Path,
+    ) -> Option<LintResult> {
+        let pnpm_major: u64 = pkg_manager
+            .strip_prefix("pnpm@")?
+            .split('.')
+            .next()?
+            .parse()
+            .ok()?;
+        let expected = Self::expected_lockfile_major(pnpm_major)?;
+        let lockfile_version = Self::read_lockfile_version(pnpm_lock)?;
+        let actual: u64 = lockfile_version.split('.').next()?.parse().ok()?;
+
+        if actual == expected {
+            return None;
+        }
+
+        Some(LintResult::new(
+            self.id(),
+            CHECK_LOCKFILE_VERSION,
+            Severity::Warning,
+            format!(
+                "pnpm-lock.yaml has lockfileVersion '{}' but packageManager pins pnpm {} (expects {}.x)",
+                lockfile_version, pnpm_major, expected
+            ),
+            package_json_path.to_path_buf(),
+            None,
+            Some(format!(
+                "Run 'pnpm install' with pnpm {} to regenerate pnpm-lock.yaml",
+                pnpm_major
+            )),
+            vec![], // Regenerating the lock requires network access
+        ))
+    }
 
-        package_jsons
+    /// Whether the `allowCommands` option permits running git to stage removals;
+    /// never in offline mode
+    fn allow_commands(&self, context: &RuleContext) -> bool {
+        !context.offline
+            && context
+                .config
+                .get("allowCommands")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false)
+    }
+
+    /// Deleting a tracked lockfile leaves it in the index until the removal is staged
+    fn lockfile_suggestion(lockfile: &str) -> String {
+        format!(
+            "Remove {0}, run 'git rm --cached {0}' if it is tracked, and use 'pnpm install' \
+             to generate pnpm-lock.yaml",
+            lockfile
+        )
     }
 
     /// Check a single package.json and its surrounding files for pnpm compliance
-    fn check_package_json(&self, package_json_path: &Path) -> Vec<LintResult> {
+    fn check_package_json(&self, package_json_path: &Path, context: &RuleContext) -> Vec<LintResult> {
         let mut results = Vec::new();
         let parent_dir = package_json_path.parent().unwrap_or(Path::new("."));
 
@@ -100,11 +351,43 @@ impl PnpmUsageRule {
                 "Found yarn.lock - project appears to use yarn instead of pnpm".into(),
                 yarn_lock,
                 None,
-                Some("Remove yarn.lock and use 'pnpm install' to generate pnpm-lock.yaml".into()),
+                Some(Self::lockfile_suggestion("yarn.lock")),
                 vec![FIX_REMOVE_YARN_LOCK],
             ));
         }
 
+        // Check for Yarn config (Berry's .yarnrc.yml, classic .yarnrc), which
+        // indicates yarn even without a lockfile
+        for name in YARNRC_FILES {
+            let yarnrc = parent_dir.join(name);
+            if yarnrc.is_file() {
+                results.push(LintResult::new(
+                    self.id(),
+                    CHECK_YARNRC_EXISTS,
+                    self.default_severity(),
+                    format!("Found {} - project appears to be configured for yarn", name),
+                    yarnrc,
+                    None,
+                    Some(format!("Remove {} and move any registry settings to .npmrc", name)),
+                    vec![FIX_REMOVE_YARNRC],
+                ));
+            }
+        }
+
+        let yarn_releases = parent_dir.join(".yarn").join("releases");
+        if yarn_releases.is_dir() {
+            results.push(LintResult::new(
+                self.id(),
+                CHECK_YARNRC_EXISTS,
+                self.default_severity(),
+                "Found .yarn/releases - project vendors a Yarn Berry binary".into(),
+                yarn_releases,
+                None,
+                Some("Remove .yarn/ once nothing in it (plugins, patches) is still needed".into()),
+                vec![], // .yarn/ may hold patches or plugins, so it is never removed automatically
+            ));
+        }
+
         // Check for package-lock.json (indicates npm usage)
         let package_lock = parent_dir.join("package-lock.json");
         if package_lock.exists() {
@@ -115,10 +398,7 @@ impl PnpmUsageRule {
                 "Found package-lock.json - project appears to use npm instead of pnpm".into(),
                 package_lock,
                 None,
-                Some(
-                    "Remove package-lock.json and use 'pnpm install' to generate pnpm-lock.yaml"
-                        .into(),
-                ),
+                Some(Self::lockfile_suggestion("package-lock.json")),
                 vec![FIX_REMOVE_PACKAGE_LOCK],
             ));
         }
@@ -128,143 +408,184 @@ impl PnpmUsageRule {
         let has_pnpm_lock = pnpm_lock.exists();
 
         // Parse and check package.json content
-        match std::fs::read_to_string(package_json_path) {
-            Ok(content) => match serde_json::from_str::<Value>(&content) {
-                Ok(json) => {
-                    // Check packageManager field
-                    if let Some(pkg_manager) = json.get("packageManager").and_then(|v| v.as_str()) {
-                        if !pkg_manager.starts_with("pnpm@") {
-                            results.push(LintResult::new(
-                                self.id(),
-                                CHECK_PACKAGE_MANAGER_FIELD,
-                                self.default_severity(),
-                                format!(
-                                    "packageManager is set to '{}' instead of pnpm",
-                                    pkg_manager
-                                ),
-                                package_json_path.to_path_buf(),
-                                None,
-                                Some(
-                                    "Change packageManager to 'pnpm@<version>' (e.g., 'pnpm@9.0.0')"
-                                        .into(),
-                                ),
-                                vec![FIX_UPDATE_PACKAGE_MANAGER],
-                            ));
-                        }
-                    } else if !has_pnpm_lock {
-                        // No packageManager field and no pnpm-lock.yaml - warn about missing pnpm setup
+        let Some(json) = context.package_json_value(package_json_path) else {
+            return results;
+        };
+
+        results.extend(self.check_declared_lockfiles(package_json_path, &json));
+        results.extend(self.check_hooks(parent_dir, &json, context));
+
+        // Check packageManager field
+        if let Some(pkg_manager) = json.get("packageManager").and_then(|v| v.as_str()) {
+            if !pkg_manager.starts_with("pnpm@") {
+                results.push(LintResult::new(
+                    self.id(),
+                    CHECK_PACKAGE_MANAGER_FIELD,
+                    self.default_severity(),
+                    format!(
+                        "packageManager is set to '{}' instead of pnpm",
+                        pkg_manager
+                    ),
+                    package_json_path.to_path_buf(),
+                    None,
+                    Some(
+                        "Change packageManager to 'pnpm@<version>' (e.g., 'pnpm@9.0.0')"
+                            .into(),
+                    ),
+                    vec![FIX_UPDATE_PACKAGE_MANAGER],
+                ));
+            } else if has_pnpm_lock {
+                results.extend(self.check_lockfile_version(
+                    pkg_manager,
+                    &pnpm_lock,
+                    package_json_path,
+                ));
+            }
+        } else if has_pnpm_lock {
+            // pnpm is in use, but Corepack can't pin its version without the field
+            let version = Self::pinned_pnpm_version(context, &pnpm_lock);
+            results.push(LintResult::new(
+                self.id(),
+                CHECK_PACKAGE_MANAGER_MISSING,
+                Severity::Warning,
+                "pnpm-lock.yaml exists but packageManager is not set".into(),
+                package_json_path.to_path_buf(),
+                None,
+                Some(match &version {
+                    Some(version) => format!("Add \"packageManager\": \"pnpm@{}\"", version),
+                    None => {
+                        "Add \"packageManager\": \"pnpm@<version>\" (option: pnpmVersion)".into()
+                    }
+                }),
+                if version.is_some() { vec![FIX_ADD_PACKAGE_MANAGER] } else { vec![] },
+            ));
+        } else {
+            // No packageManager field and no pnpm-lock.yaml - warn about missing pnpm setup
+            results.push(LintResult::new(
+                self.id(),
+                CHECK_PNPM_SETUP,
+                Severity::Warning,
+                "No packageManager field and no pnpm-lock.yaml found".into(),
+                package_json_path.to_path_buf(),
+                None,
+                Some(
+                    "Add 'packageManager' field with pnpm version or run 'pnpm install'"
+                        .into(),
+                ),
+                vec![], // Manual setup required
+            ));
+        }
+
+        // workspace: dependencies only resolve inside a pnpm workspace
+        let workspace_deps = Self::workspace_protocol_deps(&json);
+        if !workspace_deps.is_empty() && !Self::in_pnpm_workspace(parent_dir) {
+            results.push(LintResult::new(
+                self.id(),
+                CHECK_WORKSPACE_PROTOCOL,
+                self.default_severity(),
+                format!(
+                    "workspace: protocol used outside a pnpm workspace: {}",
+                    workspace_deps.join(", ")
+                ),
+                package_json_path.to_path_buf(),
+                None,
+                Some(
+                    "Add a pnpm-workspace.yaml above this package or depend on a published version"
+                        .into(),
+                ),
+                vec![], // Report-only: the right fix depends on the intended layout
+            ));
+        }
+
+        // Check for scripts using npm or yarn directly
+        if let Some(scripts) = json.get("scripts").and_then(|s| s.as_object()) {
+            for (script_name, script_value) in scripts {
+                if let Some(script_cmd) = script_value.as_str() {
+                    if !command_positions(script_cmd, "npx").is_empty() {
+                        let fixable = Self::rewrite_npx(script_cmd).is_some();
                         results.push(LintResult::new(
                             self.id(),
-                            CHECK_PNPM_SETUP,
+                            CHECK_SCRIPTS_NPX,
                             Severity::Warning,
-                            "No packageManager field and no pnpm-lock.yaml found".into(),
+                            format!(
+                                "Script '{}' uses npx, which may download an unpinned package",
+                                script_name
+                            ),
                             package_json_path.to_path_buf(),
                             None,
                             Some(
-                                "Add 'packageManager' field with pnpm version or run 'pnpm install'"
+                                "Replace 'npx' with 'pnpm exec' (or 'pnpm dlx' for one-off packages)"
                                     .into(),
                             ),
-                            vec![], // Manual setup required
+                            if fixable { vec![FIX_REPLACE_NPX] } else { vec![] },
                         ));
                     }
-
-                    // Check for scripts using npm or yarn directly
-                    if let Some(scripts) = json.get("scripts").and_then(|s| s.as_object()) {
-                        for (script_name, script_value) in scripts {
-                            if let Some(script_cmd) = script_value.as_str() {
-                                // Use word boundary detection to avoid false positives
-                                // e.g., "pnpm" contains "npm" as substring but shouldn't match
-                                if Self::contains_standalone_command(script_cmd, "npm") {
-                                    results.push(LintResult::new(
-                                        self.id(),
-                                        CHECK_SCRIPTS_NPM,
-                                        Severity::Warning,
-                                        format!(
-                                            "Script '{}' uses npm command - consider using pnpm",
-                                            script_name
-                                        ),
-                                        package_json_path.to_path_buf(),
-                                        None,
-                                        Some("Replace 'npm' with 'pnpm' in script commands".into()),
-                                        vec![], // Manual fix required
-                                    ));
-                                }
-                                if Self::contains_standalone_command(script_cmd, "yarn") {
-                                    results.push(LintResult::new(
-                                        self.id(),
-                                        CHECK_SCRIPTS_YARN,
-                                        Severity::Warning,
-                                        format!(
-                                            "Script '{}' uses yarn command - consider using pnpm",
-                                            script_name
-                                        ),
-                                        package_json_path.to_path_buf(),
-                                        None,
-                                        Some("Replace 'yarn' with 'pnpm' in script commands".into()),
-                                        vec![], // Manual fix required
-                                    ));
-                                }
-                            }
-                        }
+                    // Use word boundary detection to avoid false positives
+                    // e.g., "pnpm" contains "npm" as substring but shouldn't match
+                    if Self::contains_standalone_command(script_cmd, "npm") {
+                        results.push(LintResult::new(
+                            self.id(),
+                            CHECK_SCRIPTS_NPM,
+                            Severity::Warning,
+                            format!(
+                                "Script '{}' uses npm command - consider using pnpm",
+                                script_name
+                            ),
+                            package_json_path.to_path_buf(),
+                            None,
+                            Some("Replace 'npm' with 'pnpm' in script commands".into()),
+                            vec![], // Manual fix required
+                        ));
                     }
-
-                    // Check engines field for npm/yarn requirements
-                    if let Some(engines) = json.get("engines").and_then(|e| e.as_object()) {
-                        if engines.contains_key("npm") {
-                            results.push(LintResult::new(
-                                self.id(),
-                                CHECK_ENGINES_NPM,
-                                Severity::Warning,
-                                "engines.npm field found - suggests npm dependency".into(),
-                                package_json_path.to_path_buf(),
-                                None,
-                                Some(
-                                    "Consider removing engines.npm and adding engines.pnpm instead"
-                                        .into(),
-                                ),
-                                vec![], // Manual fix required
-                            ));
-                        }
-                        if engines.contains_key("yarn") {
-                            results.push(LintResult::new(
-                                self.id(),
-                                CHECK_ENGINES_YARN,
-                                Severity::Warning,
-                                "engines.yarn field found - suggests yarn dependency".into(),
-                                package_json_path.to_path_buf(),
-                                None,
-                                Some(
-                                    "Consider removing engines.yarn and adding engines.pnpm instead"
-                                        .into(),
-                                ),
-                                vec![], // Manual fix required
-                            ));
-                        }
+                    if Self::contains_standalone_command(script_cmd, "yarn") {
+                        results.push(LintResult::new(
+                            self.id(),
+                            CHECK_SCRIPTS_YARN,
+                            Severity::Warning,
+                            format!(
+                                "Script '{}' uses yarn command - consider using pnpm",
+                                script_name
+                            ),
+                            package_json_path.to_path_buf(),
+                            None,
+                            Some("Replace 'yarn' with 'pnpm' in script commands".into()),
+                            vec![], // Manual fix required
+                        ));
                     }
                 }
-                Err(e) => {
-                    results.push(LintResult::new(
-                        self.id(),
-                        CHECK_PACKAGE_MANAGER_FIELD,
-                        Severity::Error,
-                        format!("Invalid JSON in package.json: {}", e),
-                        package_json_path.to_path_buf(),
-                        None,
-                        Some("Fix JSON syntax errors".into()),
-                        vec![],
-                    ));
-                }
-            },
-            Err(e) => {
+            }
+        }
+
+        // Check engines field for npm/yarn requirements
+        if let Some(engines) = json.get("engines").and_then(|e| e.as_object()) {
+            if engines.contains_key("npm") {
                 results.push(LintResult::new(
                     self.id(),
-                    CHECK_PACKAGE_MANAGER_FIELD,
-                    Severity::Error,
-                    format!("Cannot read package.json: {}", e),
+                    CHECK_ENGINES_NPM,
+                    Severity::Warning,
+                    "engines.npm field found - suggests npm dependency".into(),
                     package_json_path.to_path_buf(),
                     None,
+                    Some(
+                        "Consider removing engines.npm and adding engines.pnpm instead"
+                            .into(),
+                    ),
+                    vec![], // Manual fix required
+                ));
+            }
+            if engines.contains_key("yarn") {
+                results.push(LintResult::new(
+                    self.id(),
+                    CHECK_ENGINES_YARN,
+                    Severity::Warning,
+                    "engines.yarn field found - suggests yarn dependency".into(),
+                    package_json_path.to_path_buf(),
                     None,
-                    vec![],
+                    Some(
+                        "Consider removing engines.yarn and adding engines.pnpm instead"
+                            .into(),
+                    ),
+                    vec![], // Manual fix required
                 ));
             }
         }
@@ -272,25 +593,129 @@ impl PnpmUsageRule {
         results
     }
 
-    /// Remove non-pnpm lock files
-    fn remove_lock_files(&self, parent_dir: &Path) -> std::io::Result<u32> {
+    /// Error on yarn/npm lockfiles next to a packageManager that declares pnpm,
+    /// since the repository then contradicts itself about how to install
+    fn check_declared_lockfiles(&self, package_json_path: &Path, json: &Value) -> Vec<LintResult> {
+        let Some(declared) = json
+            .get("packageManager")
+            .and_then(|v| v.as_str())
+            .filter(|pm| pm.starts_with("pnpm@"))
+        else {
+            return Vec::new();
+        };
+        let parent_dir = package_json_path.parent().unwrap_or(Path::new("."));
+
+        [("yarn.lock", FIX_REMOVE_YARN_LOCK), ("package-lock.json", FIX_REMOVE_PACKAGE_LOCK)]
+            .into_iter()
+            .filter(|(name, _)| parent_dir.join(name).exists())
+            .map(|(name, fix)| {
+                LintResult::new(
+                    self.id(),
+                    CHECK_PACKAGE_MANAGER_LOCKFILE,
+                    Severity::Error,
+                    format!("packageManager declares '{}' but {} is present", declared, name),
+                    parent_dir.join(name),
+                    None,
+                    Some(Self::lockfile_suggestion(name)),
+                    vec![fix],
+                )
+            })
+            .collect()
+    }
+
+    /// Remove non-pnpm lock files. With `allowCommands`, the removal is also
+    /// staged with `git rm --cached` so the lockfile leaves the index.
+    fn remove_lock_files(&self, parent_dir: &Path, context: &RuleContext) -> std::io::Result<u32> {
         let mut removed = 0;
 
-        let yarn_lock = parent_dir.join("yarn.lock");
-        if yarn_lock.exists() {
-            std::fs::remove_file(&yarn_lock)?;
+        for name in ["yarn.lock", "package-lock.json"] {
+            let lockfile = parent_dir.join(name);
+            if !context.file_exists(&lockfile) {
+                continue;
+            }
+            context.remove_file(&lockfile)?;
             removed += 1;
+
+            if self.allow_commands(context) {
+                // Best effort: git may be missing or commands disallowed, and
+                // the file is gone either way
+                let _ = context.run_command(
+                    "git",
+                    &["rm", "--cached", "--quiet", "--ignore-unmatch", name],
+                    parent_dir,
+                );
+            }
         }
 
-        let package_lock = parent_dir.join("package-lock.json");
-        if package_lock.exists() {
-            std::fs::remove_file(&package_lock)?;
-            removed += 1;
+        for name in YARNRC_FILES {
+            let yarnrc = parent_dir.join(name);
+            if context.file_exists(&yarnrc) {
+                context.remove_file(&yarnrc)?;
+                removed += 1;
+            }
         }
 
         Ok(removed)
     }
 
+    /// Add packageManager when pnpm-lock.yaml exists but the field is missing
+    fn add_package_manager_field(
+        &self,
+        package_json_path: &Path,
+        context: &RuleContext,
+    ) -> Result<bool, RuleError> {
+        let pnpm_lock = package_json_path
+            .parent()
+            .unwrap_or(Path::new("."))
+            .join("pnpm-lock.yaml");
+        if !pnpm_lock.exists() {
+            return Ok(false);
+        }
+
+        let content = context.read_file(package_json_path)?;
+        let mut json: Value = serde_json::from_str(&content)?;
+        if json.get("packageManager").is_some() {
+            return Ok(false);
+        }
+        let Some(version) = Self::pinned_pnpm_version(context, &pnpm_lock) else {
+            return Ok(false);
+        };
+
+        json["packageManager"] = Value::String(format!("pnpm@{}", version));
+        let updated_content = serde_json::to_string_pretty(&json)?;
+        context.write_file(package_json_path, &updated_content)?;
+
+        Ok(true)
+    }
+
+    /// Rewrite `npx <bin>` to `pnpm exec <bin>` in package.json scripts
+    fn fix_npx_scripts(
+        &self,
+        package_json_path: &Path,
+        context: &RuleContext,
+    ) -> Result<bool, RuleError> {
+        let content = context.read_file(package_json_path)?;
+        let mut json: Value = serde_json::from_str(&content)?;
+
+        let mut changed = false;
+
+        if let Some(scripts) = json.get_mut("scripts").and_then(|s| s.as_object_mut()) {
+            for script_value in scripts.values_mut() {
+                if let Some(rewritten) = script_value.as_str().and_then(Self::rewrite_npx) {
+                    *script_value = Value::String(rewritten);
+                    changed = true;
+                }
+            }
+        }
+
+        if changed {
+            let updated_content = serde_json::to_string_pretty(&json)?;
+            context.write_file(package_json_path, &updated_content)?;
+        }
+
+        Ok(changed)
+    }
+
     /// Update packageManager field in package.json if it's set to npm or yarn
     fn fix_package_manager_field(
         &self,
@@ -342,12 +767,20 @@ impl Rule for PnpmUsageRule {
         Severity::Error
     }
 
+    fn tags(&self) -> Vec<&'static str> {
+        vec!["package-manager"]
+    }
+
     fn checks(&self) -> Vec<CheckEntry> {
         vec![
             CheckEntry::new(
                 CHECK_YARN_LOCK_EXISTS,
                 "Detect yarn.lock files indicating yarn usage",
             ),
+            CheckEntry::new(
+                CHECK_YARNRC_EXISTS,
+                "Detect Yarn config (.yarnrc.yml, .yarnrc) and vendored .yarn/releases",
+            ),
             CheckEntry::new(
                 CHECK_PACKAGE_LOCK_EXISTS,
                 "Detect package-lock.json files indicating npm usage",
@@ -356,6 +789,10 @@ impl Rule for PnpmUsageRule {
                 CHECK_PACKAGE_MANAGER_FIELD,
                 "Verify packageManager field uses pnpm",
             ),
+            CheckEntry::new(
+                CHECK_PACKAGE_MANAGER_MISSING,
+                "Verify packageManager is set when pnpm-lock.yaml exists",
+            ),
             CheckEntry::new(
                 CHECK_PNPM_SETUP,
                 "Verify pnpm is set up (packageManager or pnpm-lock.yaml)",
@@ -368,6 +805,10 @@ impl Rule for PnpmUsageRule {
                 CHECK_SCRIPTS_YARN,
                 "Detect scripts that use yarn commands",
             ),
+            CheckEntry::new(
+                CHECK_SCRIPTS_NPX,
+                "Detect npx in package.json scripts (prefer pnpm exec)",
+            ),
             CheckEntry::new(
                 CHECK_ENGINES_NPM,
                 "Detect engines.npm field in package.json",
@@ -376,6 +817,22 @@ impl Rule for PnpmUsageRule {
                 CHECK_ENGINES_YARN,
                 "Detect engines.yarn field in package.json",
             ),
+            CheckEntry::new(
+                CHECK_LOCKFILE_VERSION,
+                "Verify pnpm-lock.yaml format matches the pnpm major pinned in packageManager",
+            ),
+            CheckEntry::new(
+                CHECK_WORKSPACE_PROTOCOL,
+                "Verify workspace: dependencies are only used inside a pnpm workspace",
+            ),
+            CheckEntry::new(
+                CHECK_PACKAGE_MANAGER_LOCKFILE,
+                "Verify no yarn.lock or package-lock.json sits next to a packageManager declaring pnpm",
+            ),
+            CheckEntry::new(
+                CHECK_HOOKS_PNPM,
+                "Detect Husky hooks that run npm or yarn in a pnpm project",
+            ),
         ]
     }
 
@@ -383,19 +840,39 @@ impl Rule for PnpmUsageRule {
         vec![
             FixEntry::new(
                 FIX_REMOVE_YARN_LOCK,
-                "Remove yarn.lock file",
-                vec![CHECK_YARN_LOCK_EXISTS],
+                "Remove yarn.lock file, staging the removal with git when option allowCommands is set",
+                vec![CHECK_YARN_LOCK_EXISTS, CHECK_PACKAGE_MANAGER_LOCKFILE],
+            ),
+            FixEntry::new(
+                FIX_REMOVE_YARNRC,
+                "Remove .yarnrc.yml and .yarnrc (the .yarn/ directory is left alone)",
+                vec![CHECK_YARNRC_EXISTS],
             ),
             FixEntry::new(
                 FIX_REMOVE_PACKAGE_LOCK,
-                "Remove package-lock.json file",
-                vec![CHECK_PACKAGE_LOCK_EXISTS],
+                "Remove package-lock.json file, staging the removal with git when option allowCommands is set",
+                vec![CHECK_PACKAGE_LOCK_EXISTS, CHECK_PACKAGE_MANAGER_LOCKFILE],
             ),
             FixEntry::new(
                 FIX_UPDATE_PACKAGE_MANAGER,
                 "Update packageManager field to use pnpm",
                 vec![CHECK_PACKAGE_MANAGER_FIELD],
             ),
+            FixEntry::new(
+                FIX_ADD_PACKAGE_MANAGER,
+                "Add packageManager pinned to the configured or lockfile-derived pnpm version",
+                vec![CHECK_PACKAGE_MANAGER_MISSING],
+            ),
+            FixEntry::new(
+                FIX_REPLACE_NPX,
+                "Rewrite 'npx <bin>' to 'pnpm exec <bin>' in scripts",
+                vec![CHECK_SCRIPTS_NPX],
+            ),
+            FixEntry::new(
+                FIX_REWRITE_HOOKS,
+                "Rewrite npm and yarn invocations in .husky hooks to pnpm",
+                vec![CHECK_HOOKS_PNPM],
+            ),
         ]
     }
 
@@ -403,10 +880,10 @@ impl Rule for PnpmUsageRule {
         let mut results = Vec::new();
 
         // Find all package.json files
-        let package_jsons = self.find_package_jsons(&context.root);
+        let package_jsons = context.package_jsons();
 
         for package_json in package_jsons {
-            results.extend(self.check_package_json(&package_json));
+            results.extend(self.check_package_json(&package_json, context));
         }
 
         results
@@ -416,18 +893,28 @@ impl Rule for PnpmUsageRule {
         let mut fixed = 0;
 
         // Find all package.json files
-        let package_jsons = self.find_package_jsons(&context.root);
+        let package_jsons = context.package_jsons();
 
         for package_json in package_jsons {
             let parent_dir = package_json.parent().unwrap_or(Path::new("."));
 
             // Remove non-pnpm lock files
-            fixed += self.remove_lock_files(parent_dir)?;
+            fixed += self.remove_lock_files(parent_dir, context)?;
 
             // Fix packageManager field if needed
             if self.fix_package_manager_field(&package_json, context)? {
                 fixed += 1;
             }
+
+            if self.add_package_manager_field(&package_json, context)? {
+                fixed += 1;
+            }
+
+            if self.fix_npx_scripts(&package_json, context)? {
+                fixed += 1;
+            }
+
+            fixed += self.fix_hooks(&package_json, context)?;
         }
 
         Ok(fixed)
@@ -437,11 +924,153 @@ impl Rule for PnpmUsageRule {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::command::{NoCommandRunner, RecordingCommandRunner};
     use std::fs;
+    use std::path::PathBuf;
+    use std::sync::Arc;
     use tempfile::TempDir;
 
     fn create_context(root: PathBuf) -> RuleContext {
-        RuleContext::new(root, true, serde_json::json!({}))
+        RuleContext::new(root, serde_json::json!({}))
+    }
+
+    #[test]
+    fn test_lockfile_suggestion_mentions_git_rm_cached() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::write(root.join("package.json"), r#"{"name": "test"}"#).unwrap();
+        fs::write(root.join("package-lock.json"), "{}").unwrap();
+        let runner = Arc::new(RecordingCommandRunner::new());
+        let rule = PnpmUsageRule::new();
+        let context = create_context(root.clone()).with_command_runner(runner.clone());
+
+        let results: Vec<_> = rule
+            .check(&context)
+            .into_iter()
+            .filter(|r| r.check_id == CHECK_PACKAGE_LOCK_EXISTS)
+            .collect();
+        assert_eq!(
+            results[0].suggestion.as_deref(),
+            Some(
+                "Remove package-lock.json, run 'git rm --cached package-lock.json' if it is \
+                 tracked, and use 'pnpm install' to generate pnpm-lock.yaml"
+            )
+        );
+
+        // By default the file is only deleted
+        rule.fix(&context).unwrap();
+        assert!(!root.join("package-lock.json").exists());
+        assert!(runner.commands().is_empty());
+    }
+
+    #[test]
+    fn test_fix_stages_lockfile_removal_with_allow_commands() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::write(root.join("package.json"), r#"{"name": "test"}"#).unwrap();
+        fs::write(root.join("yarn.lock"), "# yarn lockfile v1").unwrap();
+        let runner = Arc::new(RecordingCommandRunner::new());
+        let options = serde_json::json!({"allowCommands": true});
+        let context =
+            RuleContext::new(root.clone(), options).with_command_runner(runner.clone());
+
+        PnpmUsageRule::new().fix(&context).unwrap();
+
+        assert!(!root.join("yarn.lock").exists());
+        assert_eq!(
+            runner.commands(),
+            vec!["git rm --cached --quiet --ignore-unmatch yarn.lock"]
+        );
+    }
+
+    #[test]
+    fn test_fix_removes_lockfiles_when_git_cannot_run() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::write(root.join("package.json"), r#"{"name": "test"}"#).unwrap();
+        fs::write(root.join("yarn.lock"), "# yarn lockfile v1").unwrap();
+        fs::write(root.join("package-lock.json"), "{}").unwrap();
+        let options = serde_json::json!({"allowCommands": true});
+        let context = RuleContext::new(root.clone(), options)
+            .with_command_runner(Arc::new(NoCommandRunner("in tests")));
+
+        assert_eq!(PnpmUsageRule::new().fix(&context).unwrap(), 2);
+
+        assert!(!root.join("yarn.lock").exists());
+        assert!(!root.join("package-lock.json").exists());
+    }
+
+    #[test]
+    fn test_flags_yarn_lock_contradicting_declared_pnpm() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::write(
+            root.join("package.json"),
+            r#"{"name": "test", "packageManager": "pnpm@9.1.0"}"#,
+        )
+        .unwrap();
+        fs::write(root.join("pnpm-lock.yaml"), "lockfileVersion: '9.0'\n").unwrap();
+        fs::write(root.join("yarn.lock"), "# yarn lockfile v1").unwrap();
+        let rule = PnpmUsageRule::new();
+        let context = create_context(root.clone());
+
+        let mismatches: Vec<_> = rule
+            .check(&context)
+            .into_iter()
+            .filter(|r| r.check_id == CHECK_PACKAGE_MANAGER_LOCKFILE)
+            .collect();
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].severity, "error");
+        assert_eq!(
+            mismatches[0].message,
+            "packageManager declares 'pnpm@9.1.0' but yarn.lock is present"
+        );
+        assert_eq!(mismatches[0].fixable_by, vec![FIX_REMOVE_YARN_LOCK]);
+
+        rule.fix(&context).unwrap();
+        assert!(!rule
+            .check(&context)
+            .iter()
+            .any(|r| r.check_id == CHECK_PACKAGE_MANAGER_LOCKFILE));
+    }
+
+    #[test]
+    fn test_detects_yarn_berry_config() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::write(root.join("package.json"), r#"{"name": "test", "version": "1.0.0"}"#).unwrap();
+        fs::write(root.join(".yarnrc.yml"), "nodeLinker: node-modules\n").unwrap();
+        fs::create_dir_all(root.join(".yarn/releases")).unwrap();
+        fs::write(root.join(".yarn/releases/yarn-4.1.0.cjs"), "").unwrap();
+
+        let rule = PnpmUsageRule::new();
+        let context = create_context(root.clone());
+        let yarn_results: Vec<_> = rule
+            .check(&context)
+            .into_iter()
+            .filter(|r| r.check_id == CHECK_YARNRC_EXISTS)
+            .collect();
+
+        assert_eq!(yarn_results.len(), 2);
+        assert!(yarn_results[0].path.ends_with(".yarnrc.yml"));
+        assert_eq!(yarn_results[0].fixable_by, vec![FIX_REMOVE_YARNRC]);
+        assert!(yarn_results[1].path.ends_with("releases"));
+        assert!(yarn_results[1].fixable_by.is_empty());
+    }
+
+    #[test]
+    fn test_fix_removes_yarnrc_but_keeps_yarn_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::write(root.join("package.json"), r#"{"name": "test", "version": "1.0.0"}"#).unwrap();
+        fs::write(root.join(".yarnrc.yml"), "nodeLinker: node-modules\n").unwrap();
+        fs::create_dir_all(root.join(".yarn/releases")).unwrap();
+
+        let rule = PnpmUsageRule::new();
+        rule.fix(&create_context(root.clone())).unwrap();
+
+        assert!(!root.join(".yarnrc.yml").exists());
+        assert!(root.join(".yarn/releases").is_dir());
     }
 
     #[test]
@@ -560,6 +1189,72 @@ mod tests {
         assert!(results.iter().any(|r| r.message.contains("uses npm command")));
     }
 
+    #[test]
+    fn test_hook_running_npm_in_pnpm_repo_is_rewritten() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::write(root.join("package.json"), r#"{"name": "test", "packageManager": "pnpm@9.0.0"}"#)
+            .unwrap();
+        fs::create_dir_all(root.join(".husky/_")).unwrap();
+        fs::write(root.join(".husky/_/husky.sh"), "npm run x\n").unwrap();
+        fs::write(
+            root.join(".husky/pre-commit"),
+            "# npm run lint used to be slow\necho npm && npm run lint\npnpm test\n",
+        )
+        .unwrap();
+        fs::write(root.join(".husky/pre-push"), "npm ci && yarn test\n").unwrap();
+        let rule = PnpmUsageRule::new();
+        let context = create_context(root.clone());
+
+        let results: Vec<_> =
+            rule.check(&context).into_iter().filter(|r| r.check_id == CHECK_HOOKS_PNPM).collect();
+        assert_eq!(results.len(), 2);
+        assert_eq!(
+            results[0].message,
+            "Hook '.husky/pre-commit' runs npm but the project uses pnpm"
+        );
+        assert_eq!(results[0].line, Some(2));
+        assert_eq!(results[0].fixable_by, vec![FIX_REWRITE_HOOKS]);
+
+        rule.fix(&context).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(root.join(".husky/pre-commit")).unwrap(),
+            "# npm run lint used to be slow\necho npm && pnpm run lint\npnpm test\n"
+        );
+        assert_eq!(
+            fs::read_to_string(root.join(".husky/pre-push")).unwrap(),
+            "pnpm install --frozen-lockfile && pnpm test\n"
+        );
+        assert_eq!(fs::read_to_string(root.join(".husky/_/husky.sh")).unwrap(), "npm run x\n");
+        assert!(!rule.check(&context).iter().any(|r| r.check_id == CHECK_HOOKS_PNPM));
+    }
+
+    #[test]
+    fn test_bare_yarn_in_hook_becomes_pnpm_install() {
+        assert_eq!(
+            PnpmUsageRule::rewrite_hook("yarn\nyarn && yarn lint\n").as_deref(),
+            Some("pnpm install\npnpm install && pnpm lint\n")
+        );
+        assert_eq!(
+            PnpmUsageRule::rewrite_hook("yarn --frozen-lockfile\n").as_deref(),
+            Some("pnpm --frozen-lockfile\n")
+        );
+    }
+
+    #[test]
+    fn test_hooks_outside_pnpm_repos_are_not_checked() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::write(root.join("package.json"), r#"{"name": "test"}"#).unwrap();
+        fs::create_dir_all(root.join(".husky")).unwrap();
+        fs::write(root.join(".husky/pre-commit"), "npm run lint\n").unwrap();
+
+        let results = PnpmUsageRule::new().check(&create_context(root));
+
+        assert!(!results.iter().any(|r| r.check_id == CHECK_HOOKS_PNPM));
+    }
+
     #[test]
     fn test_detects_engines_npm() {
         let temp_dir = TempDir::new().unwrap();
@@ -757,4 +1452,213 @@ mod tests {
         assert!(PnpmUsageRule::contains_standalone_command("yarn install", "yarn"));
         assert!(!PnpmUsageRule::contains_standalone_command("pyarn build", "yarn"));
     }
+
+    fn setup_lockfile(package_manager: &str, lockfile_version: &str) -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("package.json"),
+            format!(r#"{{"name": "test", "packageManager": "{}"}}"#, package_manager),
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("pnpm-lock.yaml"),
+            format!("lockfileVersion: {}
+
+settings:
+  autoInstallPeers: true
+", lockfile_version),
+        )
+        .unwrap();
+        temp_dir
+    }
+
+    #[test]
+    fn test_lockfile_version_matching_pinned_pnpm() {
+        let rule = PnpmUsageRule::new();
+
+        for (package_manager, lockfile_version) in [
+            ("pnpm@9.1.0", "'9.0'"),
+            ("pnpm@8.15.4", "'6.0'"),
+            ("pnpm@10.2.0", "'9.0'"),
+        ] {
+            let temp_dir = setup_lockfile(package_manager, lockfile_version);
+            let results = rule.check(&create_context(temp_dir.path().to_path_buf()));
+            assert!(
+                !results.iter().any(|r| r.check_id == CHECK_LOCKFILE_VERSION),
+                "{} with {}",
+                package_manager,
+                lockfile_version
+            );
+        }
+    }
+
+    #[test]
+    fn test_lockfile_version_mismatch_warns() {
+        let rule = PnpmUsageRule::new();
+
+        for (package_manager, lockfile_version) in [("pnpm@9.1.0", "'6.0'"), ("pnpm@8.15.4", "\"9.0\"")] {
+            let temp_dir = setup_lockfile(package_manager, lockfile_version);
+            let results: Vec<_> = rule
+                .check(&create_context(temp_dir.path().to_path_buf()))
+                .into_iter()
+                .filter(|r| r.check_id == CHECK_LOCKFILE_VERSION)
+                .collect();
+
+            assert_eq!(results.len(), 1, "{} with {}", package_manager, lockfile_version);
+            assert_eq!(results[0].severity, "warning");
+            assert!(results[0].fixable_by.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_lockfile_version_unknown_pnpm_major_is_skipped() {
+        let temp_dir = setup_lockfile("pnpm@7.33.0", "5.4");
+        let rule = PnpmUsageRule::new();
+
+        assert!(rule
+            .check(&create_context(temp_dir.path().to_path_buf()))
+            .iter()
+            .all(|r| r.check_id != CHECK_LOCKFILE_VERSION));
+    }
+
+    #[test]
+    fn test_workspace_protocol_inside_workspace_is_valid() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::write(root.join("pnpm-workspace.yaml"), "packages:\n  - 'packages/*'\n").unwrap();
+        let package_dir = root.join("packages/app");
+        fs::create_dir_all(&package_dir).unwrap();
+        fs::write(
+            package_dir.join("package.json"),
+            r#"{"name": "app", "dependencies": {"core": "workspace:*"}}"#,
+        )
+        .unwrap();
+
+        let rule = PnpmUsageRule::new();
+        assert!(rule
+            .check(&create_context(package_dir))
+            .iter()
+            .all(|r| r.check_id != CHECK_WORKSPACE_PROTOCOL));
+    }
+
+    #[test]
+    fn test_workspace_protocol_in_standalone_package() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::write(
+            root.join("package.json"),
+            r#"{"name": "app", "dependencies": {"core": "workspace:^", "lodash": "^4.17.21"}}"#,
+        )
+        .unwrap();
+
+        let rule = PnpmUsageRule::new();
+        let results: Vec<_> = rule
+            .check(&create_context(root))
+            .into_iter()
+            .filter(|r| r.check_id == CHECK_WORKSPACE_PROTOCOL)
+            .collect();
+
+        assert_eq!(results.len(), 1);
+        assert!(results[0].message.ends_with(": core@workspace:^"));
+        assert!(results[0].fixable_by.is_empty());
+    }
+
+    #[test]
+    fn test_fix_rewrites_npx_to_pnpm_exec() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::write(
+            root.join("package.json"),
+            r#"{"name": "test", "packageManager": "pnpm@9.1.0", "scripts": {"build": "npx tsc -p .", "check": "rimraf dist && npx eslint ."}}"#,
+        )
+        .unwrap();
+
+        let rule = PnpmUsageRule::new();
+        let context = create_context(root.clone());
+        let results: Vec<_> = rule
+            .check(&context)
+            .into_iter()
+            .filter(|r| r.check_id == CHECK_SCRIPTS_NPX)
+            .collect();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].severity, "warning");
+        assert_eq!(results[0].fixable_by, vec![FIX_REPLACE_NPX]);
+
+        rule.fix(&context).unwrap();
+        let json: Value =
+            serde_json::from_str(&fs::read_to_string(root.join("package.json")).unwrap()).unwrap();
+        assert_eq!(json["scripts"]["build"], "pnpm exec tsc -p .");
+        assert_eq!(json["scripts"]["check"], "rimraf dist && pnpm exec eslint .");
+        assert!(rule.check(&context).iter().all(|r| r.check_id != CHECK_SCRIPTS_NPX));
+    }
+
+    #[test]
+    fn test_npx_inside_other_words_is_ignored() {
+        for script in ["pnpx tsc", "npx-run build", "node ./bin/npx.js", "echo unpx"] {
+            assert!(command_positions(script, "npx").is_empty(), "{}", script);
+        }
+        assert_eq!(PnpmUsageRule::rewrite_npx("npx -y create-vite app"), None);
+        assert_eq!(
+            PnpmUsageRule::rewrite_npx("npx prettier --check . ; npx tsc").as_deref(),
+            Some("pnpm exec prettier --check . ; pnpm exec tsc")
+        );
+    }
+
+    fn setup_lock_without_field(lockfile_version: &str) -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("package.json"), r#"{"name": "test"}"#).unwrap();
+        fs::write(
+            temp_dir.path().join("pnpm-lock.yaml"),
+            format!("lockfileVersion: '{}'\n", lockfile_version),
+        )
+        .unwrap();
+        temp_dir
+    }
+
+    #[test]
+    fn test_lock_without_package_manager_is_fixed_from_lockfile() {
+        let temp_dir = setup_lock_without_field("9.0");
+        let root = temp_dir.path().to_path_buf();
+        let rule = PnpmUsageRule::new();
+        let context = create_context(root.clone());
+
+        let results: Vec<_> = rule
+            .check(&context)
+            .into_iter()
+            .filter(|r| r.check_id == CHECK_PACKAGE_MANAGER_MISSING)
+            .collect();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].fixable_by, vec![FIX_ADD_PACKAGE_MANAGER]);
+
+        assert_eq!(rule.fix(&context).unwrap(), 1);
+        let json: Value =
+            serde_json::from_str(&fs::read_to_string(root.join("package.json")).unwrap()).unwrap();
+        assert_eq!(json["packageManager"], "pnpm@9.0.0");
+        assert!(rule
+            .check(&context)
+            .iter()
+            .all(|r| r.check_id != CHECK_PACKAGE_MANAGER_MISSING));
+    }
+
+    #[test]
+    fn test_lock_without_package_manager_uses_configured_version() {
+        let temp_dir = setup_lock_without_field("5.4");
+        let root = temp_dir.path().to_path_buf();
+        let rule = PnpmUsageRule::new();
+
+        // An unknown lockfile format can't be mapped to a pnpm version
+        let results = rule.check(&create_context(root.clone()));
+        let missing = results
+            .iter()
+            .find(|r| r.check_id == CHECK_PACKAGE_MANAGER_MISSING)
+            .unwrap();
+        assert!(missing.fixable_by.is_empty());
+
+        let context =
+            RuleContext::new(root.clone(), serde_json::json!({"pnpmVersion": "9.12.3"}));
+        assert_eq!(rule.fix(&context).unwrap(), 1);
+        let json: Value =
+            serde_json::from_str(&fs::read_to_string(root.join("package.json")).unwrap()).unwrap();
+        assert_eq!(json["packageManager"], "pnpm@9.12.3");
+    }
 }
@@ -0,0 +1,189 @@
+use crate::rules::{Rule, RuleError};
+use crate::types::{CheckEntry, FixEntry, LintResult, RuleContext, Severity};
+use std::path::Path;
+
+// Check IDs
+const CHECK_PR_TEMPLATE_EXISTS: &str = "pr-template-exists";
+
+// Fix IDs
+const FIX_CREATE_PR_TEMPLATE: &str = "create-pr-template";
+
+/// Locations GitHub recognizes for a single pull request template
+const PR_TEMPLATE_LOCATIONS: &[&str] = &[
+    ".github/pull_request_template.md",
+    ".github/PULL_REQUEST_TEMPLATE.md",
+];
+
+/// Stub written when no `template` option is configured
+const DEFAULT_PR_TEMPLATE: &str = r#"## Summary
+
+<!-- What does this change and why? -->
+
+## Test Plan
+
+<!-- How was this verified? -->
+"#;
+
+/// Rule: Ensure repositories ship a pull request template
+pub struct PrTemplateRule;
+
+impl PrTemplateRule {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Read the PR template from options, falling back to the default stub
+    fn template(&self, context: &RuleContext) -> String {
+        context
+            .config
+            .get("template")
+            .and_then(|v| v.as_str())
+            .unwrap_or(DEFAULT_PR_TEMPLATE)
+            .to_string()
+    }
+
+    fn has_pr_template(&self, repo_root: &Path) -> bool {
+        PR_TEMPLATE_LOCATIONS
+            .iter()
+            .any(|location| repo_root.join(location).is_file())
+    }
+}
+
+impl Default for PrTemplateRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Rule for PrTemplateRule {
+    fn id(&self) -> &'static str {
+        "pr-template"
+    }
+
+    fn name(&self) -> &'static str {
+        "Pull Request Template"
+    }
+
+    fn description(&self) -> &'static str {
+        "Ensures git repositories include a .github/pull_request_template.md"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Info
+    }
+
+    fn enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn tags(&self) -> Vec<&'static str> {
+        vec!["governance"]
+    }
+
+    fn checks(&self) -> Vec<CheckEntry> {
+        vec![CheckEntry::new(
+            CHECK_PR_TEMPLATE_EXISTS,
+            "Verify each git repository has a pull request template under .github/",
+        )]
+    }
+
+    fn fixes(&self) -> Vec<FixEntry> {
+        vec![FixEntry::new(
+            FIX_CREATE_PR_TEMPLATE,
+            "Create .github/pull_request_template.md from the configured template",
+            vec![CHECK_PR_TEMPLATE_EXISTS],
+        )]
+    }
+
+    fn check(&self, context: &RuleContext) -> Vec<LintResult> {
+        context
+            .git_repos()
+            .into_iter()
+            .filter(|repo| !self.has_pr_template(repo))
+            .map(|repo| {
+                LintResult::new(
+                    self.id(),
+                    CHECK_PR_TEMPLATE_EXISTS,
+                    self.default_severity(),
+                    "Missing .github/pull_request_template.md".into(),
+                    repo,
+                    None,
+                    Some("Add a pull request template listing what every PR should describe".into()),
+                    vec![FIX_CREATE_PR_TEMPLATE],
+                )
+            })
+            .collect()
+    }
+
+    fn fix(&self, context: &RuleContext) -> Result<u32, RuleError> {
+        let mut fixed = 0;
+        let template = self.template(context);
+
+        for repo in context.git_repos() {
+            if !self.has_pr_template(&repo) {
+                context.write_file(&repo.join(PR_TEMPLATE_LOCATIONS[0]), &template)?;
+                fixed += 1;
+            }
+        }
+
+        Ok(fixed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn setup_repo() -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir_all(temp_dir.path().join(".git")).unwrap();
+        temp_dir
+    }
+
+    fn create_context(temp_dir: &TempDir, options: Value) -> RuleContext {
+        RuleContext::new(temp_dir.path().to_path_buf(), options)
+    }
+
+    #[test]
+    fn test_detects_missing_pr_template() {
+        let temp_dir = setup_repo();
+        let rule = PrTemplateRule::new();
+
+        let results = rule.check(&create_context(&temp_dir, Value::Null));
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].check_id, CHECK_PR_TEMPLATE_EXISTS);
+        assert_eq!(results[0].severity, "info");
+        assert!(!rule.enabled_by_default());
+    }
+
+    #[test]
+    fn test_accepts_either_template_spelling() {
+        let rule = PrTemplateRule::new();
+        for location in PR_TEMPLATE_LOCATIONS {
+            let temp_dir = setup_repo();
+            fs::create_dir_all(temp_dir.path().join(".github")).unwrap();
+            fs::write(temp_dir.path().join(location), "## Summary\n").unwrap();
+
+            assert!(rule.check(&create_context(&temp_dir, Value::Null)).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_fix_scaffolds_from_template() {
+        let temp_dir = setup_repo();
+        let rule = PrTemplateRule::new();
+        let context = create_context(&temp_dir, serde_json::json!({"template": "## Why\n"}));
+
+        let fixed = rule.fix(&context).unwrap();
+
+        assert_eq!(fixed, 1);
+        let content =
+            fs::read_to_string(temp_dir.path().join(".github/pull_request_template.md")).unwrap();
+        assert_eq!(content, "## Why\n");
+        assert!(rule.check(&context).is_empty());
+    }
+}
@@ -0,0 +1,244 @@
+use crate::rules::{Rule, RuleError};
+use crate::types::{CheckEntry, FixEntry, LintResult, RuleContext, Severity};
+use std::path::{Path, PathBuf};
+
+// Check IDs
+const CHECK_CONTRIBUTING_EXISTS: &str = "contributing-exists";
+
+// Fix IDs
+const FIX_CREATE_CONTRIBUTING: &str = "create-contributing";
+
+/// Locations GitHub recognizes for contributing guidelines
+const CONTRIBUTING_LOCATIONS: &[&str] = &[
+    "CONTRIBUTING.md",
+    ".github/CONTRIBUTING.md",
+    "docs/CONTRIBUTING.md",
+];
+
+/// Rule: Ensure public repositories document how to contribute
+pub struct RepoGovernanceRule;
+
+impl RepoGovernanceRule {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Whether every git repository should be checked, not only public packages
+    fn check_all_repos(&self, context: &RuleContext) -> bool {
+        context
+            .config
+            .get("allRepos")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false)
+    }
+
+    /// The `contributingTemplate` option. Without it the rule only reports.
+    fn contributing_template<'a>(&self, context: &'a RuleContext) -> Option<&'a str> {
+        context.config.get("contributingTemplate").and_then(|v| v.as_str())
+    }
+
+    /// A repository is public when its package.json exists and isn't marked private
+    fn is_public_package(&self, repo_root: &Path, context: &RuleContext) -> bool {
+        context
+            .package_json_value(&repo_root.join("package.json"))
+            .is_some_and(|json| json.get("private").and_then(|p| p.as_bool()) != Some(true))
+    }
+
+    /// Repositories that should carry contributing guidelines
+    fn governed_repos(&self, context: &RuleContext) -> Vec<PathBuf> {
+        let all_repos = self.check_all_repos(context);
+        context
+            .git_repos()
+            .into_iter()
+            .filter(|repo| all_repos || self.is_public_package(repo, context))
+            .collect()
+    }
+
+    fn has_contributing(&self, repo_root: &Path, context: &RuleContext) -> bool {
+        CONTRIBUTING_LOCATIONS
+            .iter()
+            .any(|location| context.file_exists(&repo_root.join(location)))
+    }
+}
+
+impl Default for RepoGovernanceRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Rule for RepoGovernanceRule {
+    fn id(&self) -> &'static str {
+        "repo-governance"
+    }
+
+    fn name(&self) -> &'static str {
+        "Repository Governance"
+    }
+
+    fn description(&self) -> &'static str {
+        "Ensures public repositories include governance documents such as CONTRIBUTING.md"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Info
+    }
+
+    fn tags(&self) -> Vec<&'static str> {
+        vec!["governance"]
+    }
+
+    fn checks(&self) -> Vec<CheckEntry> {
+        vec![CheckEntry::new(
+            CHECK_CONTRIBUTING_EXISTS,
+            "Verify public repositories have a CONTRIBUTING.md",
+        )]
+    }
+
+    fn fixes(&self) -> Vec<FixEntry> {
+        vec![FixEntry::new(
+            FIX_CREATE_CONTRIBUTING,
+            "Create CONTRIBUTING.md from the contributingTemplate option",
+            vec![CHECK_CONTRIBUTING_EXISTS],
+        )]
+    }
+
+    fn check(&self, context: &RuleContext) -> Vec<LintResult> {
+        let fixable_by = match self.contributing_template(context) {
+            Some(_) => vec![FIX_CREATE_CONTRIBUTING],
+            None => vec![],
+        };
+        self.governed_repos(context)
+            .into_iter()
+            .filter(|repo| !self.has_contributing(repo, context))
+            .map(|repo| {
+                LintResult::new(
+                    self.id(),
+                    CHECK_CONTRIBUTING_EXISTS,
+                    self.default_severity(),
+                    "Missing CONTRIBUTING.md in public repository".into(),
+                    repo,
+                    None,
+                    Some("Add a CONTRIBUTING.md describing how to propose changes".into()),
+                    fixable_by.clone(),
+                )
+            })
+            .collect()
+    }
+
+    fn fix(&self, context: &RuleContext) -> Result<u32, RuleError> {
+        let Some(template) = self.contributing_template(context) else {
+            return Ok(0);
+        };
+
+        let mut fixed = 0;
+        for repo in self.governed_repos(context) {
+            if !self.has_contributing(&repo, context) {
+                context.write_file(&repo.join("CONTRIBUTING.md"), template)?;
+                fixed += 1;
+            }
+        }
+
+        Ok(fixed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn setup_repo(package_json: Option<&str>) -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir_all(temp_dir.path().join(".git")).unwrap();
+        if let Some(content) = package_json {
+            fs::write(temp_dir.path().join("package.json"), content).unwrap();
+        }
+        temp_dir
+    }
+
+    fn create_context(root: PathBuf, options: Value) -> RuleContext {
+        RuleContext::new(root, options)
+    }
+
+    #[test]
+    fn test_detects_missing_contributing() {
+        let temp_dir = setup_repo(Some(r#"{"name": "public-pkg"}"#));
+        let rule = RepoGovernanceRule::new();
+        let context = create_context(temp_dir.path().to_path_buf(), Value::Null);
+
+        let results = rule.check(&context);
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].check_id, CHECK_CONTRIBUTING_EXISTS);
+        assert_eq!(results[0].severity, "info");
+    }
+
+    #[test]
+    fn test_accepts_existing_contributing() {
+        let temp_dir = setup_repo(Some(r#"{"name": "public-pkg"}"#));
+        fs::create_dir_all(temp_dir.path().join(".github")).unwrap();
+        fs::write(temp_dir.path().join(".github/CONTRIBUTING.md"), "# Contributing").unwrap();
+        let rule = RepoGovernanceRule::new();
+        let context = create_context(temp_dir.path().to_path_buf(), Value::Null);
+
+        assert!(rule.check(&context).is_empty());
+    }
+
+    #[test]
+    fn test_skips_private_packages() {
+        let temp_dir = setup_repo(Some(r#"{"name": "internal", "private": true}"#));
+        let rule = RepoGovernanceRule::new();
+        let context = create_context(temp_dir.path().to_path_buf(), Value::Null);
+
+        assert!(rule.check(&context).is_empty());
+    }
+
+    #[test]
+    fn test_all_repos_option_checks_repos_without_package_json() {
+        let temp_dir = setup_repo(None);
+        let rule = RepoGovernanceRule::new();
+
+        let default_context = create_context(temp_dir.path().to_path_buf(), Value::Null);
+        assert!(rule.check(&default_context).is_empty());
+
+        let context = create_context(
+            temp_dir.path().to_path_buf(),
+            serde_json::json!({"allRepos": true}),
+        );
+        assert_eq!(rule.check(&context).len(), 1);
+    }
+
+    #[test]
+    fn test_without_template_only_reports() {
+        let temp_dir = setup_repo(Some(r#"{"name": "public-pkg"}"#));
+        let rule = RepoGovernanceRule::new();
+        let context = create_context(temp_dir.path().to_path_buf(), Value::Null);
+
+        let results = rule.check(&context);
+
+        assert_eq!(results.len(), 1);
+        assert!(results[0].fixable_by.is_empty());
+        assert_eq!(rule.fix(&context).unwrap(), 0);
+        assert!(!temp_dir.path().join("CONTRIBUTING.md").exists());
+    }
+
+    #[test]
+    fn test_fix_scaffolds_from_template() {
+        let temp_dir = setup_repo(Some(r#"{"name": "public-pkg"}"#));
+        let rule = RepoGovernanceRule::new();
+        let context = create_context(
+            temp_dir.path().to_path_buf(),
+            serde_json::json!({"contributingTemplate": "# How to help\n"}),
+        );
+
+        let fixed = rule.fix(&context).unwrap();
+
+        assert_eq!(fixed, 1);
+        let content = fs::read_to_string(temp_dir.path().join("CONTRIBUTING.md")).unwrap();
+        assert_eq!(content, "# How to help\n");
+        assert!(rule.check(&context).is_empty());
+    }
+}
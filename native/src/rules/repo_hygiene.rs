@@ -0,0 +1,165 @@
+use crate::rules::Rule;
+use crate::types::{CheckEntry, FixEntry, LintResult, RuleContext, Severity};
+use std::path::Path;
+
+// Check IDs
+const CHECK_HAS_INITIAL_COMMIT: &str = "has-initial-commit";
+
+/// Rule: Ensure git repositories are in a state tooling expects
+pub struct RepoHygieneRule;
+
+impl RepoHygieneRule {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Whether a directory holds any file, at any depth. Branch names with
+    /// slashes live in subdirectories of refs/heads.
+    fn has_files(dir: &Path) -> bool {
+        std::fs::read_dir(dir).is_ok_and(|entries| {
+            entries.flatten().any(|entry| {
+                let path = entry.path();
+                path.is_file() || (path.is_dir() && Self::has_files(&path))
+            })
+        })
+    }
+
+    /// Whether a repository's .git directory shows no commits: HEAD points at a
+    /// branch but no branch ref exists, loose or packed. Read from the files so
+    /// git doesn't have to be run. Worktrees and submodules (.git files) are skipped.
+    fn is_empty_repo(git_dir: &Path) -> bool {
+        let Ok(head) = std::fs::read_to_string(git_dir.join("HEAD")) else {
+            return false;
+        };
+        // A detached HEAD names a commit directly
+        if !head.trim_start().starts_with("ref:") {
+            return false;
+        }
+        let packed_branches = std::fs::read_to_string(git_dir.join("packed-refs"))
+            .is_ok_and(|packed| packed.lines().any(|line| line.contains(" refs/heads/")));
+        !packed_branches && !Self::has_files(&git_dir.join("refs").join("heads"))
+    }
+}
+
+impl Default for RepoHygieneRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Rule for RepoHygieneRule {
+    fn id(&self) -> &'static str {
+        "repo-hygiene"
+    }
+
+    fn name(&self) -> &'static str {
+        "Repo Hygiene"
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports git repositories in states that trip up tooling, such as having no commits"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Info
+    }
+
+    fn tags(&self) -> Vec<&'static str> {
+        vec!["git"]
+    }
+
+    fn checks(&self) -> Vec<CheckEntry> {
+        vec![CheckEntry::new(
+            CHECK_HAS_INITIAL_COMMIT,
+            "Verify each git repository has at least one commit",
+        )]
+    }
+
+    fn fixes(&self) -> Vec<FixEntry> {
+        vec![]
+    }
+
+    fn check(&self, context: &RuleContext) -> Vec<LintResult> {
+        context
+            .git_repos()
+            .into_iter()
+            .map(|repo| repo.join(".git"))
+            .filter(|git_dir| git_dir.is_dir() && Self::is_empty_repo(git_dir))
+            .map(|git_dir| {
+                LintResult::new(
+                    self.id(),
+                    CHECK_HAS_INITIAL_COMMIT,
+                    self.default_severity(),
+                    "Repository has no commits yet, so git hooks won't fire until the first commit"
+                        .into(),
+                    git_dir,
+                    None,
+                    Some(
+                        "Create an initial commit, e.g. \
+                         'git commit --allow-empty -m \"Initial commit\"'"
+                            .into(),
+                    ),
+                    vec![], // Report-only: commits are left to the user
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    /// A .git directory as `git init` leaves it, without objects or config
+    fn setup_empty_repo() -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        let git_dir = temp_dir.path().join(".git");
+        fs::create_dir_all(git_dir.join("refs/heads")).unwrap();
+        fs::create_dir_all(git_dir.join("refs/tags")).unwrap();
+        fs::write(git_dir.join("HEAD"), "ref: refs/heads/main\n").unwrap();
+        temp_dir
+    }
+
+    fn check(temp_dir: &TempDir) -> Vec<LintResult> {
+        let context =
+            RuleContext::new(temp_dir.path().to_path_buf(), serde_json::Value::Null);
+        RepoHygieneRule::new().check(&context)
+    }
+
+    #[test]
+    fn test_reports_repo_without_commits() {
+        let temp_dir = setup_empty_repo();
+
+        let results = check(&temp_dir);
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].check_id, CHECK_HAS_INITIAL_COMMIT);
+        assert_eq!(results[0].severity, "info");
+        assert!(results[0].path.ends_with(".git"));
+        assert!(results[0].fixable_by.is_empty());
+    }
+
+    #[test]
+    fn test_accepts_loose_packed_and_detached_commits() {
+        let temp_dir = setup_empty_repo();
+        let git_dir = temp_dir.path().join(".git");
+
+        fs::create_dir_all(git_dir.join("refs/heads/feature")).unwrap();
+        fs::write(git_dir.join("refs/heads/feature/login"), "0123abcd\n").unwrap();
+        assert!(check(&temp_dir).is_empty());
+
+        fs::remove_dir_all(git_dir.join("refs/heads/feature")).unwrap();
+        fs::write(
+            git_dir.join("packed-refs"),
+            "# pack-refs with: peeled fully-peeled sorted\n0123abcd refs/heads/main\n",
+        )
+        .unwrap();
+        assert!(check(&temp_dir).is_empty());
+
+        fs::remove_file(git_dir.join("packed-refs")).unwrap();
+        fs::write(git_dir.join("HEAD"), "0123abcd\n").unwrap();
+        assert!(check(&temp_dir).is_empty());
+    }
+}
@@ -0,0 +1,421 @@
+use crate::rules::{Rule, RuleError};
+use crate::shell::command_positions;
+use crate::types::{CheckEntry, FixEntry, LintResult, RuleContext, Severity};
+use serde_json::Value;
+use std::path::Path;
+
+// Check IDs
+const CHECK_NO_RM_RF: &str = "no-rm-rf";
+const CHECK_LIFECYCLE_NETWORK: &str = "no-network-lifecycle-scripts";
+
+// Fix IDs
+const FIX_USE_RIMRAF: &str = "use-rimraf";
+
+/// Version range added when no `rimrafVersion` option is configured
+const DEFAULT_RIMRAF_VERSION: &str = "^6.0.0";
+
+/// Scripts npm and pnpm run implicitly around install
+const LIFECYCLE_SCRIPTS: &[&str] = &["preinstall", "postinstall", "prepare"];
+
+/// Commands that fetch content from the network
+const NETWORK_COMMANDS: &[&str] = &["curl", "wget"];
+
+/// Shells that execute whatever is piped into them
+const SHELLS: &[&str] = &["sh", "bash", "zsh"];
+
+/// Rule: Keep package.json scripts portable across shells and platforms
+pub struct ScriptsHygieneRule;
+
+impl ScriptsHygieneRule {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Read the `rimrafVersion` option, falling back to the default range
+    fn rimraf_version(&self, context: &RuleContext) -> String {
+        context
+            .config
+            .get("rimrafVersion")
+            .and_then(|v| v.as_str())
+            .unwrap_or(DEFAULT_RIMRAF_VERSION)
+            .to_string()
+    }
+
+    /// The flags of the `rm` invocation at `i` and the byte offset where they end
+    fn rm_flags(script: &str, i: usize) -> (Vec<&str>, usize) {
+        let mut flags = Vec::new();
+        let mut end = i + 2;
+        let mut rest = &script[end..];
+        loop {
+            let trimmed = rest.trim_start();
+            let token = trimmed.split_whitespace().next().unwrap_or("");
+            if !token.starts_with('-') {
+                break;
+            }
+            flags.push(token);
+            end += rest.len() - trimmed.len() + token.len();
+            rest = &script[end..];
+        }
+        (flags, end)
+    }
+
+    fn is_recursive(flags: &[&str]) -> bool {
+        flags.iter().any(|flag| match flag.strip_prefix("--") {
+            Some(long) => long == "recursive",
+            None => flag.contains(['r', 'R']),
+        })
+    }
+
+    /// Recursive `rm` invocations in a script, as the text of their command and flags
+    fn recursive_rms(script: &str) -> Vec<&str> {
+        command_positions(script, "rm")
+            .into_iter()
+            .filter_map(|i| {
+                let (flags, end) = Self::rm_flags(script, i);
+                Self::is_recursive(&flags).then(|| &script[i..end])
+            })
+            .collect()
+    }
+
+    /// Rewrite every recursive `rm` to `rimraf`. Returns None when there is none, or
+    /// when one passes flags other than -r/-R/-f, which rimraf doesn't share.
+    fn rewrite_rm(script: &str) -> Option<String> {
+        let mut rewrites = Vec::new();
+        for i in command_positions(script, "rm") {
+            let (flags, end) = Self::rm_flags(script, i);
+            if !Self::is_recursive(&flags) {
+                continue;
+            }
+            let portable = flags.iter().all(|flag| {
+                *flag == "--recursive"
+                    || *flag == "--force"
+                    || flag[1..].chars().all(|c| matches!(c, 'r' | 'R' | 'f'))
+            });
+            if !portable {
+                return None;
+            }
+            rewrites.push((i, end));
+        }
+        if rewrites.is_empty() {
+            return None;
+        }
+
+        let mut rewritten = script.to_string();
+        for &(start, end) in rewrites.iter().rev() {
+            rewritten.replace_range(start..end, "rimraf");
+        }
+        Some(rewritten)
+    }
+
+    /// The command word of each simple command in a script, and whether its
+    /// input is piped from the previous command. `a || b` is not a pipe.
+    fn command_words(script: &str) -> Vec<(bool, &str)> {
+        let bytes = script.as_bytes();
+        let mut words = Vec::new();
+        let mut start = 0;
+        let mut piped = false;
+        for (i, &b) in bytes.iter().enumerate() {
+            if !matches!(b, b';' | b'&' | b'|' | b'(') {
+                continue;
+            }
+            if let Some(word) = script[start..i].split_whitespace().next() {
+                words.push((piped, word));
+            }
+            piped = b == b'|'
+                && bytes.get(i + 1) != Some(&b'|')
+                && (i == 0 || bytes[i - 1] != b'|');
+            start = i + 1;
+        }
+        if let Some(word) = script[start..].split_whitespace().next() {
+            words.push((piped, word));
+        }
+        words
+    }
+
+    /// What a lifecycle script does that reaches the network, e.g. `runs 'curl'`
+    fn network_usage(script: &str) -> Vec<String> {
+        Self::command_words(script)
+            .into_iter()
+            .filter_map(|(piped, word)| {
+                // `/bin/sh` and `sh` are the same shell
+                let program = word.rsplit('/').next().unwrap_or(word);
+                if NETWORK_COMMANDS.contains(&program) {
+                    Some(format!("runs '{}'", program))
+                } else if piped && SHELLS.contains(&program) {
+                    Some(format!("pipes into '{}'", program))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    fn check_lifecycle_scripts(
+        &self,
+        package_json_path: &Path,
+        scripts: &serde_json::Map<String, Value>,
+    ) -> Vec<LintResult> {
+        LIFECYCLE_SCRIPTS
+            .iter()
+            .filter_map(|name| {
+                let usage = Self::network_usage(scripts.get(*name)?.as_str()?);
+                if usage.is_empty() {
+                    return None;
+                }
+                Some(LintResult::new(
+                    self.id(),
+                    CHECK_LIFECYCLE_NETWORK,
+                    self.default_severity(),
+                    format!("Lifecycle script '{}' {}", name, usage.join(" and ")),
+                    package_json_path.to_path_buf(),
+                    None,
+                    Some(
+                        "Install scripts run on every install; vendor the download or move it \
+                         to an explicitly invoked script"
+                            .into(),
+                    ),
+                    vec![], // Report-only: the script's intent can't be inferred
+                ))
+            })
+            .collect()
+    }
+
+    fn has_dependency(json: &Value, name: &str) -> bool {
+        ["dependencies", "devDependencies"].iter().any(|key| {
+            json.get(key)
+                .and_then(|d| d.as_object())
+                .is_some_and(|deps| deps.contains_key(name))
+        })
+    }
+
+    fn check_package_json(&self, package_json_path: &Path, context: &RuleContext) -> Vec<LintResult> {
+        let Some(json) = context.package_json_value(package_json_path) else {
+            return Vec::new();
+        };
+        let Some(scripts) = json.get("scripts").and_then(|s| s.as_object()) else {
+            return Vec::new();
+        };
+
+        let rm_results = scripts.iter().filter_map(|(name, command)| {
+            let command = command.as_str()?;
+            let invocation = *Self::recursive_rms(command).first()?;
+            let fixable_by = match Self::rewrite_rm(command) {
+                Some(_) => vec![FIX_USE_RIMRAF],
+                None => vec![],
+            };
+            Some(LintResult::new(
+                self.id(),
+                CHECK_NO_RM_RF,
+                self.default_severity(),
+                format!("Script '{}' uses '{}', which fails on Windows", name, invocation),
+                package_json_path.to_path_buf(),
+                None,
+                Some("Use 'rimraf <paths>' to delete files portably".into()),
+                fixable_by,
+            ))
+        });
+
+        rm_results
+            .chain(self.check_lifecycle_scripts(package_json_path, scripts))
+            .collect()
+    }
+
+    /// Rewrite `rm -rf` in scripts to `rimraf` and add rimraf to devDependencies
+    fn use_rimraf(&self, package_json_path: &Path, context: &RuleContext) -> Result<bool, RuleError> {
+        let content = context.read_file(package_json_path)?;
+        let mut json: Value = serde_json::from_str(&content)?;
+
+        let mut changed = false;
+        if let Some(scripts) = json.get_mut("scripts").and_then(|s| s.as_object_mut()) {
+            for script_value in scripts.values_mut() {
+                if let Some(rewritten) = script_value.as_str().and_then(Self::rewrite_rm) {
+                    *script_value = Value::String(rewritten);
+                    changed = true;
+                }
+            }
+        }
+        if !changed {
+            return Ok(false);
+        }
+
+        if !Self::has_dependency(&json, "rimraf") {
+            if json.get("devDependencies").is_none() {
+                json["devDependencies"] = serde_json::json!({});
+            }
+            if let Some(dev_deps) = json.get_mut("devDependencies").and_then(|d| d.as_object_mut()) {
+                dev_deps.insert("rimraf".to_string(), Value::String(self.rimraf_version(context)));
+            }
+        }
+
+        let updated_content = serde_json::to_string_pretty(&json)?;
+        context.write_file(package_json_path, &updated_content)?;
+
+        Ok(true)
+    }
+}
+
+impl Default for ScriptsHygieneRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Rule for ScriptsHygieneRule {
+    fn id(&self) -> &'static str {
+        "scripts-hygiene"
+    }
+
+    fn name(&self) -> &'static str {
+        "Scripts Hygiene"
+    }
+
+    fn description(&self) -> &'static str {
+        "Ensures package.json scripts avoid shell commands that break on Windows or fetch remote code on install"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn tags(&self) -> Vec<&'static str> {
+        vec!["package-manager"]
+    }
+
+    fn checks(&self) -> Vec<CheckEntry> {
+        vec![
+            CheckEntry::new(
+                CHECK_NO_RM_RF,
+                "Verify scripts don't delete files with 'rm -rf' or 'rm -r'",
+            ),
+            CheckEntry::new(
+                CHECK_LIFECYCLE_NETWORK,
+                "Verify preinstall/postinstall/prepare scripts don't run curl or wget or pipe into a shell",
+            ),
+        ]
+    }
+
+    fn fixes(&self) -> Vec<FixEntry> {
+        vec![FixEntry::new(
+            FIX_USE_RIMRAF,
+            "Rewrite 'rm -rf' to 'rimraf' and add rimraf to devDependencies (option: rimrafVersion)",
+            vec![CHECK_NO_RM_RF],
+        )]
+    }
+
+    fn check(&self, context: &RuleContext) -> Vec<LintResult> {
+        context
+            .package_jsons()
+            .iter()
+            .flat_map(|path| self.check_package_json(path, context))
+            .collect()
+    }
+
+    fn fix(&self, context: &RuleContext) -> Result<u32, RuleError> {
+        let mut fixed = 0;
+
+        for package_json in context.package_jsons() {
+            if self.use_rimraf(&package_json, context)? {
+                fixed += 1;
+            }
+        }
+
+        Ok(fixed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn setup_package(json: &str) -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("package.json"), json).unwrap();
+        temp_dir
+    }
+
+    fn create_context(temp_dir: &TempDir) -> RuleContext {
+        RuleContext::new(temp_dir.path().to_path_buf(), Value::Null)
+    }
+
+    #[test]
+    fn test_flags_rm_rf_but_not_lookalikes() {
+        let temp_dir = setup_package(
+            r#"{"name": "test", "scripts": {"clean": "rm -rf dist coverage", "build": "pnpm clean && tsc", "say": "echo rm -rf && rm file.txt", "perm": "chmod -R 755 bin"}}"#,
+        );
+
+        let results = ScriptsHygieneRule::new().check(&create_context(&temp_dir));
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].check_id, CHECK_NO_RM_RF);
+        assert_eq!(results[0].message, "Script 'clean' uses 'rm -rf', which fails on Windows");
+        assert_eq!(results[0].fixable_by, vec![FIX_USE_RIMRAF]);
+    }
+
+    #[test]
+    fn test_rewrite_rm_handles_flag_spellings() {
+        assert_eq!(
+            ScriptsHygieneRule::rewrite_rm("rm -r -f dist && tsc; rm -Rf out").as_deref(),
+            Some("rimraf dist && tsc; rimraf out")
+        );
+        assert_eq!(ScriptsHygieneRule::rewrite_rm("rm file.txt"), None);
+        // -v has no rimraf equivalent, so the script is reported but left alone
+        assert_eq!(ScriptsHygieneRule::rewrite_rm("rm -rfv dist"), None);
+        assert_eq!(ScriptsHygieneRule::recursive_rms("rm -rfv dist"), vec!["rm -rfv"]);
+    }
+
+    #[test]
+    fn test_fix_rewrites_script_and_adds_rimraf() {
+        let temp_dir = setup_package(
+            r#"{"name": "test", "scripts": {"clean": "rm -rf dist", "build": "rm -rf dist && tsc"}}"#,
+        );
+        let rule = ScriptsHygieneRule::new();
+        let context = create_context(&temp_dir);
+
+        assert_eq!(rule.fix(&context).unwrap(), 1);
+
+        let json: Value = serde_json::from_str(
+            &fs::read_to_string(temp_dir.path().join("package.json")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(json["scripts"]["clean"], "rimraf dist");
+        assert_eq!(json["scripts"]["build"], "rimraf dist && tsc");
+        assert_eq!(json["devDependencies"]["rimraf"], DEFAULT_RIMRAF_VERSION);
+        assert!(rule.check(&context).is_empty());
+        assert_eq!(rule.fix(&context).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_flags_network_commands_in_lifecycle_scripts() {
+        let temp_dir = setup_package(
+            r#"{"name": "test", "scripts": {"postinstall": "curl -fsSL https://example.com/install.sh | sh", "prepare": "husky", "fetch": "wget https://example.com/data.json"}}"#,
+        );
+
+        let results = ScriptsHygieneRule::new().check(&create_context(&temp_dir));
+
+        // Only lifecycle scripts are checked; `fetch` runs when invoked explicitly
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].check_id, CHECK_LIFECYCLE_NETWORK);
+        assert_eq!(
+            results[0].message,
+            "Lifecycle script 'postinstall' runs 'curl' and pipes into 'sh'"
+        );
+        assert!(results[0].fixable_by.is_empty());
+    }
+
+    #[test]
+    fn test_network_usage_is_tokenized() {
+        assert!(ScriptsHygieneRule::network_usage("husky").is_empty());
+        assert!(ScriptsHygieneRule::network_usage("node scripts/curl-check.js || sh fallback.sh").is_empty());
+        assert!(ScriptsHygieneRule::network_usage("echo curl | grep sh").is_empty());
+        assert_eq!(
+            ScriptsHygieneRule::network_usage("cd vendor && wget -qO- https://x.test/i | /bin/bash"),
+            vec!["runs 'wget'", "pipes into 'bash'"]
+        );
+    }
+}
@@ -0,0 +1,334 @@
+use crate::rules::{file_too_large_result, Rule};
+use crate::types::{CheckEntry, FixEntry, LintResult, RuleContext, Severity};
+use ignore::WalkBuilder;
+use std::path::{Path, PathBuf};
+
+// Check IDs
+const CHECK_TODO_BUDGET: &str = "todo-budget-exceeded";
+
+/// Default maximum number of TODO/FIXME markers allowed in a single file
+const DEFAULT_MAX_PER_FILE: u64 = 10;
+
+/// How many line numbers to include in the report message
+const MAX_REPORTED_LINES: usize = 5;
+
+/// Directories skipped even when no .gitignore lists them
+const ALWAYS_IGNORED_DIRS: &[&str] = &["node_modules", ".git"];
+
+/// Extensions of files considered source code
+const SOURCE_EXTENSIONS: &[&str] = &[
+    "ts", "tsx", "js", "jsx", "mjs", "cjs", "mts", "cts", "vue", "svelte", "rs", "py", "go",
+    "java", "kt", "rb", "swift", "c", "h", "cpp", "hpp", "cs", "sh",
+];
+
+/// Markers counted against the budget
+const MARKERS: &[&str] = &["TODO", "FIXME"];
+
+/// Rule: Warn when a single source file accumulates too many TODO/FIXME comments
+pub struct TodoBudgetRule;
+
+impl TodoBudgetRule {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Read the `maxPerFile` option, falling back to the default
+    fn max_per_file(&self, context: &RuleContext) -> u64 {
+        context
+            .config
+            .get("maxPerFile")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(DEFAULT_MAX_PER_FILE)
+    }
+
+    fn is_source_file(path: &Path) -> bool {
+        path.extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|ext| SOURCE_EXTENSIONS.contains(&ext))
+    }
+
+    /// Find all source files under the root, skipping whatever .gitignore excludes
+    fn find_source_files(&self, context: &RuleContext) -> Vec<PathBuf> {
+        WalkBuilder::new(&context.root)
+            .hidden(false)
+            .require_git(false)
+            .follow_links(context.follow_symlinks)
+            .sort_by_file_name(|a, b| a.cmp(b))
+            .filter_entry(|e| {
+                e.depth() == 0
+                    || !e.file_type().is_some_and(|t| t.is_dir())
+                    || !e.file_name().to_str().is_some_and(|n| ALWAYS_IGNORED_DIRS.contains(&n))
+            })
+            .build()
+            .flatten()
+            .filter(|e| {
+                e.file_type().is_some_and(|t| t.is_file()) && Self::is_source_file(e.path())
+            })
+            .map(|e| e.into_path())
+            .collect()
+    }
+
+    /// Check whether `marker` appears in `line` as a standalone word
+    fn contains_marker(line: &str, marker: &str) -> bool {
+        line.match_indices(marker).any(|(i, _)| {
+            let valid_start = line[..i]
+                .chars()
+                .last()
+                .is_none_or(|c| !c.is_alphanumeric() && c != '_');
+            let valid_end = line[i + marker.len()..]
+                .chars()
+                .next()
+                .is_none_or(|c| !c.is_alphanumeric() && c != '_');
+            valid_start && valid_end
+        })
+    }
+
+    /// Return the 1-based line numbers containing a TODO/FIXME marker
+    fn marker_lines(content: &str) -> Vec<u32> {
+        content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| MARKERS.iter().any(|m| Self::contains_marker(line, m)))
+            .map(|(i, _)| i as u32 + 1)
+            .collect()
+    }
+
+    /// Check a single file against the budget
+    fn check_file(
+        &self,
+        path: &Path,
+        max_per_file: u64,
+        context: &RuleContext,
+    ) -> Option<LintResult> {
+        let content = match context.read_file_capped(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::FileTooLarge => {
+                return Some(file_too_large_result(self, path, &e));
+            }
+            // Unreadable or not UTF-8, e.g. a binary file
+            Err(_) => return None,
+        };
+
+        // Skip binary files
+        if content.contains('\0') {
+            return None;
+        }
+
+        let lines = Self::marker_lines(&content);
+        if (lines.len() as u64) <= max_per_file {
+            return None;
+        }
+
+        let shown: Vec<String> = lines
+            .iter()
+            .take(MAX_REPORTED_LINES)
+            .map(|l| l.to_string())
+            .collect();
+        let more = if lines.len() > MAX_REPORTED_LINES {
+            ", ..."
+        } else {
+            ""
+        };
+
+        Some(LintResult::new(
+            self.id(),
+            CHECK_TODO_BUDGET,
+            self.default_severity(),
+            format!(
+                "File has {} TODO/FIXME comments (budget is {}) on lines {}{}",
+                lines.len(),
+                max_per_file,
+                shown.join(", "),
+                more
+            ),
+            path.to_path_buf(),
+            lines.first().copied(),
+            Some("Resolve or track the outstanding TODO/FIXME items in an issue tracker".into()),
+            vec![], // Report-only
+        ))
+    }
+}
+
+impl Default for TodoBudgetRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Rule for TodoBudgetRule {
+    fn id(&self) -> &'static str {
+        "todo-budget"
+    }
+
+    fn name(&self) -> &'static str {
+        "TODO Budget"
+    }
+
+    fn description(&self) -> &'static str {
+        "Warns when a source file exceeds the configured number of TODO/FIXME comments"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn tags(&self) -> Vec<&'static str> {
+        vec!["quality"]
+    }
+
+    fn checks(&self) -> Vec<CheckEntry> {
+        vec![CheckEntry::new(
+            CHECK_TODO_BUDGET,
+            "Verify no source file exceeds the TODO/FIXME budget (option: maxPerFile)",
+        )]
+    }
+
+    fn fixes(&self) -> Vec<FixEntry> {
+        vec![]
+    }
+
+    fn check(&self, context: &RuleContext) -> Vec<LintResult> {
+        let max_per_file = self.max_per_file(context);
+
+        self.find_source_files(context)
+            .iter()
+            .filter_map(|path| self.check_file(path, max_per_file, context))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn create_context(root: PathBuf, options: serde_json::Value) -> RuleContext {
+        RuleContext::new(root, options)
+    }
+
+    fn todo_lines(count: usize) -> String {
+        (0..count)
+            .map(|i| format!("// TODO: item {}\nconst x{} = {};\n", i, i, i))
+            .collect()
+    }
+
+    #[test]
+    fn test_flags_file_over_budget() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::write(root.join("over.ts"), todo_lines(4)).unwrap();
+
+        let rule = TodoBudgetRule::new();
+        let context = create_context(root, serde_json::json!({"maxPerFile": 3}));
+        let results = rule.check(&context);
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].check_id, CHECK_TODO_BUDGET);
+        assert!(results[0].message.contains("4 TODO/FIXME"));
+        assert!(results[0].message.contains("lines 1, 3, 5, 7"));
+        assert_eq!(results[0].line, Some(1));
+    }
+
+    #[test]
+    fn test_accepts_file_under_budget() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::write(root.join("under.ts"), todo_lines(3)).unwrap();
+
+        let rule = TodoBudgetRule::new();
+        let context = create_context(root, serde_json::json!({"maxPerFile": 3}));
+
+        assert!(rule.check(&context).is_empty());
+    }
+
+    #[test]
+    fn test_uses_default_budget_without_options() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::write(root.join("a.js"), todo_lines(10)).unwrap();
+        fs::write(root.join("b.js"), todo_lines(11)).unwrap();
+
+        let rule = TodoBudgetRule::new();
+        let context = create_context(root, serde_json::Value::Null);
+        let results = rule.check(&context);
+
+        assert_eq!(results.len(), 1);
+        assert!(results[0].path.ends_with("b.js"));
+    }
+
+    #[test]
+    fn test_skips_ignored_dirs_and_binary_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+
+        let node_modules = root.join("node_modules").join("dep");
+        fs::create_dir_all(&node_modules).unwrap();
+        fs::write(node_modules.join("index.js"), todo_lines(5)).unwrap();
+
+        let mut binary = todo_lines(5).into_bytes();
+        binary.push(0);
+        fs::write(root.join("blob.js"), binary).unwrap();
+
+        let rule = TodoBudgetRule::new();
+        let context = create_context(root, serde_json::json!({"maxPerFile": 1}));
+
+        assert!(rule.check(&context).is_empty());
+    }
+
+    #[test]
+    fn test_skips_gitignored_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::write(root.join(".gitignore"), "generated/\n*.gen.ts\n").unwrap();
+        fs::create_dir_all(root.join("generated")).unwrap();
+        fs::write(root.join("generated/api.ts"), todo_lines(5)).unwrap();
+        fs::write(root.join("schema.gen.ts"), todo_lines(5)).unwrap();
+        fs::create_dir_all(root.join("dist")).unwrap();
+        fs::write(root.join("dist/index.js"), todo_lines(5)).unwrap();
+
+        let rule = TodoBudgetRule::new();
+        let context = create_context(root, serde_json::json!({"maxPerFile": 1}));
+        let results = rule.check(&context);
+
+        // dist/ isn't gitignored here, so it counts like any other source
+        assert_eq!(results.len(), 1);
+        assert!(results[0].path.ends_with("dist/index.js"));
+    }
+
+    #[test]
+    fn test_skips_oversized_file_with_info_note() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::write(root.join("generated.ts"), todo_lines(50)).unwrap();
+        fs::write(root.join("small.ts"), todo_lines(2)).unwrap();
+
+        let rule = TodoBudgetRule::new();
+        let context = create_context(root, serde_json::json!({"maxPerFile": 1}))
+            .with_max_file_size(200);
+        let results = rule.check(&context);
+
+        assert_eq!(results.len(), 2);
+        let skipped = results
+            .iter()
+            .find(|r| r.check_id == crate::rules::CHECK_FILE_TOO_LARGE)
+            .unwrap();
+        assert!(skipped.path.ends_with("generated.ts"));
+        assert_eq!(skipped.severity, "info");
+        assert!(results
+            .iter()
+            .any(|r| r.check_id == CHECK_TODO_BUDGET && r.path.ends_with("small.ts")));
+    }
+
+    #[test]
+    fn test_contains_marker_requires_word_boundary() {
+        assert!(TodoBudgetRule::contains_marker("// TODO: fix", "TODO"));
+        assert!(TodoBudgetRule::contains_marker("/* FIXME */", "FIXME"));
+        assert!(!TodoBudgetRule::contains_marker("const TODOS = []", "TODO"));
+        assert!(!TodoBudgetRule::contains_marker("MY_TODO_LIST", "TODO"));
+    }
+}
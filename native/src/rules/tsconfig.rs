@@ -0,0 +1,1101 @@
+use crate::gitignore;
+use crate::jsonc;
+use crate::rules::{Rule, RuleError};
+use crate::types::{CheckEntry, FixEntry, LintResult, RuleContext, Severity};
+use crate::walk;
+use globset::GlobBuilder;
+use serde_json::Value;
+use std::path::{Component, Path, PathBuf};
+
+// Check IDs
+const CHECK_SKIP_LIB_CHECK: &str = "skip-lib-check";
+const CHECK_COMPILE_SCOPE: &str = "compile-scope";
+const CHECK_OUT_DIR_IGNORED: &str = "out-dir-gitignored";
+const CHECK_TESTS_INCLUDED: &str = "tests-included";
+const CHECK_TYPECHECK_SCRIPT: &str = "typecheck-script";
+
+// Fix IDs
+const FIX_ENABLE_SKIP_LIB_CHECK: &str = "enable-skip-lib-check";
+const FIX_ADD_EXCLUDE: &str = "add-exclude";
+const FIX_GITIGNORE_OUT_DIR: &str = "gitignore-out-dir";
+const FIX_ADD_TYPECHECK_SCRIPT: &str = "add-typecheck-script";
+
+/// Entries the `add-exclude` fix makes sure `exclude` contains
+const DEFAULT_EXCLUDE: &[&str] = &["node_modules", "dist"];
+
+/// Directories that never contain first-party tsconfig files
+const IGNORED_DIRS: &[&str] = &["node_modules", ".git"];
+
+/// Conventional test directory names checked against `include`
+const TEST_DIRS: &[&str] = &["test", "tests", "__tests__"];
+
+/// Extensions of files TypeScript type-checks
+const TS_EXTENSIONS: &[&str] = &["ts", "tsx", "mts", "cts"];
+
+/// Script the `add-typecheck-script` fix adds as `typecheck`
+const TYPECHECK_COMMAND: &str = "tsc --noEmit";
+
+/// Script names already understood to type-check the project
+const TYPECHECK_SCRIPT_NAMES: &[&str] = &["typecheck", "type-check", "check-types"];
+
+/// Compilers whose `--noEmit` run type-checks the project
+const TYPECHECK_BINS: &[&str] = &["tsc", "vue-tsc"];
+
+/// How many `extends` hops to follow before giving up (guards against cycles)
+const MAX_EXTENDS_DEPTH: usize = 10;
+
+/// Where a setting's effective value comes from
+enum OptionSource {
+    /// Set in the tsconfig itself
+    Own(Value),
+    /// Inherited through `extends` from the given file
+    Inherited(Value, PathBuf),
+    /// Not set anywhere in the chain
+    Unset,
+}
+
+/// Rule: Ensure tsconfig.json files use settings that keep type-checking fast and predictable
+pub struct TsconfigRule;
+
+impl TsconfigRule {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Whether the `requireSkipLibCheck` option (default true) asks for skipLibCheck
+    fn require_skip_lib_check(&self, context: &RuleContext) -> bool {
+        context
+            .config
+            .get("requireSkipLibCheck")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true)
+    }
+
+    /// Whether the `requireCompileScope` option (default true) asks for include/exclude
+    fn require_compile_scope(&self, context: &RuleContext) -> bool {
+        context
+            .config
+            .get("requireCompileScope")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true)
+    }
+
+    /// Whether the `requireOutDirIgnored` option (default true) asks for a gitignored outDir
+    fn require_out_dir_ignored(&self, context: &RuleContext) -> bool {
+        context
+            .config
+            .get("requireOutDirIgnored")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true)
+    }
+
+    /// Whether the `requireTestsIncluded` option (default false) asks for tests in `include`
+    fn require_tests_included(&self, context: &RuleContext) -> bool {
+        context
+            .config
+            .get("requireTestsIncluded")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false)
+    }
+
+    /// Whether the `requireTypecheckScript` option (default true) asks for a typecheck script
+    fn require_typecheck_script(&self, context: &RuleContext) -> bool {
+        context
+            .config
+            .get("requireTypecheckScript")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true)
+    }
+
+    fn find_tsconfigs(&self, context: &RuleContext) -> Vec<PathBuf> {
+        walk::entries(&context.root, context.follow_symlinks, IGNORED_DIRS)
+            .into_iter()
+            .filter(|e| e.file_name() == "tsconfig.json" && e.path().is_file())
+            .map(|e| e.into_path())
+            .collect()
+    }
+
+    fn read_tsconfig(path: &Path) -> Option<Value> {
+        jsonc::parse(&std::fs::read_to_string(path).ok()?).ok()
+    }
+
+    /// Resolve one `extends` entry: a relative path, or a package in node_modules
+    fn resolve_extends(tsconfig_path: &Path, spec: &str) -> Option<PathBuf> {
+        let dir = tsconfig_path.parent()?;
+        let with_json = |path: PathBuf| {
+            if path.extension().is_some_and(|e| e == "json") {
+                vec![path]
+            } else {
+                vec![path.with_extension("json"), path.join("tsconfig.json"), path]
+            }
+        };
+
+        let candidates = if spec.starts_with('.') || Path::new(spec).is_absolute() {
+            with_json(dir.join(spec))
+        } else {
+            dir.ancestors()
+                .flat_map(|d| with_json(d.join("node_modules").join(spec)))
+                .collect()
+        };
+
+        candidates
+            .into_iter()
+            .find(|p| p.is_file())
+            .map(|p| Self::normalize(&p))
+    }
+
+    /// Collapse `.` and `..` components without touching the filesystem
+    fn normalize(path: &Path) -> PathBuf {
+        let mut normalized = PathBuf::new();
+        for component in path.components() {
+            match component {
+                Component::CurDir => {}
+                Component::ParentDir => {
+                    normalized.pop();
+                }
+                other => normalized.push(other),
+            }
+        }
+        normalized
+    }
+
+    /// The tsconfig files listed in `extends`, in order (later entries win)
+    fn extends_paths(tsconfig_path: &Path, json: &Value) -> Vec<PathBuf> {
+        let specs: Vec<&str> = match json.get("extends") {
+            Some(Value::String(spec)) => vec![spec.as_str()],
+            Some(Value::Array(specs)) => specs.iter().filter_map(|s| s.as_str()).collect(),
+            _ => Vec::new(),
+        };
+
+        specs
+            .into_iter()
+            .filter_map(|spec| Self::resolve_extends(tsconfig_path, spec))
+            .collect()
+    }
+
+    /// The value at `key` (e.g. `["compilerOptions", "skipLibCheck"]`), if set
+    fn lookup<'a>(json: &'a Value, key: &[&str]) -> Option<&'a Value> {
+        key.iter().try_fold(json, |value, part| value.get(part))
+    }
+
+    /// Find a setting in a base config, following further `extends`
+    fn inherited_setting(
+        tsconfig_path: &Path,
+        json: &Value,
+        key: &[&str],
+        depth: usize,
+    ) -> Option<(Value, PathBuf)> {
+        if depth >= MAX_EXTENDS_DEPTH {
+            return None;
+        }
+
+        Self::extends_paths(tsconfig_path, json)
+            .into_iter()
+            .rev()
+            .find_map(|base_path| {
+                let base = Self::read_tsconfig(&base_path)?;
+                match Self::lookup(&base, key) {
+                    Some(value) => Some((value.clone(), base_path)),
+                    None => Self::inherited_setting(&base_path, &base, key, depth + 1),
+                }
+            })
+    }
+
+    fn setting(tsconfig_path: &Path, json: &Value, key: &[&str]) -> OptionSource {
+        if let Some(value) = Self::lookup(json, key) {
+            return OptionSource::Own(value.clone());
+        }
+        match Self::inherited_setting(tsconfig_path, json, key, 0) {
+            Some((value, from)) => OptionSource::Inherited(value, from),
+            None => OptionSource::Unset,
+        }
+    }
+
+    fn compiler_option(tsconfig_path: &Path, json: &Value, option: &str) -> OptionSource {
+        Self::setting(tsconfig_path, json, &["compilerOptions", option])
+    }
+
+    /// Whether an `exclude` list keeps node_modules out of the program
+    fn excludes_node_modules(exclude: &Value) -> bool {
+        exclude.as_array().is_some_and(|entries| {
+            entries.iter().filter_map(|e| e.as_str()).any(|entry| {
+                let entry = entry
+                    .trim()
+                    .trim_start_matches("./")
+                    .trim_start_matches("**/")
+                    .trim_end_matches("/**")
+                    .trim_end_matches('/');
+                entry == "node_modules"
+            })
+        })
+    }
+
+    fn display_path(path: &Path, context: &RuleContext) -> String {
+        path.strip_prefix(&context.root)
+            .unwrap_or(path)
+            .display()
+            .to_string()
+    }
+
+    fn check_skip_lib_check(
+        &self,
+        tsconfig_path: &Path,
+        json: &Value,
+        context: &RuleContext,
+    ) -> Option<LintResult> {
+        let (severity, message, fixable_by) =
+            match Self::compiler_option(tsconfig_path, json, "skipLibCheck") {
+                OptionSource::Own(Value::Bool(true)) => return None,
+                OptionSource::Own(_) => (
+                    self.default_severity(),
+                    "compilerOptions.skipLibCheck is disabled".to_string(),
+                    vec![FIX_ENABLE_SKIP_LIB_CHECK],
+                ),
+                OptionSource::Inherited(Value::Bool(true), from) => {
+                    if context.quiet {
+                        return None;
+                    }
+                    (
+                        Severity::Info,
+                        format!(
+                            "compilerOptions.skipLibCheck is inherited from {}",
+                            Self::display_path(&from, context)
+                        ),
+                        vec![],
+                    )
+                }
+                OptionSource::Inherited(_, from) => (
+                    self.default_severity(),
+                    format!(
+                        "compilerOptions.skipLibCheck is disabled in {}",
+                        Self::display_path(&from, context)
+                    ),
+                    vec![FIX_ENABLE_SKIP_LIB_CHECK],
+                ),
+                OptionSource::Unset => (
+                    self.default_severity(),
+                    "compilerOptions.skipLibCheck is not set".to_string(),
+                    vec![FIX_ENABLE_SKIP_LIB_CHECK],
+                ),
+            };
+
+        let suggestion = if fixable_by.is_empty() {
+            None
+        } else {
+            Some(
+                "Set compilerOptions.skipLibCheck to true so node_modules .d.ts files aren't type-checked"
+                    .into(),
+            )
+        };
+
+        Some(LintResult::new(
+            self.id(),
+            CHECK_SKIP_LIB_CHECK,
+            severity,
+            message,
+            tsconfig_path.to_path_buf(),
+            None,
+            suggestion,
+            fixable_by,
+        ))
+    }
+
+    /// How `include`, `files` or an `exclude` covering node_modules limit the
+    /// program: set here, inherited from a base config, or not at all
+    fn compile_scope(tsconfig_path: &Path, json: &Value) -> OptionSource {
+        let mut inherited = None;
+        for key in ["include", "files", "exclude"] {
+            let source = Self::setting(tsconfig_path, json, &[key]);
+            let limits = |value: &Value| key != "exclude" || Self::excludes_node_modules(value);
+            match source {
+                OptionSource::Own(ref value) if limits(value) => return source,
+                OptionSource::Inherited(ref value, _) if limits(value) => {
+                    inherited.get_or_insert(source);
+                }
+                _ => {}
+            }
+        }
+        inherited.unwrap_or(OptionSource::Unset)
+    }
+
+    fn check_compile_scope(
+        &self,
+        tsconfig_path: &Path,
+        json: &Value,
+        context: &RuleContext,
+    ) -> Option<LintResult> {
+        let (severity, message, suggestion, fixable_by) =
+            match Self::compile_scope(tsconfig_path, json) {
+                OptionSource::Own(_) => return None,
+                OptionSource::Inherited(_, from) => {
+                    if context.quiet {
+                        return None;
+                    }
+                    (
+                        Severity::Info,
+                        format!(
+                            "include/exclude are inherited from {}",
+                            Self::display_path(&from, context)
+                        ),
+                        None,
+                        vec![],
+                    )
+                }
+                OptionSource::Unset => (
+                    self.default_severity(),
+                    "tsconfig.json sets neither include nor files, and exclude doesn't cover node_modules"
+                        .to_string(),
+                    Some(
+                        "Add \"exclude\": [\"node_modules\", \"dist\"] or list the sources in \"include\""
+                            .to_string(),
+                    ),
+                    vec![FIX_ADD_EXCLUDE],
+                ),
+            };
+
+        Some(LintResult::new(
+            self.id(),
+            CHECK_COMPILE_SCOPE,
+            severity,
+            message,
+            tsconfig_path.to_path_buf(),
+            None,
+            suggestion,
+            fixable_by,
+        ))
+    }
+
+    /// The repository holding the tsconfig and its outDir relative to that
+    /// repository as a '/'-separated path, when outDir is set (here or in a base
+    /// config, relative to the file declaring it) and isn't already gitignored
+    fn unignored_out_dir(
+        tsconfig_path: &Path,
+        json: &Value,
+        context: &RuleContext,
+    ) -> Option<(PathBuf, String)> {
+        let (out_dir, declared_in) = match Self::compiler_option(tsconfig_path, json, "outDir") {
+            OptionSource::Own(value) => (value, tsconfig_path.to_path_buf()),
+            OptionSource::Inherited(value, from) => (value, from),
+            OptionSource::Unset => return None,
+        };
+        let out_dir = Self::normalize(&declared_in.parent()?.join(out_dir.as_str()?));
+
+        let repo_root = gitignore::repo_root(tsconfig_path.parent()?, &context.root);
+        let relative = out_dir.strip_prefix(&repo_root).ok()?;
+        if relative.as_os_str().is_empty() || gitignore::is_ignored(&repo_root, relative, true) {
+            return None;
+        }
+        let parts: Vec<_> = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect();
+        Some((repo_root, parts.join("/")))
+    }
+
+    fn check_out_dir_ignored(
+        &self,
+        tsconfig_path: &Path,
+        json: &Value,
+        context: &RuleContext,
+    ) -> Option<LintResult> {
+        let (_, out_dir) = Self::unignored_out_dir(tsconfig_path, json, context)?;
+
+        Some(LintResult::new(
+            self.id(),
+            CHECK_OUT_DIR_IGNORED,
+            self.default_severity(),
+            format!("compilerOptions.outDir '{}' is not gitignored", out_dir),
+            tsconfig_path.to_path_buf(),
+            None,
+            Some(format!("Add '{}/' to .gitignore so build output isn't committed", out_dir)),
+            vec![FIX_GITIGNORE_OUT_DIR],
+        ))
+    }
+
+    /// Whether an `include` entry matches a '/'-separated path relative to the
+    /// config declaring it. Entries without wildcards name a file or directory.
+    fn include_matches(pattern: &str, relative: &str) -> bool {
+        let pattern = pattern.trim().trim_start_matches("./").trim_end_matches('/');
+        if !pattern.contains(['*', '?']) {
+            return pattern.is_empty()
+                || pattern == "."
+                || relative == pattern
+                || relative.starts_with(&format!("{}/", pattern));
+        }
+        GlobBuilder::new(pattern)
+            .literal_separator(true)
+            .build()
+            .is_ok_and(|glob| glob.compile_matcher().is_match(relative))
+    }
+
+    /// Test directories next to the tsconfig holding TypeScript files that no
+    /// `include` entry matches. Tests with their own tsconfig are left alone.
+    fn unincluded_test_dirs(
+        tsconfig_path: &Path,
+        json: &Value,
+        context: &RuleContext,
+    ) -> Vec<String> {
+        let (include, declared_in) = match Self::setting(tsconfig_path, json, &["include"]) {
+            OptionSource::Own(value) => (value, tsconfig_path.to_path_buf()),
+            OptionSource::Inherited(value, from) => (value, from),
+            OptionSource::Unset => return Vec::new(),
+        };
+        let Some(patterns) = include.as_array() else {
+            return Vec::new();
+        };
+        let patterns: Vec<&str> = patterns.iter().filter_map(|p| p.as_str()).collect();
+        let (Some(project_dir), Some(base_dir)) = (tsconfig_path.parent(), declared_in.parent())
+        else {
+            return Vec::new();
+        };
+        if project_dir.join("tsconfig.test.json").is_file() {
+            return Vec::new();
+        }
+
+        TEST_DIRS
+            .iter()
+            .map(|name| project_dir.join(name))
+            .filter(|dir| dir.is_dir() && !dir.join("tsconfig.json").is_file())
+            .filter(|dir| {
+                let ts_files: Vec<String> =
+                    walk::entries(dir, context.follow_symlinks, IGNORED_DIRS)
+                        .into_iter()
+                        .filter(|e| {
+                            e.path()
+                                .extension()
+                                .and_then(|ext| ext.to_str())
+                                .is_some_and(|ext| TS_EXTENSIONS.contains(&ext))
+                        })
+                        .filter_map(|e| {
+                            let relative = e.path().strip_prefix(base_dir).ok()?;
+                            let parts: Vec<_> = relative
+                                .components()
+                                .map(|c| c.as_os_str().to_string_lossy())
+                                .collect();
+                            Some(parts.join("/"))
+                        })
+                        .collect();
+                !ts_files.is_empty()
+                    && !ts_files
+                        .iter()
+                        .any(|file| patterns.iter().any(|p| Self::include_matches(p, file)))
+            })
+            .filter_map(|dir| Some(dir.file_name()?.to_string_lossy().into_owned()))
+            .collect()
+    }
+
+    fn check_tests_included(
+        &self,
+        tsconfig_path: &Path,
+        json: &Value,
+        context: &RuleContext,
+    ) -> Vec<LintResult> {
+        Self::unincluded_test_dirs(tsconfig_path, json, context)
+            .into_iter()
+            .map(|test_dir| {
+                LintResult::new(
+                    self.id(),
+                    CHECK_TESTS_INCLUDED,
+                    self.default_severity(),
+                    format!(
+                        "Tests in '{}' aren't matched by include, so they aren't type-checked",
+                        test_dir
+                    ),
+                    tsconfig_path.to_path_buf(),
+                    None,
+                    Some(format!(
+                        "Add '{}' to include, or create a tsconfig.test.json that extends \
+                         tsconfig.json and includes the tests",
+                        test_dir
+                    )),
+                    vec![], // Report-only: whether tests share the build config is a choice
+                )
+            })
+            .collect()
+    }
+
+    /// Whether a script type-checks: it's named like `typecheck`, or runs tsc or
+    /// vue-tsc with `--noEmit` as a command word
+    fn is_typecheck_script(name: &str, command: &str) -> bool {
+        let tokens: Vec<&str> = command
+            .split(|c: char| c.is_whitespace() || matches!(c, ';' | '&' | '|' | '(' | ')'))
+            .collect();
+        TYPECHECK_SCRIPT_NAMES.contains(&name)
+            || (tokens.iter().any(|token| TYPECHECK_BINS.contains(token))
+                && tokens.contains(&"--noEmit"))
+    }
+
+    /// The package.json next to a tsconfig when none of its scripts type-checks
+    fn missing_typecheck_script(tsconfig_path: &Path, context: &RuleContext) -> Option<PathBuf> {
+        let package_json_path = tsconfig_path.parent()?.join("package.json");
+        let json = context.package_json_value(&package_json_path)?;
+        let has_typecheck = json.get("scripts").and_then(|s| s.as_object()).is_some_and(|scripts| {
+            scripts.iter().any(|(name, command)| {
+                Self::is_typecheck_script(name, command.as_str().unwrap_or(""))
+            })
+        });
+        (!has_typecheck).then_some(package_json_path)
+    }
+
+    fn check_typecheck_script(
+        &self,
+        tsconfig_path: &Path,
+        context: &RuleContext,
+    ) -> Option<LintResult> {
+        let package_json_path = Self::missing_typecheck_script(tsconfig_path, context)?;
+        Some(LintResult::new(
+            self.id(),
+            CHECK_TYPECHECK_SCRIPT,
+            self.default_severity(),
+            "No script runs 'tsc --noEmit', so CI can't type-check the project".into(),
+            package_json_path,
+            None,
+            Some(format!("Add \"typecheck\": \"{}\" to scripts", TYPECHECK_COMMAND)),
+            vec![FIX_ADD_TYPECHECK_SCRIPT],
+        ))
+    }
+
+    /// Add a `typecheck` script to the package.json next to the tsconfig
+    fn add_typecheck_script(
+        &self,
+        tsconfig_path: &Path,
+        context: &RuleContext,
+    ) -> Result<bool, RuleError> {
+        let Some(package_json_path) = Self::missing_typecheck_script(tsconfig_path, context) else {
+            return Ok(false);
+        };
+        let content = context.read_file(&package_json_path)?;
+        let Some(updated) = jsonc::set_property(
+            &content,
+            &["scripts", "typecheck"],
+            &Value::from(TYPECHECK_COMMAND),
+        ) else {
+            return Ok(false);
+        };
+        context.write_file(&package_json_path, &updated)?;
+
+        Ok(true)
+    }
+
+    /// Append the outDir to the repository's .gitignore
+    fn gitignore_out_dir(
+        &self,
+        tsconfig_path: &Path,
+        context: &RuleContext,
+    ) -> Result<bool, RuleError> {
+        let Some(json) = Self::read_tsconfig(tsconfig_path) else {
+            return Ok(false);
+        };
+        let Some((repo_root, out_dir)) = Self::unignored_out_dir(tsconfig_path, &json, context)
+        else {
+            return Ok(false);
+        };
+        Ok(gitignore::append_entry(context, &repo_root, &format!("{}/", out_dir))?)
+    }
+
+    /// Add node_modules and dist to `exclude`, keeping existing entries
+    fn add_exclude(&self, tsconfig_path: &Path, context: &RuleContext) -> Result<bool, RuleError> {
+        let content = context.read_file(tsconfig_path)?;
+        let Ok(json) = jsonc::parse(&content) else {
+            return Ok(false);
+        };
+        if !matches!(Self::compile_scope(tsconfig_path, &json), OptionSource::Unset) {
+            return Ok(false);
+        }
+
+        let mut exclude: Vec<Value> = json
+            .get("exclude")
+            .and_then(|e| e.as_array())
+            .cloned()
+            .unwrap_or_default();
+        for entry in DEFAULT_EXCLUDE {
+            if !exclude.iter().any(|e| e == entry) {
+                exclude.push(Value::from(*entry));
+            }
+        }
+
+        let Some(updated) = jsonc::set_property(&content, &["exclude"], &Value::Array(exclude))
+        else {
+            return Ok(false);
+        };
+        context.write_file(tsconfig_path, &updated)?;
+
+        Ok(true)
+    }
+
+    /// Set compilerOptions.skipLibCheck to true, keeping comments and layout
+    fn enable_skip_lib_check(
+        &self,
+        tsconfig_path: &Path,
+        context: &RuleContext,
+    ) -> Result<bool, RuleError> {
+        let content = context.read_file(tsconfig_path)?;
+        let Ok(json) = jsonc::parse(&content) else {
+            return Ok(false);
+        };
+
+        let needs_fix = match Self::compiler_option(tsconfig_path, &json, "skipLibCheck") {
+            OptionSource::Own(value) | OptionSource::Inherited(value, _) => {
+                value != Value::Bool(true)
+            }
+            OptionSource::Unset => true,
+        };
+        if !needs_fix {
+            return Ok(false);
+        }
+
+        let Some(updated) =
+            jsonc::set_property(&content, &["compilerOptions", "skipLibCheck"], &Value::Bool(true))
+        else {
+            return Ok(false);
+        };
+        context.write_file(tsconfig_path, &updated)?;
+
+        Ok(true)
+    }
+}
+
+impl Default for TsconfigRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Rule for TsconfigRule {
+    fn id(&self) -> &'static str {
+        "tsconfig"
+    }
+
+    fn name(&self) -> &'static str {
+        "TypeScript Config"
+    }
+
+    fn description(&self) -> &'static str {
+        "Ensures tsconfig.json files use settings that keep type-checking fast"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn tags(&self) -> Vec<&'static str> {
+        vec!["typescript"]
+    }
+
+    fn checks(&self) -> Vec<CheckEntry> {
+        vec![
+            CheckEntry::new(
+                CHECK_SKIP_LIB_CHECK,
+                "Verify compilerOptions.skipLibCheck is true, directly or via extends (option: requireSkipLibCheck)",
+            ),
+            CheckEntry::new(
+                CHECK_COMPILE_SCOPE,
+                "Verify include or files is set, or exclude covers node_modules (option: requireCompileScope)",
+            ),
+            CheckEntry::new(
+                CHECK_OUT_DIR_IGNORED,
+                "Verify compilerOptions.outDir is excluded by .gitignore (option: requireOutDirIgnored)",
+            ),
+            CheckEntry::new(
+                CHECK_TESTS_INCLUDED,
+                "Verify include covers test/ and __tests__ directories (opt-in via option: requireTestsIncluded)",
+            ),
+            CheckEntry::new(
+                CHECK_TYPECHECK_SCRIPT,
+                "Verify the package.json next to tsconfig.json has a script running 'tsc --noEmit' (option: requireTypecheckScript)",
+            ),
+        ]
+    }
+
+    fn fixes(&self) -> Vec<FixEntry> {
+        vec![
+            FixEntry::new(
+                FIX_ENABLE_SKIP_LIB_CHECK,
+                "Set compilerOptions.skipLibCheck to true in tsconfig.json",
+                vec![CHECK_SKIP_LIB_CHECK],
+            ),
+            FixEntry::new(
+                FIX_ADD_EXCLUDE,
+                "Add node_modules and dist to exclude in tsconfig.json",
+                vec![CHECK_COMPILE_SCOPE],
+            ),
+            FixEntry::new(
+                FIX_GITIGNORE_OUT_DIR,
+                "Append compilerOptions.outDir to the repository's .gitignore",
+                vec![CHECK_OUT_DIR_IGNORED],
+            ),
+            FixEntry::new(
+                FIX_ADD_TYPECHECK_SCRIPT,
+                "Add a \"typecheck\": \"tsc --noEmit\" script to package.json",
+                vec![CHECK_TYPECHECK_SCRIPT],
+            ),
+        ]
+    }
+
+    fn check(&self, context: &RuleContext) -> Vec<LintResult> {
+        let skip_lib_check = self.require_skip_lib_check(context);
+        let compile_scope = self.require_compile_scope(context);
+        let out_dir_ignored = self.require_out_dir_ignored(context);
+        let tests_included = self.require_tests_included(context);
+        let typecheck_script = self.require_typecheck_script(context);
+        let mut results = Vec::new();
+
+        for path in self.find_tsconfigs(context) {
+            let Some(json) = Self::read_tsconfig(&path) else {
+                continue;
+            };
+            if skip_lib_check {
+                results.extend(self.check_skip_lib_check(&path, &json, context));
+            }
+            if compile_scope {
+                results.extend(self.check_compile_scope(&path, &json, context));
+            }
+            if out_dir_ignored {
+                results.extend(self.check_out_dir_ignored(&path, &json, context));
+            }
+            if tests_included {
+                results.extend(self.check_tests_included(&path, &json, context));
+            }
+            if typecheck_script {
+                results.extend(self.check_typecheck_script(&path, context));
+            }
+        }
+
+        results
+    }
+
+    fn fix(&self, context: &RuleContext) -> Result<u32, RuleError> {
+        let skip_lib_check = self.require_skip_lib_check(context);
+        let compile_scope = self.require_compile_scope(context);
+        let out_dir_ignored = self.require_out_dir_ignored(context);
+        let typecheck_script = self.require_typecheck_script(context);
+
+        let mut fixed = 0;
+        for path in self.find_tsconfigs(context) {
+            if skip_lib_check && self.enable_skip_lib_check(&path, context)? {
+                fixed += 1;
+            }
+            if compile_scope && self.add_exclude(&path, context)? {
+                fixed += 1;
+            }
+            if out_dir_ignored && self.gitignore_out_dir(&path, context)? {
+                fixed += 1;
+            }
+            if typecheck_script && self.add_typecheck_script(&path, context)? {
+                fixed += 1;
+            }
+        }
+
+        Ok(fixed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn create_context(root: PathBuf, options: Value) -> RuleContext {
+        RuleContext::new(root, options)
+    }
+
+    /// Options isolating the skipLibCheck check
+    fn skip_lib_check_only() -> Value {
+        serde_json::json!({"requireCompileScope": false})
+    }
+
+    #[test]
+    fn test_missing_skip_lib_check_is_fixed_keeping_comments() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::write(
+            root.join("tsconfig.json"),
+            "{\n  // project settings\n  \"compilerOptions\": {\n    \"strict\": true,\n  },\n}\n",
+        )
+        .unwrap();
+
+        let rule = TsconfigRule::new();
+        let context = create_context(root.clone(), skip_lib_check_only());
+        let results = rule.check(&context);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].severity, "warning");
+        assert_eq!(results[0].message, "compilerOptions.skipLibCheck is not set");
+
+        assert_eq!(rule.fix(&context).unwrap(), 1);
+        assert_eq!(
+            fs::read_to_string(root.join("tsconfig.json")).unwrap(),
+            "{\n  // project settings\n  \"compilerOptions\": {\n    \"strict\": true,\n    \"skipLibCheck\": true,\n  },\n}\n"
+        );
+        assert!(rule.check(&context).is_empty());
+        assert_eq!(rule.fix(&context).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_present_skip_lib_check_passes() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::write(
+            root.join("tsconfig.json"),
+            r#"{"compilerOptions": {"skipLibCheck": true}}"#,
+        )
+        .unwrap();
+
+        let rule = TsconfigRule::new();
+        assert!(rule.check(&create_context(root, skip_lib_check_only())).is_empty());
+    }
+
+    #[test]
+    fn test_inherited_skip_lib_check_is_info() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::write(
+            root.join("tsconfig.base.json"),
+            "{\n  /* shared */\n  \"compilerOptions\": {\"skipLibCheck\": true}\n}\n",
+        )
+        .unwrap();
+        let package_dir = root.join("packages/app");
+        fs::create_dir_all(&package_dir).unwrap();
+        fs::write(
+            package_dir.join("tsconfig.json"),
+            r#"{"extends": "../../tsconfig.base.json"}"#,
+        )
+        .unwrap();
+
+        let rule = TsconfigRule::new();
+        let context = create_context(root, skip_lib_check_only());
+        let results = rule.check(&context);
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].severity, "info");
+        assert_eq!(
+            results[0].message,
+            "compilerOptions.skipLibCheck is inherited from tsconfig.base.json"
+        );
+        assert!(results[0].fixable_by.is_empty());
+        assert_eq!(rule.fix(&context).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_require_skip_lib_check_can_be_turned_off() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::write(root.join("tsconfig.json"), "{}").unwrap();
+
+        let rule = TsconfigRule::new();
+        let context = create_context(
+            root,
+            serde_json::json!({"requireSkipLibCheck": false, "requireCompileScope": false}),
+        );
+        assert!(rule.check(&context).is_empty());
+        assert_eq!(rule.fix(&context).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_bare_tsconfig_gets_conservative_exclude() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::write(
+            root.join("tsconfig.json"),
+            "{\n  // app\n  \"compilerOptions\": {\n    \"skipLibCheck\": true\n  }\n}\n",
+        )
+        .unwrap();
+
+        let rule = TsconfigRule::new();
+        let context = create_context(root.clone(), Value::Null);
+        let results = rule.check(&context);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].check_id, CHECK_COMPILE_SCOPE);
+        assert_eq!(results[0].severity, "warning");
+
+        assert_eq!(rule.fix(&context).unwrap(), 1);
+        assert_eq!(
+            fs::read_to_string(root.join("tsconfig.json")).unwrap(),
+            "{\n  // app\n  \"compilerOptions\": {\n    \"skipLibCheck\": true\n  },\n  \"exclude\": [\"node_modules\",\"dist\"]\n}\n"
+        );
+        assert!(rule.check(&context).is_empty());
+    }
+
+    #[test]
+    fn test_include_or_node_modules_exclude_passes() {
+        let rule = TsconfigRule::new();
+        for tsconfig in [
+            r#"{"compilerOptions": {"skipLibCheck": true}, "include": ["src"]}"#,
+            r#"{"compilerOptions": {"skipLibCheck": true}, "exclude": ["./node_modules/"]}"#,
+        ] {
+            let temp_dir = TempDir::new().unwrap();
+            fs::write(temp_dir.path().join("tsconfig.json"), tsconfig).unwrap();
+
+            let context = create_context(temp_dir.path().to_path_buf(), Value::Null);
+            assert!(rule.check(&context).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_inherited_include_is_info() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::write(root.join("tsconfig.base.json"), r#"{"include": ["src"]}"#).unwrap();
+        fs::write(
+            root.join("tsconfig.json"),
+            r#"{"extends": "./tsconfig.base.json", "compilerOptions": {"skipLibCheck": true}}"#,
+        )
+        .unwrap();
+
+        let rule = TsconfigRule::new();
+        let context = create_context(root, Value::Null);
+        let results = rule.check(&context);
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].severity, "info");
+        assert_eq!(results[0].message, "include/exclude are inherited from tsconfig.base.json");
+        assert_eq!(rule.fix(&context).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_out_dir_not_gitignored_is_fixed_once() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::create_dir_all(root.join(".git")).unwrap();
+        fs::write(root.join(".gitignore"), "node_modules\n").unwrap();
+        fs::write(
+            root.join("tsconfig.json"),
+            "{\n  // build settings\n  \"compilerOptions\": { \"outDir\": \"./dist\", \"skipLibCheck\": true },\n  \"include\": [\"src\"],\n}\n",
+        )
+        .unwrap();
+
+        let rule = TsconfigRule::new();
+        let context = create_context(root.clone(), Value::Null);
+        let results = rule.check(&context);
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].check_id, CHECK_OUT_DIR_IGNORED);
+        assert_eq!(results[0].message, "compilerOptions.outDir 'dist' is not gitignored");
+        assert_eq!(results[0].fixable_by, vec![FIX_GITIGNORE_OUT_DIR]);
+
+        assert_eq!(rule.fix(&context).unwrap(), 1);
+        assert_eq!(fs::read_to_string(root.join(".gitignore")).unwrap(), "node_modules\ndist/\n");
+        assert!(rule.check(&context).is_empty());
+        assert_eq!(rule.fix(&context).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_inherited_out_dir_resolves_against_base_config() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::create_dir_all(root.join(".git")).unwrap();
+        fs::write(
+            root.join("tsconfig.base.json"),
+            r#"{"compilerOptions": {"outDir": "build", "skipLibCheck": true}}"#,
+        )
+        .unwrap();
+        let package_dir = root.join("packages/core");
+        fs::create_dir_all(&package_dir).unwrap();
+        fs::write(
+            package_dir.join("tsconfig.json"),
+            r#"{"extends": "../../tsconfig.base.json", "include": ["src"]}"#,
+        )
+        .unwrap();
+
+        let rule = TsconfigRule::new();
+        let out_dir_results = |root: &PathBuf| -> Vec<LintResult> {
+            rule.check(&create_context(root.clone(), Value::Null))
+                .into_iter()
+                .filter(|r| r.check_id == CHECK_OUT_DIR_IGNORED)
+                .collect()
+        };
+        let results = out_dir_results(&root);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].message, "compilerOptions.outDir 'build' is not gitignored");
+
+        fs::write(root.join(".gitignore"), "build/\n").unwrap();
+        assert!(out_dir_results(&root).is_empty());
+    }
+
+    #[test]
+    fn test_include_missing_tests_is_flagged() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::write(
+            root.join("tsconfig.json"),
+            "{\n  // Build sources only\n  \"compilerOptions\": {\"skipLibCheck\": true},\n  \"include\": [\"src/**/*.ts\"]\n}\n",
+        )
+        .unwrap();
+        for file in ["src/index.ts", "__tests__/index.test.ts", "test/fixtures/data.json"] {
+            fs::create_dir_all(root.join(file).parent().unwrap()).unwrap();
+            fs::write(root.join(file), "").unwrap();
+        }
+        let rule = TsconfigRule::new();
+        let options = serde_json::json!({"requireTestsIncluded": true});
+
+        let results = rule.check(&create_context(root.clone(), options.clone()));
+
+        // test/ holds no TypeScript, so only __tests__ is reported
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].check_id, CHECK_TESTS_INCLUDED);
+        assert_eq!(
+            results[0].message,
+            "Tests in '__tests__' aren't matched by include, so they aren't type-checked"
+        );
+        assert!(results[0].fixable_by.is_empty());
+        assert!(rule.check(&create_context(root.clone(), Value::Null)).is_empty());
+
+        fs::write(
+            root.join("tsconfig.json"),
+            r#"{"compilerOptions": {"skipLibCheck": true}, "include": ["src", "**/*.test.ts"]}"#,
+        )
+        .unwrap();
+        assert!(rule.check(&create_context(root, options)).is_empty());
+    }
+
+    #[test]
+    fn test_include_matches_follows_typescript_semantics() {
+        assert!(TsconfigRule::include_matches("./test/", "test/a/b.ts"));
+        assert!(TsconfigRule::include_matches("**/*", "__tests__/a.ts"));
+        assert!(!TsconfigRule::include_matches("src/*.ts", "src/nested/a.ts"));
+        assert!(!TsconfigRule::include_matches("tests", "tests-utils/a.ts"));
+    }
+
+    #[test]
+    fn test_missing_typecheck_script_is_fixed() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::write(
+            root.join("tsconfig.json"),
+            r#"{"compilerOptions": {"skipLibCheck": true}, "include": ["src"]}"#,
+        )
+        .unwrap();
+        fs::write(
+            root.join("package.json"),
+            "{\n  \"name\": \"app\",\n  \"scripts\": {\n    \"build\": \"tsc\"\n  }\n}\n",
+        )
+        .unwrap();
+        let rule = TsconfigRule::new();
+        let context = create_context(root.clone(), Value::Null);
+
+        let results = rule.check(&context);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].check_id, CHECK_TYPECHECK_SCRIPT);
+        assert!(results[0].path.ends_with("package.json"));
+        assert_eq!(results[0].fixable_by, vec![FIX_ADD_TYPECHECK_SCRIPT]);
+        let options = serde_json::json!({"requireTypecheckScript": false});
+        assert!(rule.check(&create_context(root.clone(), options)).is_empty());
+
+        assert_eq!(rule.fix(&context).unwrap(), 1);
+        assert_eq!(
+            fs::read_to_string(root.join("package.json")).unwrap(),
+            "{\n  \"name\": \"app\",\n  \"scripts\": {\n    \"build\": \"tsc\",\n    \"typecheck\": \"tsc --noEmit\"\n  }\n}\n"
+        );
+        assert!(rule.check(&context).is_empty());
+        assert_eq!(rule.fix(&context).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_existing_typecheck_equivalents_pass() {
+        assert!(TsconfigRule::is_typecheck_script("lint", "eslint . && tsc --noEmit"));
+        assert!(TsconfigRule::is_typecheck_script("check", "vue-tsc --noEmit -p app.json"));
+        assert!(TsconfigRule::is_typecheck_script("type-check", "tsc -b"));
+        assert!(!TsconfigRule::is_typecheck_script("build", "tsc"));
+        assert!(!TsconfigRule::is_typecheck_script("check", "tsc-alias --noEmit"));
+    }
+}
@@ -0,0 +1,344 @@
+use crate::rules::{Rule, RuleError};
+use crate::types::{CheckEntry, FixEntry, LintResult, RuleContext, Severity};
+use serde_json::{json, Value};
+use std::path::Path;
+
+// Check IDs
+const CHECK_RECOMMENDS_EXTENSIONS: &str = "recommends-extensions";
+
+// Fix IDs
+const FIX_MERGE_RECOMMENDATIONS: &str = "merge-recommendations";
+
+pub(crate) const ESLINT_EXTENSION: &str = "dbaeumer.vscode-eslint";
+const CSPELL_EXTENSION: &str = "streetsidesoftware.code-spell-checker";
+pub(crate) const PRETTIER_EXTENSION: &str = "esbenp.prettier-vscode";
+
+const ESLINT_CONFIGS: &[&str] = &[
+    "eslint.config.mjs",
+    "eslint.config.js",
+    "eslint.config.cjs",
+    "eslint.config.ts",
+    ".eslintrc",
+    ".eslintrc.js",
+    ".eslintrc.json",
+    ".eslintrc.yml",
+    ".eslintrc.yaml",
+];
+const CSPELL_CONFIGS: &[&str] = &[
+    "cspell.json",
+    "cspell.yaml",
+    "cspell.yml",
+    "cspell.config.js",
+    "cspell.config.cjs",
+];
+const PRETTIER_CONFIGS: &[&str] = &[
+    ".prettierrc",
+    ".prettierrc.json",
+    ".prettierrc.yml",
+    ".prettierrc.yaml",
+    ".prettierrc.js",
+    ".prettierrc.cjs",
+    "prettier.config.js",
+    "prettier.config.cjs",
+    "prettier.config.mjs",
+];
+
+/// Rule: Ensure .vscode/extensions.json recommends the tooling configured in the repo
+pub struct VscodeRecommendationsRule;
+
+impl VscodeRecommendationsRule {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn has_dependency(package_json: Option<&Value>, name: &str) -> bool {
+        package_json.is_some_and(|json| {
+            ["dependencies", "devDependencies"].iter().any(|key| {
+                json.get(key)
+                    .and_then(|d| d.as_object())
+                    .is_some_and(|deps| deps.contains_key(name))
+            })
+        })
+    }
+
+    /// Extensions to recommend, based on which tools are set up in the repo
+    pub(crate) fn expected_extensions(
+        &self,
+        repo_root: &Path,
+        context: &RuleContext,
+    ) -> Vec<&'static str> {
+        let package_json = context.package_json_value(&repo_root.join("package.json"));
+        let has_any = |files: &[&str]| files.iter().any(|f| repo_root.join(f).exists());
+
+        let mut expected = Vec::new();
+        if has_any(ESLINT_CONFIGS) || Self::has_dependency(package_json.as_ref(), "eslint") {
+            expected.push(ESLINT_EXTENSION);
+        }
+        if has_any(CSPELL_CONFIGS) || Self::has_dependency(package_json.as_ref(), "cspell") {
+            expected.push(CSPELL_EXTENSION);
+        }
+        if has_any(PRETTIER_CONFIGS) || Self::has_dependency(package_json.as_ref(), "prettier") {
+            expected.push(PRETTIER_EXTENSION);
+        }
+        expected
+    }
+
+    fn recommendations(json: &Value) -> Vec<&str> {
+        json.get("recommendations")
+            .and_then(|r| r.as_array())
+            .map(|arr| arr.iter().filter_map(|v| v.as_str()).collect())
+            .unwrap_or_default()
+    }
+
+    /// Check a single repository's extensions.json
+    fn check_repo(&self, repo_root: &Path, context: &RuleContext) -> Vec<LintResult> {
+        let expected = self.expected_extensions(repo_root, context);
+        if expected.is_empty() {
+            return Vec::new();
+        }
+
+        let extensions_path = repo_root.join(".vscode").join("extensions.json");
+        let existing = match std::fs::read_to_string(&extensions_path) {
+            Ok(content) => match serde_json::from_str::<Value>(&content) {
+                Ok(json) => json,
+                Err(e) => {
+                    return vec![LintResult::new(
+                        self.id(),
+                        CHECK_RECOMMENDS_EXTENSIONS,
+                        Severity::Error,
+                        format!("Invalid JSON in .vscode/extensions.json: {}", e),
+                        extensions_path,
+                        None,
+                        Some("Fix JSON syntax errors".into()),
+                        vec![], // Cannot auto-fix invalid JSON
+                    )];
+                }
+            },
+            Err(_) => json!({}),
+        };
+
+        let recommended = Self::recommendations(&existing);
+        let missing: Vec<&str> = expected
+            .into_iter()
+            .filter(|ext| !recommended.contains(ext))
+            .collect();
+
+        if missing.is_empty() {
+            return Vec::new();
+        }
+
+        vec![LintResult::new(
+            self.id(),
+            CHECK_RECOMMENDS_EXTENSIONS,
+            self.default_severity(),
+            format!(
+                ".vscode/extensions.json does not recommend: {}",
+                missing.join(", ")
+            ),
+            extensions_path,
+            None,
+            Some("Add the missing extensions to the 'recommendations' array".into()),
+            vec![FIX_MERGE_RECOMMENDATIONS],
+        )]
+    }
+
+    /// Add missing recommendations, keeping existing entries. Returns true if changed.
+    fn merge_recommendations(&self, existing: &mut Value, expected: &[&str]) -> bool {
+        if !existing.is_object() {
+            return false;
+        }
+        if !existing.get("recommendations").is_some_and(|r| r.is_array()) {
+            existing["recommendations"] = json!([]);
+        }
+
+        let recommendations = existing["recommendations"].as_array_mut().unwrap();
+        let mut changed = false;
+        for ext in expected {
+            if !recommendations.iter().any(|v| v.as_str() == Some(ext)) {
+                recommendations.push(Value::String(ext.to_string()));
+                changed = true;
+            }
+        }
+        changed
+    }
+}
+
+impl Default for VscodeRecommendationsRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Rule for VscodeRecommendationsRule {
+    fn id(&self) -> &'static str {
+        "vscode-recommendations"
+    }
+
+    fn name(&self) -> &'static str {
+        "VS Code Extension Recommendations"
+    }
+
+    fn description(&self) -> &'static str {
+        "Ensures .vscode/extensions.json recommends extensions for the tools configured in the repository"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn tags(&self) -> Vec<&'static str> {
+        vec!["editor"]
+    }
+
+    fn checks(&self) -> Vec<CheckEntry> {
+        vec![CheckEntry::new(
+            CHECK_RECOMMENDS_EXTENSIONS,
+            "Verify .vscode/extensions.json recommends extensions for configured tools (ESLint, CSpell, Prettier)",
+        )]
+    }
+
+    fn fixes(&self) -> Vec<FixEntry> {
+        vec![FixEntry::new(
+            FIX_MERGE_RECOMMENDATIONS,
+            "Create or merge the 'recommendations' array in .vscode/extensions.json",
+            vec![CHECK_RECOMMENDS_EXTENSIONS],
+        )]
+    }
+
+    fn check(&self, context: &RuleContext) -> Vec<LintResult> {
+        context
+            .git_repos()
+            .iter()
+            .flat_map(|repo| self.check_repo(repo, context))
+            .collect()
+    }
+
+    fn fix(&self, context: &RuleContext) -> Result<u32, RuleError> {
+        let mut fixed = 0;
+
+        for repo in context.git_repos() {
+            let expected = self.expected_extensions(&repo, context);
+            if expected.is_empty() {
+                continue;
+            }
+
+            let extensions_path = repo.join(".vscode").join("extensions.json");
+            let mut existing = if context.file_exists(&extensions_path) {
+                match serde_json::from_str::<Value>(&context.read_file(&extensions_path)?) {
+                    Ok(json) => json,
+                    Err(_) => continue, // Leave invalid files for the user to fix
+                }
+            } else {
+                json!({})
+            };
+
+            if self.merge_recommendations(&mut existing, &expected) {
+                context.write_file(&extensions_path, &serde_json::to_string_pretty(&existing)?)?;
+                fixed += 1;
+            }
+        }
+
+        Ok(fixed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    fn setup_repo(package_json: &str) -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir_all(temp_dir.path().join(".git")).unwrap();
+        fs::write(temp_dir.path().join("package.json"), package_json).unwrap();
+        temp_dir
+    }
+
+    fn create_context(root: PathBuf) -> RuleContext {
+        RuleContext::new(root, serde_json::json!({}))
+    }
+
+    #[test]
+    fn test_recommends_only_configured_tools() {
+        let temp_dir = setup_repo(r#"{"devDependencies": {"eslint": "^9.0.0"}}"#);
+        let rule = VscodeRecommendationsRule::new();
+        let results = rule.check(&create_context(temp_dir.path().to_path_buf()));
+
+        assert_eq!(results.len(), 1);
+        assert!(results[0].message.contains(ESLINT_EXTENSION));
+        assert!(!results[0].message.contains(CSPELL_EXTENSION));
+    }
+
+    #[test]
+    fn test_recommends_cspell_when_configured() {
+        let temp_dir = setup_repo(r#"{"name": "test"}"#);
+        fs::write(temp_dir.path().join("cspell.json"), "{}").unwrap();
+        let rule = VscodeRecommendationsRule::new();
+        let results = rule.check(&create_context(temp_dir.path().to_path_buf()));
+
+        assert_eq!(results.len(), 1);
+        assert!(results[0].message.contains(CSPELL_EXTENSION));
+        assert!(!results[0].message.contains(ESLINT_EXTENSION));
+    }
+
+    #[test]
+    fn test_skips_repo_without_tooling() {
+        let temp_dir = setup_repo(r#"{"name": "test"}"#);
+        let rule = VscodeRecommendationsRule::new();
+
+        assert!(rule
+            .check(&create_context(temp_dir.path().to_path_buf()))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_fix_merges_without_removing_user_entries() {
+        let temp_dir = setup_repo(r#"{"devDependencies": {"eslint": "^9.0.0", "cspell": "^8.0.0"}}"#);
+        let vscode_dir = temp_dir.path().join(".vscode");
+        fs::create_dir_all(&vscode_dir).unwrap();
+        fs::write(
+            vscode_dir.join("extensions.json"),
+            r#"{"recommendations": ["rust-lang.rust-analyzer", "dbaeumer.vscode-eslint"]}"#,
+        )
+        .unwrap();
+
+        let rule = VscodeRecommendationsRule::new();
+        let context = create_context(temp_dir.path().to_path_buf());
+        let fixed = rule.fix(&context).unwrap();
+
+        assert_eq!(fixed, 1);
+        let content: Value =
+            serde_json::from_str(&fs::read_to_string(vscode_dir.join("extensions.json")).unwrap())
+                .unwrap();
+        assert_eq!(
+            content["recommendations"],
+            json!([
+                "rust-lang.rust-analyzer",
+                "dbaeumer.vscode-eslint",
+                "streetsidesoftware.code-spell-checker"
+            ])
+        );
+        assert!(rule.check(&context).is_empty());
+    }
+
+    #[test]
+    fn test_fix_creates_extensions_json() {
+        let temp_dir = setup_repo(r#"{"devDependencies": {"cspell": "^8.0.0"}}"#);
+        let rule = VscodeRecommendationsRule::new();
+        let context = create_context(temp_dir.path().to_path_buf());
+
+        assert_eq!(rule.fix(&context).unwrap(), 1);
+
+        let content: Value = serde_json::from_str(
+            &fs::read_to_string(temp_dir.path().join(".vscode/extensions.json")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(content["recommendations"], json!([CSPELL_EXTENSION]));
+    }
+}
@@ -0,0 +1,290 @@
+use crate::jsonc;
+use crate::rules::vscode_recommendations::{
+    VscodeRecommendationsRule, ESLINT_EXTENSION, PRETTIER_EXTENSION,
+};
+use crate::rules::{Rule, RuleError};
+use crate::types::{CheckEntry, FixEntry, LintResult, RuleContext, Severity};
+use serde_json::Value;
+use std::path::Path;
+
+// Check IDs
+const CHECK_FORMAT_ON_SAVE: &str = "format-on-save";
+
+// Fix IDs
+const FIX_MERGE_FORMAT_SETTINGS: &str = "merge-format-settings";
+
+/// Rule: Ensure VS Code formats on save with the formatter the repo is set up for
+pub struct VscodeSettingsRule;
+
+impl VscodeSettingsRule {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Settings the repo's tooling calls for: Prettier formats when configured,
+    /// otherwise ESLint does. Empty when neither is set up.
+    fn expected_settings(
+        &self,
+        repo_root: &Path,
+        context: &RuleContext,
+    ) -> Vec<(&'static str, Value)> {
+        let extensions = VscodeRecommendationsRule::new().expected_extensions(repo_root, context);
+        let formatter = [PRETTIER_EXTENSION, ESLINT_EXTENSION]
+            .into_iter()
+            .find(|ext| extensions.contains(ext));
+
+        match formatter {
+            Some(formatter) => vec![
+                ("editor.formatOnSave", Value::Bool(true)),
+                ("editor.defaultFormatter", Value::from(formatter)),
+            ],
+            None => Vec::new(),
+        }
+    }
+
+    /// Expected settings whose value differs from settings.json
+    fn missing_settings(
+        existing: &Value,
+        expected: Vec<(&'static str, Value)>,
+    ) -> Vec<(&'static str, Value)> {
+        expected
+            .into_iter()
+            .filter(|(key, value)| existing.get(key) != Some(value))
+            .collect()
+    }
+
+    /// Check a single repository's settings.json
+    fn check_repo(&self, repo_root: &Path, context: &RuleContext) -> Option<LintResult> {
+        let expected = self.expected_settings(repo_root, context);
+        if expected.is_empty() {
+            return None;
+        }
+
+        let settings_path = repo_root.join(".vscode").join("settings.json");
+        let existing = match std::fs::read_to_string(&settings_path) {
+            Ok(content) => match jsonc::parse(&content) {
+                Ok(json) => json,
+                Err(e) => {
+                    return Some(LintResult::new(
+                        self.id(),
+                        CHECK_FORMAT_ON_SAVE,
+                        Severity::Error,
+                        format!("Invalid JSON in .vscode/settings.json: {}", e),
+                        settings_path,
+                        None,
+                        Some("Fix JSON syntax errors".into()),
+                        vec![], // Cannot auto-fix invalid JSON
+                    ));
+                }
+            },
+            Err(_) => Value::Object(Default::default()),
+        };
+
+        let missing = Self::missing_settings(&existing, expected);
+        if missing.is_empty() {
+            return None;
+        }
+
+        let settings: Vec<String> = missing
+            .iter()
+            .map(|(key, value)| format!("\"{}\": {}", key, value))
+            .collect();
+        Some(LintResult::new(
+            self.id(),
+            CHECK_FORMAT_ON_SAVE,
+            self.default_severity(),
+            format!(".vscode/settings.json should set {}", settings.join(", ")),
+            settings_path,
+            None,
+            Some("Add the settings so files are formatted on save".into()),
+            vec![FIX_MERGE_FORMAT_SETTINGS],
+        ))
+    }
+
+    /// Set the missing keys in settings.json, keeping other settings and comments
+    fn merge_settings(&self, repo_root: &Path, context: &RuleContext) -> Result<bool, RuleError> {
+        let expected = self.expected_settings(repo_root, context);
+        if expected.is_empty() {
+            return Ok(false);
+        }
+
+        let settings_path = repo_root.join(".vscode").join("settings.json");
+        let mut content = if context.file_exists(&settings_path) {
+            context.read_file(&settings_path)?
+        } else {
+            "{}\n".to_string()
+        };
+        let Ok(existing) = jsonc::parse(&content) else {
+            return Ok(false); // Leave invalid files for the user to fix
+        };
+
+        let missing = Self::missing_settings(&existing, expected);
+        if missing.is_empty() {
+            return Ok(false);
+        }
+        for (key, value) in missing {
+            let Some(updated) = jsonc::set_property(&content, &[key], &value) else {
+                return Ok(false);
+            };
+            content = updated;
+        }
+        context.write_file(&settings_path, &content)?;
+
+        Ok(true)
+    }
+}
+
+impl Default for VscodeSettingsRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Rule for VscodeSettingsRule {
+    fn id(&self) -> &'static str {
+        "vscode-settings"
+    }
+
+    fn name(&self) -> &'static str {
+        "VS Code Settings"
+    }
+
+    fn description(&self) -> &'static str {
+        "Ensures .vscode/settings.json formats on save with the repository's configured formatter"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn tags(&self) -> Vec<&'static str> {
+        vec!["editor"]
+    }
+
+    fn checks(&self) -> Vec<CheckEntry> {
+        vec![CheckEntry::new(
+            CHECK_FORMAT_ON_SAVE,
+            "Verify .vscode/settings.json sets editor.formatOnSave and editor.defaultFormatter (Prettier, else ESLint)",
+        )]
+    }
+
+    fn fixes(&self) -> Vec<FixEntry> {
+        vec![FixEntry::new(
+            FIX_MERGE_FORMAT_SETTINGS,
+            "Create or merge the format-on-save settings into .vscode/settings.json, keeping comments",
+            vec![CHECK_FORMAT_ON_SAVE],
+        )]
+    }
+
+    fn check(&self, context: &RuleContext) -> Vec<LintResult> {
+        context
+            .git_repos()
+            .iter()
+            .filter_map(|repo| self.check_repo(repo, context))
+            .collect()
+    }
+
+    fn fix(&self, context: &RuleContext) -> Result<u32, RuleError> {
+        let mut fixed = 0;
+
+        for repo in context.git_repos() {
+            if self.merge_settings(&repo, context)? {
+                fixed += 1;
+            }
+        }
+
+        Ok(fixed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    fn setup_repo(package_json: &str) -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir_all(temp_dir.path().join(".git")).unwrap();
+        fs::write(temp_dir.path().join("package.json"), package_json).unwrap();
+        temp_dir
+    }
+
+    fn create_context(root: PathBuf) -> RuleContext {
+        RuleContext::new(root, serde_json::json!({}))
+    }
+
+    #[test]
+    fn test_prefers_prettier_as_formatter() {
+        let temp_dir = setup_repo(r#"{"devDependencies": {"eslint": "^9.0.0"}}"#);
+        let rule = VscodeSettingsRule::new();
+        let context = create_context(temp_dir.path().to_path_buf());
+
+        let results = rule.check(&context);
+        assert_eq!(results.len(), 1);
+        assert!(results[0].message.contains(ESLINT_EXTENSION));
+
+        fs::write(temp_dir.path().join(".prettierrc"), "{}").unwrap();
+        let results = rule.check(&context);
+        assert_eq!(
+            results[0].message,
+            ".vscode/settings.json should set \"editor.formatOnSave\": true, \
+             \"editor.defaultFormatter\": \"esbenp.prettier-vscode\""
+        );
+    }
+
+    #[test]
+    fn test_skips_repo_without_formatter() {
+        let temp_dir = setup_repo(r#"{"devDependencies": {"cspell": "^8.0.0"}}"#);
+
+        assert!(VscodeSettingsRule::new()
+            .check(&create_context(temp_dir.path().to_path_buf()))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_fix_merges_keeping_user_settings_and_comments() {
+        let temp_dir = setup_repo(r#"{"devDependencies": {"prettier": "^3.0.0"}}"#);
+        let vscode_dir = temp_dir.path().join(".vscode");
+        fs::create_dir_all(&vscode_dir).unwrap();
+        fs::write(
+            vscode_dir.join("settings.json"),
+            "{\n  // Team font\n  \"editor.fontSize\": 14,\n  \"editor.formatOnSave\": false,\n}\n",
+        )
+        .unwrap();
+        let rule = VscodeSettingsRule::new();
+        let context = create_context(temp_dir.path().to_path_buf());
+
+        assert_eq!(rule.fix(&context).unwrap(), 1);
+
+        let content = fs::read_to_string(vscode_dir.join("settings.json")).unwrap();
+        assert!(content.contains("// Team font"));
+        let json = jsonc::parse(&content).unwrap();
+        assert_eq!(json["editor.fontSize"], 14);
+        assert_eq!(json["editor.formatOnSave"], true);
+        assert_eq!(json["editor.defaultFormatter"], PRETTIER_EXTENSION);
+        assert!(rule.check(&context).is_empty());
+        assert_eq!(rule.fix(&context).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_fix_creates_settings_json() {
+        let temp_dir = setup_repo(r#"{"devDependencies": {"eslint": "^9.0.0"}}"#);
+        let rule = VscodeSettingsRule::new();
+        let context = create_context(temp_dir.path().to_path_buf());
+
+        assert_eq!(rule.fix(&context).unwrap(), 1);
+
+        let json = jsonc::parse(
+            &fs::read_to_string(temp_dir.path().join(".vscode/settings.json")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(json["editor.defaultFormatter"], ESLINT_EXTENSION);
+        assert!(rule.check(&context).is_empty());
+    }
+}
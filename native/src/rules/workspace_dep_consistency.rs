@@ -0,0 +1,253 @@
+use crate::rules::workspace_membership::{
+    WorkspaceGlobs, WorkspaceMembershipRule, WORKSPACE_FILE,
+};
+use crate::rules::Rule;
+use crate::types::{CheckEntry, FixEntry, LintResult, RuleContext, Severity};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+// Check IDs
+const CHECK_DEP_VERSIONS_CONSISTENT: &str = "dep-versions-consistent";
+
+/// Dependency sections whose ranges decide what gets installed. peerDependencies
+/// are left out since they are meant to be wider than what's installed.
+const DEPENDENCY_KEYS: &[&str] = &["dependencies", "devDependencies", "optionalDependencies"];
+
+/// Rule: Ensure workspace packages agree on the versions of shared dependencies
+pub struct WorkspaceDepConsistencyRule;
+
+impl WorkspaceDepConsistencyRule {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Every dependency of the workspace members, mapped to the (relative dir, range)
+    /// pairs that declare it, sorted by dir
+    fn member_ranges(
+        &self,
+        context: &RuleContext,
+        workspace_root: &Path,
+        globs: &WorkspaceGlobs,
+    ) -> BTreeMap<String, Vec<(String, String)>> {
+        let mut ranges: BTreeMap<String, Vec<(String, String)>> = BTreeMap::new();
+
+        for path in context.package_jsons() {
+            let Some(relative) = path
+                .parent()
+                .and_then(|dir| WorkspaceMembershipRule::relative_dir(dir, workspace_root))
+            else {
+                continue;
+            };
+            if !globs.covers(&relative) {
+                continue;
+            }
+            let Some(json) = context.package_json_value(&path) else {
+                continue;
+            };
+
+            for key in DEPENDENCY_KEYS {
+                let Some(deps) = json.get(key).and_then(|d| d.as_object()) else {
+                    continue;
+                };
+                for (name, range) in deps {
+                    let Some(range) = range.as_str().map(str::trim) else {
+                        continue;
+                    };
+                    // Links to other members resolve locally, whatever the range
+                    if range.starts_with("workspace:") {
+                        continue;
+                    }
+                    let entry = (relative.clone(), range.to_string());
+                    let declared = ranges.entry(name.clone()).or_default();
+                    if !declared.contains(&entry) {
+                        declared.push(entry);
+                    }
+                }
+            }
+        }
+
+        for declared in ranges.values_mut() {
+            declared.sort();
+        }
+        ranges
+    }
+
+    fn check_workspace(&self, workspace_file: &Path, context: &RuleContext) -> Vec<LintResult> {
+        let Some(workspace_root) = workspace_file.parent() else {
+            return Vec::new();
+        };
+        let Ok(content) = std::fs::read_to_string(workspace_file) else {
+            return Vec::new();
+        };
+        // Invalid workspace files are reported by workspace-membership
+        let Ok(globs) = WorkspaceGlobs::parse(&content) else {
+            return Vec::new();
+        };
+
+        self.member_ranges(context, workspace_root, &globs)
+            .into_iter()
+            .filter(|(_, declared)| declared.iter().any(|(_, range)| *range != declared[0].1))
+            .map(|(name, declared)| {
+                let versions: Vec<String> = declared
+                    .iter()
+                    .map(|(dir, range)| format!("{} ('{}')", dir, range))
+                    .collect();
+                LintResult::new(
+                    self.id(),
+                    CHECK_DEP_VERSIONS_CONSISTENT,
+                    self.default_severity(),
+                    format!(
+                        "Workspace packages declare conflicting ranges for '{}': {}",
+                        name,
+                        versions.join(", ")
+                    ),
+                    workspace_file.to_path_buf(),
+                    None,
+                    Some(format!(
+                        "Use one range for '{}' across the workspace, e.g. with a pnpm catalog",
+                        name
+                    )),
+                    vec![], // Report-only: which range should win is a policy decision
+                )
+            })
+            .collect()
+    }
+}
+
+impl Default for WorkspaceDepConsistencyRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Rule for WorkspaceDepConsistencyRule {
+    fn id(&self) -> &'static str {
+        "workspace-dep-consistency"
+    }
+
+    fn name(&self) -> &'static str {
+        "Workspace Dependency Consistency"
+    }
+
+    fn description(&self) -> &'static str {
+        "Ensures pnpm workspace packages don't declare conflicting ranges of shared dependencies"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn tags(&self) -> Vec<&'static str> {
+        vec!["workspace"]
+    }
+
+    fn checks(&self) -> Vec<CheckEntry> {
+        vec![CheckEntry::new(
+            CHECK_DEP_VERSIONS_CONSISTENT,
+            "Verify workspace members declaring the same dependency use the same version range",
+        )]
+    }
+
+    fn fixes(&self) -> Vec<FixEntry> {
+        vec![]
+    }
+
+    fn inputs(&self) -> Vec<&'static str> {
+        vec!["**/package.json", "**/pnpm-workspace.yaml"]
+    }
+
+    fn check(&self, context: &RuleContext) -> Vec<LintResult> {
+        context
+            .package_jsons()
+            .iter()
+            .filter_map(|path| {
+                let workspace_file = path.parent()?.join(WORKSPACE_FILE);
+                workspace_file.is_file().then_some(workspace_file)
+            })
+            .flat_map(|workspace_file| self.check_workspace(&workspace_file, context))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn setup_monorepo(packages: &[(&str, serde_json::Value)]) -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(root.join(WORKSPACE_FILE), "packages:\n  - 'packages/*'\n").unwrap();
+        fs::write(root.join("package.json"), r#"{"name": "monorepo", "private": true}"#).unwrap();
+        for (dir, json) in packages {
+            let package_dir = root.join("packages").join(dir);
+            fs::create_dir_all(&package_dir).unwrap();
+            fs::write(package_dir.join("package.json"), json.to_string()).unwrap();
+        }
+        temp_dir
+    }
+
+    fn check(temp_dir: &TempDir) -> Vec<LintResult> {
+        let context =
+            RuleContext::new(temp_dir.path().to_path_buf(), serde_json::Value::Null);
+        WorkspaceDepConsistencyRule::new().check(&context)
+    }
+
+    #[test]
+    fn test_flags_conflicting_react_ranges() {
+        let temp_dir = setup_monorepo(&[
+            (
+                "web",
+                serde_json::json!({"name": "web", "dependencies": {"react": "^18.2.0"}}),
+            ),
+            (
+                "admin",
+                serde_json::json!({
+                    "name": "admin",
+                    "dependencies": {"react": "^17.0.2", "web": "workspace:*"}
+                }),
+            ),
+            (
+                "docs",
+                serde_json::json!({"name": "docs", "devDependencies": {"web": "workspace:^"}}),
+            ),
+        ]);
+
+        let results = check(&temp_dir);
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].check_id, CHECK_DEP_VERSIONS_CONSISTENT);
+        assert_eq!(
+            results[0].message,
+            "Workspace packages declare conflicting ranges for 'react': \
+             packages/admin ('^17.0.2'), packages/web ('^18.2.0')"
+        );
+        assert!(results[0].path.ends_with(WORKSPACE_FILE));
+        assert!(results[0].fixable_by.is_empty());
+    }
+
+    #[test]
+    fn test_accepts_matching_ranges_and_skips_non_workspaces() {
+        let temp_dir = setup_monorepo(&[
+            (
+                "web",
+                serde_json::json!({"name": "web", "dependencies": {"react": "^18.2.0"}}),
+            ),
+            (
+                "admin",
+                serde_json::json!({"name": "admin", "devDependencies": {"react": "^18.2.0"}}),
+            ),
+        ]);
+        assert!(check(&temp_dir).is_empty());
+
+        fs::write(
+            temp_dir.path().join("packages/admin/package.json"),
+            r#"{"name": "admin", "dependencies": {"react": "^17.0.2"}}"#,
+        )
+        .unwrap();
+        assert_eq!(check(&temp_dir).len(), 1);
+        fs::remove_file(temp_dir.path().join(WORKSPACE_FILE)).unwrap();
+        assert!(check(&temp_dir).is_empty());
+    }
+}
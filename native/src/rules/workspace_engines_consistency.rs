@@ -0,0 +1,210 @@
+use crate::rules::workspace_membership::{
+    WorkspaceGlobs, WorkspaceMembershipRule, WORKSPACE_FILE,
+};
+use crate::rules::Rule;
+use crate::types::{CheckEntry, FixEntry, LintResult, RuleContext, Severity};
+use std::cmp::Reverse;
+use std::path::Path;
+
+// Check IDs
+const CHECK_ENGINES_NODE_CONSISTENT: &str = "engines-node-consistent";
+
+/// Rule: Ensure workspace packages agree on the Node.js versions they support
+pub struct WorkspaceEnginesConsistencyRule;
+
+impl WorkspaceEnginesConsistencyRule {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// `engines.node` of each workspace member as (relative dir, range), sorted by dir
+    fn member_engines(
+        &self,
+        context: &RuleContext,
+        workspace_root: &Path,
+        globs: &WorkspaceGlobs,
+    ) -> Vec<(String, String)> {
+        let mut engines: Vec<(String, String)> = context
+            .package_jsons()
+            .into_iter()
+            .filter_map(|path| {
+                let relative =
+                    WorkspaceMembershipRule::relative_dir(path.parent()?, workspace_root)?;
+                if !globs.covers(&relative) {
+                    return None;
+                }
+                let json = context.package_json_value(&path)?;
+                let range = json.get("engines")?.get("node")?.as_str()?.trim().to_string();
+                Some((relative, range))
+            })
+            .collect();
+        engines.sort();
+        engines
+    }
+
+    fn check_workspace(&self, workspace_file: &Path, context: &RuleContext) -> Option<LintResult> {
+        let workspace_root = workspace_file.parent()?;
+        let content = std::fs::read_to_string(workspace_file).ok()?;
+        // Invalid workspace files are reported by workspace-membership
+        let globs = WorkspaceGlobs::parse(&content).ok()?;
+        let engines = self.member_engines(context, workspace_root, &globs);
+
+        // Ranges in order of how many packages use them; ties keep first-seen order
+        let mut ranges: Vec<(&str, usize)> = Vec::new();
+        for (_, range) in &engines {
+            match ranges.iter_mut().find(|(r, _)| r == range) {
+                Some((_, count)) => *count += 1,
+                None => ranges.push((range, 1)),
+            }
+        }
+        if ranges.len() < 2 {
+            return None;
+        }
+        ranges.sort_by_key(|&(_, count)| Reverse(count));
+        let common = ranges[0].0;
+
+        let outliers: Vec<String> = engines
+            .iter()
+            .filter(|(_, range)| range != common)
+            .map(|(dir, range)| format!("{} ('{}')", dir, range))
+            .collect();
+
+        Some(LintResult::new(
+            self.id(),
+            CHECK_ENGINES_NODE_CONSISTENT,
+            self.default_severity(),
+            format!(
+                "Workspace packages disagree on engines.node: {} of {} use '{}', outliers: {}",
+                ranges[0].1,
+                engines.len(),
+                common,
+                outliers.join(", ")
+            ),
+            workspace_file.to_path_buf(),
+            None,
+            Some("Declare the same engines.node range in every workspace package".into()),
+            vec![], // Report-only: which range should win is a policy decision
+        ))
+    }
+}
+
+impl Default for WorkspaceEnginesConsistencyRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Rule for WorkspaceEnginesConsistencyRule {
+    fn id(&self) -> &'static str {
+        "workspace-engines-consistency"
+    }
+
+    fn name(&self) -> &'static str {
+        "Workspace Engines Consistency"
+    }
+
+    fn description(&self) -> &'static str {
+        "Ensures pnpm workspace packages declare consistent engines.node ranges"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn tags(&self) -> Vec<&'static str> {
+        vec!["workspace"]
+    }
+
+    fn checks(&self) -> Vec<CheckEntry> {
+        vec![CheckEntry::new(
+            CHECK_ENGINES_NODE_CONSISTENT,
+            "Verify workspace members that declare engines.node all use the same range",
+        )]
+    }
+
+    fn fixes(&self) -> Vec<FixEntry> {
+        vec![]
+    }
+
+    fn inputs(&self) -> Vec<&'static str> {
+        vec!["**/package.json", "**/pnpm-workspace.yaml"]
+    }
+
+    fn check(&self, context: &RuleContext) -> Vec<LintResult> {
+        context
+            .package_jsons()
+            .iter()
+            .filter_map(|path| {
+                let workspace_file = path.parent()?.join(WORKSPACE_FILE);
+                workspace_file.is_file().then_some(workspace_file)
+            })
+            .filter_map(|workspace_file| self.check_workspace(&workspace_file, context))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn setup_monorepo(packages: &[(&str, Option<&str>)]) -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(root.join(WORKSPACE_FILE), "packages:\n  - 'packages/*'\n").unwrap();
+        fs::write(root.join("package.json"), r#"{"name": "monorepo", "private": true}"#).unwrap();
+        for (dir, node) in packages {
+            let package_dir = root.join("packages").join(dir);
+            fs::create_dir_all(&package_dir).unwrap();
+            let json = match node {
+                Some(range) => serde_json::json!({"name": dir, "engines": {"node": range}}),
+                None => serde_json::json!({"name": dir}),
+            };
+            fs::write(package_dir.join("package.json"), json.to_string()).unwrap();
+        }
+        temp_dir
+    }
+
+    fn check(temp_dir: &TempDir) -> Vec<LintResult> {
+        let context =
+            RuleContext::new(temp_dir.path().to_path_buf(), serde_json::Value::Null);
+        WorkspaceEnginesConsistencyRule::new().check(&context)
+    }
+
+    #[test]
+    fn test_flags_conflicting_node_ranges() {
+        let temp_dir = setup_monorepo(&[
+            ("api", Some(">=20")),
+            ("cli", Some(">=18")),
+            ("web", Some(">=20")),
+        ]);
+
+        let results = check(&temp_dir);
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].check_id, CHECK_ENGINES_NODE_CONSISTENT);
+        assert_eq!(
+            results[0].message,
+            "Workspace packages disagree on engines.node: 2 of 3 use '>=20', outliers: packages/cli ('>=18')"
+        );
+        assert!(results[0].path.ends_with(WORKSPACE_FILE));
+        assert!(results[0].fixable_by.is_empty());
+    }
+
+    #[test]
+    fn test_accepts_matching_or_undeclared_ranges() {
+        let temp_dir =
+            setup_monorepo(&[("api", Some(">=20")), ("cli", None), ("web", Some(">=20"))]);
+
+        assert!(check(&temp_dir).is_empty());
+    }
+
+    #[test]
+    fn test_skips_repos_without_workspace_root() {
+        let temp_dir = setup_monorepo(&[("api", Some(">=20")), ("cli", Some(">=18"))]);
+        fs::remove_file(temp_dir.path().join(WORKSPACE_FILE)).unwrap();
+
+        assert!(check(&temp_dir).is_empty());
+    }
+}
@@ -0,0 +1,466 @@
+use crate::rules::{Rule, RuleError};
+use crate::types::{CheckEntry, FixEntry, LintResult, RuleContext, Severity};
+use crate::walk;
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+// Check IDs
+const CHECK_PACKAGE_IN_WORKSPACE: &str = "package-in-workspace";
+const CHECK_ROOT_PRIVATE: &str = "workspace-root-private";
+
+// Fix IDs
+const FIX_ADD_WORKSPACE_GLOB: &str = "add-workspace-glob";
+const FIX_SET_ROOT_PRIVATE: &str = "set-workspace-root-private";
+
+pub(crate) const WORKSPACE_FILE: &str = "pnpm-workspace.yaml";
+
+/// The `packages:` globs of a pnpm-workspace.yaml, split into includes and `!` excludes
+pub(crate) struct WorkspaceGlobs {
+    include: GlobSet,
+    exclude: GlobSet,
+}
+
+impl WorkspaceGlobs {
+    pub(crate) fn parse(content: &str) -> Result<Self, String> {
+        let yaml: serde_yaml::Value = serde_yaml::from_str(content).map_err(|e| e.to_string())?;
+        let patterns: Vec<&str> = yaml
+            .get("packages")
+            .and_then(|p| p.as_sequence())
+            .map(|seq| seq.iter().filter_map(|v| v.as_str()).collect())
+            .unwrap_or_default();
+
+        let mut include = GlobSetBuilder::new();
+        let mut exclude = GlobSetBuilder::new();
+        for pattern in patterns {
+            let (builder, pattern) = match pattern.strip_prefix('!') {
+                Some(negated) => (&mut exclude, negated),
+                None => (&mut include, pattern),
+            };
+            let pattern = pattern.trim_start_matches("./").trim_end_matches('/');
+            let glob = GlobBuilder::new(pattern)
+                .literal_separator(true)
+                .build()
+                .map_err(|e| e.to_string())?;
+            builder.add(glob);
+        }
+
+        Ok(Self {
+            include: include.build().map_err(|e| e.to_string())?,
+            exclude: exclude.build().map_err(|e| e.to_string())?,
+        })
+    }
+
+    /// Whether a package directory (relative to the workspace root) is a member
+    pub(crate) fn covers(&self, relative_dir: &str) -> bool {
+        self.include.is_match(relative_dir) && !self.exclude.is_match(relative_dir)
+    }
+}
+
+/// Rule: Ensure every package in a pnpm monorepo is matched by the workspace globs
+pub struct WorkspaceMembershipRule;
+
+impl WorkspaceMembershipRule {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn find_workspace_files(&self, context: &RuleContext) -> Vec<PathBuf> {
+        walk::entries(&context.root, context.follow_symlinks, &["node_modules", ".git"])
+            .into_iter()
+            .filter(|e| e.file_name() == WORKSPACE_FILE && e.path().is_file())
+            .map(|e| e.into_path())
+            .collect()
+    }
+
+    /// Relative directory as a '/'-separated string, matching how pnpm globs are written
+    pub(crate) fn relative_dir(dir: &Path, workspace_root: &Path) -> Option<String> {
+        let relative = dir.strip_prefix(workspace_root).ok()?;
+        let parts: Vec<_> = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect();
+        (!parts.is_empty()).then(|| parts.join("/"))
+    }
+
+    /// Package directories under the workspace root not covered by its globs.
+    /// Packages nested inside a member (test fixtures, examples) are left alone.
+    fn orphan_packages(
+        &self,
+        context: &RuleContext,
+        workspace_root: &Path,
+        globs: &WorkspaceGlobs,
+    ) -> Vec<(PathBuf, String)> {
+        let mut package_dirs: Vec<(PathBuf, String)> =
+            walk::find_package_jsons(workspace_root, context.follow_symlinks)
+                .into_iter()
+                .filter(|p| !context.is_excluded_package(p))
+                .filter_map(|p| {
+                    let dir = p.parent()?.to_path_buf();
+                    let relative = Self::relative_dir(&dir, workspace_root)?;
+                    Some((dir, relative))
+                })
+                .collect();
+        package_dirs.sort_by(|a, b| a.1.cmp(&b.1));
+
+        let members: Vec<&str> = package_dirs
+            .iter()
+            .filter(|(_, rel)| globs.covers(rel))
+            .map(|(_, rel)| rel.as_str())
+            .collect();
+        let inside_member = |rel: &str| {
+            members
+                .iter()
+                .any(|m| rel.strip_prefix(m).is_some_and(|rest| rest.starts_with('/')))
+        };
+
+        package_dirs
+            .iter()
+            .filter(|(_, rel)| !globs.covers(rel) && !inside_member(rel))
+            .cloned()
+            .collect()
+    }
+
+    /// The workspace root's package.json, if it exists and isn't `"private": true`
+    fn public_root_package(workspace_root: &Path, context: &RuleContext) -> Option<PathBuf> {
+        let package_json = workspace_root.join("package.json");
+        let json = context.package_json_value(&package_json)?;
+        (json.get("private") != Some(&Value::Bool(true))).then_some(package_json)
+    }
+
+    /// Glob that would cover a package: its siblings (`apps/*`), or the
+    /// directory itself for packages at the top level of the workspace
+    fn covering_glob(relative_dir: &str) -> String {
+        match relative_dir.rsplit_once('/') {
+            Some((parent, _)) => format!("{}/*", parent),
+            None => relative_dir.to_string(),
+        }
+    }
+
+    /// Append globs to the `packages:` list, editing the text so comments and
+    /// quoting survive. Returns None when the list isn't in block style.
+    fn append_globs(content: &str, globs: &[String]) -> Option<String> {
+        let lines: Vec<&str> = content.lines().collect();
+        let Some(key_index) = lines.iter().position(|l| l.trim_end() == "packages:") else {
+            if lines.iter().any(|l| l.starts_with("packages:")) {
+                return None; // Flow-style list, e.g. `packages: [a, b]`
+            }
+            let mut updated = content.to_string();
+            if !updated.is_empty() && !updated.ends_with('\n') {
+                updated.push('\n');
+            }
+            updated.push_str("packages:\n");
+            for glob in globs {
+                updated.push_str(&format!("  - '{}'\n", glob));
+            }
+            return Some(updated);
+        };
+
+        // Find the last item of the block list and copy its indentation and quoting
+        let mut last_item = None;
+        for (i, line) in lines.iter().enumerate().skip(key_index + 1) {
+            let trimmed = line.trim_start();
+            if trimmed.starts_with("- ") {
+                last_item = Some(i);
+            } else if !trimmed.is_empty() && !trimmed.starts_with('#') {
+                break;
+            }
+        }
+
+        let (insert_at, indent, quote) = match last_item {
+            Some(i) => {
+                let line = lines[i];
+                let indent = &line[..line.len() - line.trim_start().len()];
+                let value = line.trim_start()[2..].trim_start();
+                let quote = match value.chars().next() {
+                    Some(q @ ('\'' | '"')) => q.to_string(),
+                    _ => String::new(),
+                };
+                (i + 1, indent.to_string(), quote)
+            }
+            None => (key_index + 1, "  ".to_string(), "'".to_string()),
+        };
+
+        let mut updated: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+        for (offset, glob) in globs.iter().enumerate() {
+            updated.insert(
+                insert_at + offset,
+                format!("{}- {}{}{}", indent, quote, glob, quote),
+            );
+        }
+        Some(updated.join("\n") + "\n")
+    }
+}
+
+impl Default for WorkspaceMembershipRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Rule for WorkspaceMembershipRule {
+    fn id(&self) -> &'static str {
+        "workspace-membership"
+    }
+
+    fn name(&self) -> &'static str {
+        "Workspace Membership"
+    }
+
+    fn description(&self) -> &'static str {
+        "Ensures every package in a pnpm monorepo is matched by the pnpm-workspace.yaml globs"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn tags(&self) -> Vec<&'static str> {
+        vec!["package-manager", "workspace"]
+    }
+
+    fn checks(&self) -> Vec<CheckEntry> {
+        vec![
+            CheckEntry::new(
+                CHECK_PACKAGE_IN_WORKSPACE,
+                "Verify each nested package.json directory is covered by a pnpm-workspace.yaml glob",
+            ),
+            CheckEntry::new(
+                CHECK_ROOT_PRIVATE,
+                "Verify the package.json next to pnpm-workspace.yaml sets \"private\": true",
+            ),
+        ]
+    }
+
+    fn fixes(&self) -> Vec<FixEntry> {
+        vec![
+            FixEntry::new(
+                FIX_ADD_WORKSPACE_GLOB,
+                "Append a glob covering orphan packages to pnpm-workspace.yaml",
+                vec![CHECK_PACKAGE_IN_WORKSPACE],
+            ),
+            FixEntry::new(
+                FIX_SET_ROOT_PRIVATE,
+                "Set \"private\": true in the workspace root package.json",
+                vec![CHECK_ROOT_PRIVATE],
+            ),
+        ]
+    }
+
+    fn check(&self, context: &RuleContext) -> Vec<LintResult> {
+        let mut results = Vec::new();
+
+        for workspace_file in self.find_workspace_files(context) {
+            let workspace_root = workspace_file.parent().unwrap_or(Path::new("."));
+            if let Some(package_json) = Self::public_root_package(workspace_root, context) {
+                results.push(LintResult::new(
+                    self.id(),
+                    CHECK_ROOT_PRIVATE,
+                    self.default_severity(),
+                    "Workspace root package.json is not marked \"private\": true".into(),
+                    package_json,
+                    None,
+                    Some("Set \"private\": true so the workspace root can't be published".into()),
+                    vec![FIX_SET_ROOT_PRIVATE],
+                ));
+            }
+
+            let globs = match std::fs::read_to_string(&workspace_file)
+                .map_err(|e| e.to_string())
+                .and_then(|content| WorkspaceGlobs::parse(&content))
+            {
+                Ok(globs) => globs,
+                Err(e) => {
+                    results.push(LintResult::new(
+                        self.id(),
+                        CHECK_PACKAGE_IN_WORKSPACE,
+                        Severity::Error,
+                        format!("Cannot read {}: {}", WORKSPACE_FILE, e),
+                        workspace_file.clone(),
+                        None,
+                        Some("Fix the YAML syntax and package globs".into()),
+                        vec![], // Cannot auto-fix invalid YAML
+                    ));
+                    continue;
+                }
+            };
+
+            for (dir, relative) in self.orphan_packages(context, workspace_root, &globs) {
+                results.push(LintResult::new(
+                    self.id(),
+                    CHECK_PACKAGE_IN_WORKSPACE,
+                    self.default_severity(),
+                    format!(
+                        "Package '{}' is not matched by any {} glob",
+                        relative, WORKSPACE_FILE
+                    ),
+                    dir.join("package.json"),
+                    None,
+                    Some(format!(
+                        "Add '{}' to the packages list in {}",
+                        Self::covering_glob(&relative),
+                        WORKSPACE_FILE
+                    )),
+                    vec![FIX_ADD_WORKSPACE_GLOB],
+                ));
+            }
+        }
+
+        results
+    }
+
+    fn fix(&self, context: &RuleContext) -> Result<u32, RuleError> {
+        let mut fixed = 0;
+
+        for workspace_file in self.find_workspace_files(context) {
+            let workspace_root = workspace_file.parent().unwrap_or(Path::new("."));
+            if let Some(package_json) = Self::public_root_package(workspace_root, context) {
+                let mut json: Value = serde_json::from_str(&context.read_file(&package_json)?)?;
+                json["private"] = Value::Bool(true);
+                context.write_file(&package_json, &serde_json::to_string_pretty(&json)?)?;
+                fixed += 1;
+            }
+
+            let content = context.read_file(&workspace_file)?;
+            let Ok(globs) = WorkspaceGlobs::parse(&content) else {
+                continue; // Leave invalid files for the user to fix
+            };
+
+            let mut new_globs: Vec<String> = Vec::new();
+            for (_, relative) in self.orphan_packages(context, workspace_root, &globs) {
+                let glob = Self::covering_glob(&relative);
+                if !new_globs.contains(&glob) {
+                    new_globs.push(glob);
+                }
+            }
+            if new_globs.is_empty() {
+                continue;
+            }
+
+            if let Some(updated) = Self::append_globs(&content, &new_globs) {
+                context.write_file(&workspace_file, &updated)?;
+                fixed += 1;
+            }
+        }
+
+        Ok(fixed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn setup_monorepo(workspace_yaml: &str, packages: &[&str]) -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(root.join("package.json"), r#"{"name": "root", "private": true}"#).unwrap();
+        fs::write(root.join(WORKSPACE_FILE), workspace_yaml).unwrap();
+        for package in packages {
+            let dir = root.join(package);
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(dir.join("package.json"), r#"{"name": "pkg"}"#).unwrap();
+        }
+        temp_dir
+    }
+
+    fn create_context(root: PathBuf) -> RuleContext {
+        RuleContext::new(root, serde_json::json!({}))
+    }
+
+    #[test]
+    fn test_flags_and_fixes_public_workspace_root() {
+        let temp_dir = setup_monorepo("packages:\n  - 'packages/*'\n", &["packages/a"]);
+        let root = temp_dir.path();
+        fs::write(root.join("package.json"), r#"{"name": "root", "private": false}"#).unwrap();
+        let rule = WorkspaceMembershipRule::new();
+        let context = create_context(root.to_path_buf());
+
+        // Only the root next to pnpm-workspace.yaml is checked, not its members
+        let results = rule.check(&context);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].check_id, CHECK_ROOT_PRIVATE);
+        assert_eq!(results[0].path, root.join("package.json"));
+        assert_eq!(results[0].fixable_by, vec![FIX_SET_ROOT_PRIVATE]);
+
+        assert_eq!(rule.fix(&context).unwrap(), 1);
+        let json: Value =
+            serde_json::from_str(&fs::read_to_string(root.join("package.json")).unwrap()).unwrap();
+        assert_eq!(json["private"], true);
+        assert_eq!(json["name"], "root");
+        assert!(rule.check(&context).is_empty());
+    }
+
+    #[test]
+    fn test_reports_orphan_package() {
+        let temp_dir = setup_monorepo(
+            "packages:\n  - 'packages/*'\n",
+            &["packages/core", "apps/web"],
+        );
+        let rule = WorkspaceMembershipRule::new();
+        let results = rule.check(&create_context(temp_dir.path().to_path_buf()));
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].check_id, CHECK_PACKAGE_IN_WORKSPACE);
+        assert!(results[0].message.contains("apps/web"));
+        assert_eq!(results[0].severity, "warning");
+    }
+
+    #[test]
+    fn test_single_star_does_not_cross_directories() {
+        let temp_dir = setup_monorepo("packages:\n  - 'packages/*'\n", &["packages/group/lib"]);
+        let rule = WorkspaceMembershipRule::new();
+
+        assert_eq!(
+            rule.check(&create_context(temp_dir.path().to_path_buf())).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_respects_negated_globs_and_nested_fixtures() {
+        let temp_dir = setup_monorepo(
+            "packages:\n  - 'packages/**'\n  - '!**/test/**'\n",
+            &["packages/core", "packages/core/fixtures/app", "packages/test/helper"],
+        );
+        let rule = WorkspaceMembershipRule::new();
+        let results = rule.check(&create_context(temp_dir.path().to_path_buf()));
+
+        // The excluded package is reported; the fixture inside a member is not
+        assert_eq!(results.len(), 1);
+        assert!(results[0].message.contains("packages/test/helper"));
+    }
+
+    #[test]
+    fn test_fix_appends_covering_glob() {
+        let temp_dir = setup_monorepo(
+            "# workspace\npackages:\n  - \"packages/*\"\n\ncatalog:\n  react: ^18.0.0\n",
+            &["packages/core", "apps/web", "apps/docs"],
+        );
+        let root = temp_dir.path().to_path_buf();
+        let rule = WorkspaceMembershipRule::new();
+        let context = create_context(root.clone());
+
+        assert_eq!(rule.fix(&context).unwrap(), 1);
+        assert_eq!(
+            fs::read_to_string(root.join(WORKSPACE_FILE)).unwrap(),
+            "# workspace\npackages:\n  - \"packages/*\"\n  - \"apps/*\"\n\ncatalog:\n  react: ^18.0.0\n"
+        );
+        assert!(rule.check(&context).is_empty());
+    }
+
+    #[test]
+    fn test_ignores_repos_without_workspace_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path().join("apps/web");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("package.json"), "{}").unwrap();
+
+        let rule = WorkspaceMembershipRule::new();
+        assert!(rule
+            .check(&create_context(temp_dir.path().to_path_buf()))
+            .is_empty());
+    }
+}
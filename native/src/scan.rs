@@ -0,0 +1,358 @@
+//! One-time discovery of the files most rules look at.
+//!
+//! Without a scan, every rule walks the tree and reads each package.json on its
+//! own. The engine instead builds a `WorkspaceScan` once per check pass: a single
+//! walk finds package.json files and git repositories, and every package.json is
+//! read and parsed up front. Rules see the result through `RuleContext`, and a
+//! package.json that can't be parsed is reported once by the engine rather than
+//! by every rule that reads it. When the root is a single package, a cheaper
+//! walk that skips node_modules and .git internals is used instead, falling back
+//! to the full walk as soon as a nested package.json turns up.
+//!
+//! `WorkspaceInfo` does the same for facts several rules derive from the tree,
+//! such as the installed Husky version, so they are detected once per run.
+
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Filesystem entries a scan walked over, as a measure of its cost
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct WalkCounts {
+    pub directories: u64,
+    pub files: u64,
+}
+
+impl WalkCounts {
+    fn count(&mut self, is_dir: bool) {
+        if is_dir {
+            self.directories += 1;
+        } else {
+            self.files += 1;
+        }
+    }
+}
+
+/// Files discovered under a scan root, with every package.json pre-parsed
+pub struct WorkspaceScan {
+    package_jsons: Vec<PathBuf>,
+    git_repos: Vec<PathBuf>,
+    package_json_values: HashMap<PathBuf, Result<Value, String>>,
+    counts: WalkCounts,
+}
+
+impl WorkspaceScan {
+    pub fn new(root: &Path, follow_symlinks: bool) -> Self {
+        // An abandoned fast walk still counts towards the cost
+        let mut counts = WalkCounts::default();
+        let (package_jsons, git_repos) = Self::single_package(root, follow_symlinks, &mut counts)
+            .unwrap_or_else(|| Self::full_walk(root, follow_symlinks, &mut counts));
+        Self::from_files(package_jsons, git_repos, counts)
+    }
+
+    /// Scan with the full walk even when the root is a single package
+    #[cfg(test)]
+    fn new_full_walk(root: &Path, follow_symlinks: bool) -> Self {
+        let mut counts = WalkCounts::default();
+        let (package_jsons, git_repos) = Self::full_walk(root, follow_symlinks, &mut counts);
+        Self::from_files(package_jsons, git_repos, counts)
+    }
+
+    /// Walk every entry under the root, including node_modules and .git internals
+    fn full_walk(
+        root: &Path,
+        follow_symlinks: bool,
+        counts: &mut WalkCounts,
+    ) -> (Vec<PathBuf>, Vec<PathBuf>) {
+        let mut package_jsons = Vec::new();
+        let mut git_repos = Vec::new();
+
+        for entry in crate::walk::entries(root, follow_symlinks, &[]) {
+            counts.count(entry.file_type().is_dir());
+            let path = entry.path();
+            if entry.file_name() == ".git" && path.is_dir() {
+                git_repos.extend(path.parent().map(Path::to_path_buf));
+            } else if entry.file_name() == "package.json"
+                && path.is_file()
+                && !Self::in_node_modules(path, root)
+            {
+                package_jsons.push(entry.into_path());
+            }
+        }
+
+        (package_jsons, git_repos)
+    }
+
+    /// Fast path for a root that is one package: stream the tree without
+    /// descending into node_modules or .git, and give up at the first nested
+    /// package.json. Returns None when the full walk is needed. Git repositories
+    /// inside node_modules are installed dependencies and aren't reported.
+    fn single_package(
+        root: &Path,
+        follow_symlinks: bool,
+        counts: &mut WalkCounts,
+    ) -> Option<(Vec<PathBuf>, Vec<PathBuf>)> {
+        let root_package_json = root.join("package.json");
+        // Symlinked directories need the full walk's loop protection
+        if follow_symlinks || !root_package_json.is_file() {
+            return None;
+        }
+
+        let mut git_repos = Vec::new();
+        let mut walker = WalkDir::new(root).into_iter();
+        while let Some(entry) = walker.next() {
+            let Ok(entry) = entry else {
+                continue;
+            };
+            counts.count(entry.file_type().is_dir());
+            if entry.depth() == 0 {
+                continue;
+            }
+            let path = entry.path();
+            if entry.file_name() == ".git" && path.is_dir() {
+                git_repos.extend(path.parent().map(Path::to_path_buf));
+                walker.skip_current_dir();
+            } else if entry.file_name() == "node_modules" && entry.file_type().is_dir() {
+                walker.skip_current_dir();
+            } else if entry.file_name() == "package.json" && entry.depth() > 1 && path.is_file() {
+                return None;
+            }
+        }
+
+        Some((vec![root_package_json], git_repos))
+    }
+
+    fn from_files(package_jsons: Vec<PathBuf>, git_repos: Vec<PathBuf>, counts: WalkCounts) -> Self {
+        let package_json_values = package_jsons
+            .iter()
+            .map(|path| (path.clone(), read_package_json(path)))
+            .collect();
+
+        Self {
+            package_jsons,
+            git_repos,
+            package_json_values,
+            counts,
+        }
+    }
+
+    fn in_node_modules(path: &Path, root: &Path) -> bool {
+        path.strip_prefix(root)
+            .unwrap_or(path)
+            .components()
+            .any(|c| c.as_os_str() == "node_modules")
+    }
+
+    /// All package.json files outside node_modules
+    pub fn package_jsons(&self) -> &[PathBuf] {
+        &self.package_jsons
+    }
+
+    /// Roots of all git repositories
+    pub fn git_repos(&self) -> &[PathBuf] {
+        &self.git_repos
+    }
+
+    /// Directories and files the walk went through, including the root
+    pub fn walk_counts(&self) -> WalkCounts {
+        self.counts
+    }
+
+    /// The parsed content of a scanned package.json, or the read/parse error.
+    /// None if the path wasn't part of the scan.
+    pub fn package_json(&self, path: &Path) -> Option<&Result<Value, String>> {
+        self.package_json_values.get(path)
+    }
+
+    /// package.json files that couldn't be read or parsed, with the error, sorted by path
+    pub fn invalid_package_jsons(&self) -> Vec<(&Path, &str)> {
+        let mut invalid: Vec<(&Path, &str)> = self
+            .package_json_values
+            .iter()
+            .filter_map(|(path, value)| value.as_ref().err().map(|e| (path.as_path(), e.as_str())))
+            .collect();
+        invalid.sort();
+        invalid
+    }
+}
+
+/// Lockfiles and the package manager each one belongs to, in detection order
+const LOCKFILES: &[(&str, &str)] = &[
+    ("pnpm-lock.yaml", "pnpm"),
+    ("yarn.lock", "yarn"),
+    ("package-lock.json", "npm"),
+    ("bun.lockb", "bun"),
+];
+
+/// Facts about the scanned workspace that rules share instead of re-detecting
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkspaceInfo {
+    /// The scan root the facts were detected for
+    pub root: PathBuf,
+    /// Nearest directory at or above the root holding pnpm-workspace.yaml, else the root
+    pub workspace_root: PathBuf,
+    /// Package manager named by `packageManager` in the workspace root package.json,
+    /// or implied by the lockfile there
+    pub package_manager: Option<String>,
+    /// Husky major version installed or declared at the root
+    pub husky_version: Option<u64>,
+}
+
+impl WorkspaceInfo {
+    pub fn detect(root: &Path) -> Self {
+        let workspace_root = root
+            .ancestors()
+            .find(|dir| dir.join("pnpm-workspace.yaml").is_file())
+            .unwrap_or(root)
+            .to_path_buf();
+
+        Self {
+            root: root.to_path_buf(),
+            package_manager: Self::detect_package_manager(&workspace_root),
+            husky_version: crate::husky::detect_major_version(root),
+            workspace_root,
+        }
+    }
+
+    fn detect_package_manager(workspace_root: &Path) -> Option<String> {
+        let declared = std::fs::read_to_string(workspace_root.join("package.json"))
+            .ok()
+            .and_then(|content| serde_json::from_str::<Value>(&content).ok())
+            .and_then(|json| {
+                let spec = json.get("packageManager")?.as_str()?.to_string();
+                // "pnpm@9.1.0+sha512..." names the manager before the '@'
+                spec.split('@').next().filter(|name| !name.is_empty()).map(String::from)
+            });
+        declared.or_else(|| {
+            LOCKFILES
+                .iter()
+                .find(|(lockfile, _)| workspace_root.join(lockfile).is_file())
+                .map(|(_, manager)| manager.to_string())
+        })
+    }
+
+    /// Husky major version for a project directory, reusing the detected value
+    /// for the root and detecting other directories on demand
+    pub fn husky_version(&self, project_dir: &Path) -> Option<u64> {
+        if project_dir == self.root {
+            self.husky_version
+        } else {
+            crate::husky::detect_major_version(project_dir)
+        }
+    }
+}
+
+#[cfg(test)]
+thread_local! {
+    /// Number of package.json files read from disk on this thread
+    pub static PACKAGE_JSON_READS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+/// Read and parse a package.json, describing any failure
+pub fn read_package_json(path: &Path) -> Result<Value, String> {
+    #[cfg(test)]
+    PACKAGE_JSON_READS.with(|reads| reads.set(reads.get() + 1));
+
+    let content =
+        std::fs::read_to_string(path).map_err(|e| format!("Cannot read package.json: {}", e))?;
+    serde_json::from_str(&content).map_err(|e| format!("Invalid JSON in package.json: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_scan_finds_packages_and_repos_in_one_walk() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join(".git")).unwrap();
+        fs::write(root.join("package.json"), r#"{"name": "root"}"#).unwrap();
+        fs::create_dir_all(root.join("node_modules/dep")).unwrap();
+        fs::write(root.join("node_modules/dep/package.json"), "{}").unwrap();
+        fs::create_dir_all(root.join("broken")).unwrap();
+        fs::write(root.join("broken/package.json"), "{ nope").unwrap();
+
+        let scan = WorkspaceScan::new(root, false);
+
+        assert_eq!(scan.git_repos(), [root.to_path_buf()]);
+        assert_eq!(scan.package_jsons().len(), 2);
+        assert!(scan.package_json(&root.join("package.json")).unwrap().is_ok());
+        let invalid = scan.invalid_package_jsons();
+        assert_eq!(invalid.len(), 1);
+        assert!(invalid[0].0.ends_with("broken/package.json"));
+        assert!(invalid[0].1.starts_with("Invalid JSON"));
+    }
+
+    /// Package files and repos, sorted so walk order doesn't matter
+    fn discovered(scan: &WorkspaceScan) -> (Vec<PathBuf>, Vec<PathBuf>) {
+        let mut package_jsons = scan.package_jsons().to_vec();
+        let mut git_repos = scan.git_repos().to_vec();
+        package_jsons.sort();
+        git_repos.sort();
+        (package_jsons, git_repos)
+    }
+
+    #[test]
+    fn test_single_package_fast_path_matches_full_walk() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join(".git/refs")).unwrap();
+        fs::write(root.join("package.json"), r#"{"name": "single"}"#).unwrap();
+        fs::create_dir_all(root.join("src/lib")).unwrap();
+        fs::write(root.join("src/lib/index.ts"), "export {};\n").unwrap();
+        fs::create_dir_all(root.join("node_modules/dep")).unwrap();
+        fs::write(root.join("node_modules/dep/package.json"), "{}").unwrap();
+        fs::create_dir_all(root.join("vendor/tool/.git")).unwrap();
+
+        assert!(WorkspaceScan::single_package(root, false, &mut WalkCounts::default()).is_some());
+        let fast = WorkspaceScan::new(root, false);
+        let full = WorkspaceScan::new_full_walk(root, false);
+
+        assert_eq!(discovered(&fast), discovered(&full));
+        assert_eq!(fast.git_repos().len(), 2);
+        assert_eq!(
+            fast.package_json(&root.join("package.json")),
+            full.package_json(&root.join("package.json"))
+        );
+    }
+
+    #[test]
+    fn test_nested_package_falls_back_to_full_walk() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(root.join("package.json"), r#"{"name": "root"}"#).unwrap();
+        fs::create_dir_all(root.join("examples/basic")).unwrap();
+        fs::write(root.join("examples/basic/package.json"), r#"{"name": "example"}"#).unwrap();
+
+        assert!(WorkspaceScan::single_package(root, false, &mut WalkCounts::default()).is_none());
+        assert_eq!(WorkspaceScan::new(root, false).package_jsons().len(), 2);
+    }
+
+    #[test]
+    fn test_workspace_info_detects_shared_facts() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(root.join("pnpm-workspace.yaml"), "packages:\n  - packages/*\n").unwrap();
+        fs::write(
+            root.join("package.json"),
+            r#"{"name": "root", "packageManager": "pnpm@9.1.0", "devDependencies": {"husky": "^8.0.3"}}"#,
+        )
+        .unwrap();
+        fs::create_dir_all(root.join("packages/app")).unwrap();
+        fs::write(root.join("packages/app/package.json"), r#"{"name": "app"}"#).unwrap();
+
+        let info = WorkspaceInfo::detect(root);
+        assert_eq!(info.workspace_root, root);
+        assert_eq!(info.package_manager.as_deref(), Some("pnpm"));
+        assert_eq!(info.husky_version, Some(8));
+
+        let nested = WorkspaceInfo::detect(&root.join("packages/app"));
+        assert_eq!(nested.workspace_root, root);
+        assert_eq!(nested.package_manager.as_deref(), Some("pnpm"));
+        assert_eq!(nested.husky_version, None);
+    }
+}
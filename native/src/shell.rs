@@ -0,0 +1,38 @@
+//! Helpers for finding commands in shell snippets such as package.json scripts
+//! and Husky hooks.
+
+/// Byte offsets where `command` starts a command in `script`: at the start or
+/// after a shell operator (`;`, `&`, `|`, `(`), and followed by whitespace or the
+/// end. `echo npm`, `pnpm` or `node ./bin/rm.js` don't count.
+pub fn command_positions(script: &str, command: &str) -> Vec<usize> {
+    script
+        .match_indices(command)
+        .map(|(i, _)| i)
+        .filter(|&i| {
+            let before = script[..i].trim_end().chars().last();
+            let after = script[i + command.len()..].chars().next();
+            before.is_none_or(|c| matches!(c, ';' | '&' | '|' | '('))
+                && after.is_none_or(char::is_whitespace)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_finds_commands_after_shell_operators() {
+        assert_eq!(command_positions("npm test", "npm"), vec![0]);
+        assert_eq!(command_positions("tsc && npm test", "npm"), vec![7]);
+        assert_eq!(command_positions("(npm ci; npm test) | tee log", "npm"), vec![1, 9]);
+        assert_eq!(command_positions("  npm", "npm"), vec![2]);
+    }
+
+    #[test]
+    fn test_ignores_arguments_and_longer_words() {
+        for script in ["echo npm", "pnpm test", "npm-run-all", "node ./bin/npm.js"] {
+            assert!(command_positions(script, "npm").is_empty(), "{}", script);
+        }
+    }
+}
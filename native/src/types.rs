@@ -1,7 +1,10 @@
+use crate::command::{CommandRunner, SystemCommandRunner};
+use crate::scan::{WorkspaceInfo, WorkspaceScan};
 use napi_derive::napi;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
 
 /// Severity level for lint results
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
@@ -22,9 +25,13 @@ impl std::fmt::Display for Severity {
     }
 }
 
+// The #[napi(object)] types below are marshaled to JS with camelCase field names.
+// Their serde representation uses the same names, so JSON output matches what JS sees.
+
 /// Describes a single check operation a rule performs
 #[napi(object)]
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct CheckEntry {
     /// Unique identifier within the rule (e.g., "settings-file-exists")
     pub id: String,
@@ -44,6 +51,7 @@ impl CheckEntry {
 /// Describes a single fix operation a rule can perform
 #[napi(object)]
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct FixEntry {
     /// Unique identifier within the rule (e.g., "create-settings-file")
     pub id: String,
@@ -63,9 +71,128 @@ impl FixEntry {
     }
 }
 
+/// A check in the flat catalog of every rule's checks
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckRef {
+    /// The rule this check belongs to
+    pub rule_id: String,
+    /// The check identifier within the rule
+    pub check_id: String,
+    /// Human-readable description of what this check validates
+    pub description: String,
+}
+
+/// A fix in the flat catalog of every rule's fixes
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FixRef {
+    /// The rule this fix belongs to
+    pub rule_id: String,
+    /// The fix identifier within the rule
+    pub fix_id: String,
+    /// Human-readable description of what this fix does
+    pub description: String,
+    /// Which check IDs this fix addresses
+    pub addresses: Vec<String>,
+}
+
+/// A single check cross-referenced with the fixes that address it
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckDescription {
+    /// The rule this check belongs to
+    pub rule_id: String,
+    /// The check identifier within the rule
+    pub check_id: String,
+    /// Human-readable description of what this check validates
+    pub description: String,
+    /// Whether any fix addresses this check
+    pub fixable: bool,
+    /// Fixes that address this check
+    pub fixes: Vec<FixEntry>,
+}
+
+/// A fix referenced by a report's results, with what applying it would touch
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FixSummary {
+    /// The fix identifier within its rule
+    pub fix_id: String,
+    /// The rule that owns the fix
+    pub rule_id: String,
+    /// Human-readable description of what the fix does
+    pub description: String,
+    /// Distinct paths of the results the fix addresses, in report order
+    pub affected_paths: Vec<String>,
+    /// Number of results listing this fix in `fixable_by`
+    pub resolves_count: u32,
+}
+
+/// A file as it would look after fixes, from a fix preview
+#[napi(object)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileContent {
+    pub path: String,
+    /// Full content after fixes; empty for deleted files
+    pub content: String,
+    /// The fixes would remove the file
+    pub deleted: bool,
+}
+
+/// One file change a fix would make, with the content it was planned against
+#[napi(object)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlannedChange {
+    pub path: String,
+    /// Full content to write; empty for deletions
+    pub content: String,
+    /// The change removes the file
+    pub deleted: bool,
+    /// Content of the file when the change was planned; None if it didn't exist
+    pub original: Option<String>,
+}
+
+/// Cost of the shared workspace scan under a root
+#[napi(object)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScanStats {
+    /// Directories the walk entered, including the root
+    pub directories_visited: i64,
+    /// Non-directory entries the walk went past
+    pub files_examined: i64,
+    /// package.json files found outside node_modules, before exclusions
+    pub package_jsons: u32,
+    /// Directories holding a .git entry
+    pub git_repos: u32,
+    /// Wall-clock time of the scan, including parsing every package.json
+    pub duration_ms: i64,
+}
+
+/// What the workspace scan found under a root, before any rule runs
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceReport {
+    /// package.json files rules will check, after node_modules and fixture exclusions
+    pub package_jsons: Vec<String>,
+    /// Directories holding a .git entry
+    pub git_repos: Vec<String>,
+    /// Nearest directory at or above the root with pnpm-workspace.yaml, if any
+    pub workspace_root: Option<String>,
+}
+
 /// A single lint result
 #[napi(object)]
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct LintResult {
     /// The rule that produced this result
     pub rule_id: String,
@@ -83,10 +210,16 @@ pub struct LintResult {
     pub suggestion: Option<String>,
     /// Which fix IDs can address this issue
     pub fixable_by: Vec<String>,
+    /// Stable identifier for baselining, independent of line numbers (set by the engine)
+    pub fingerprint: String,
+    /// Whether a fix in `fixable_by` can run here, i.e. the tools it needs are on
+    /// PATH. Only set when `annotateRunnableFixes` is enabled and the result is fixable.
+    pub fix_runnable: Option<bool>,
 }
 
 impl LintResult {
     /// Create a new LintResult with check_id and fixable_by
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         rule_id: &str,
         check_id: &str,
@@ -106,6 +239,8 @@ impl LintResult {
             line,
             suggestion,
             fixable_by: fixable_by.into_iter().map(String::from).collect(),
+            fingerprint: String::new(),
+            fix_runnable: None,
         }
     }
 }
@@ -113,12 +248,40 @@ impl LintResult {
 /// Complete lint report
 #[napi(object)]
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct LintReport {
     pub results: Vec<LintResult>,
     pub error_count: u32,
     pub warning_count: u32,
     pub info_count: u32,
     pub fixed_count: u32,
+    /// Whether results were cut off at the `maxResults` cap
+    #[serde(default)]
+    pub truncated: bool,
+    /// Number of results before truncation; the severity counts cover all of them
+    #[serde(default)]
+    pub total_before_truncation: u32,
+    /// Whether `failFast` stopped the run early, so later rules never ran
+    #[serde(default)]
+    pub fail_fast_triggered: bool,
+    /// Set by fix runs when no finding had a fix before fixing started, so
+    /// "already compliant" can be told apart from fixes that changed nothing
+    #[serde(default)]
+    pub nothing_to_fix: bool,
+    /// Fixes that failed during a fix run; the run carries on with the next rule
+    #[serde(default)]
+    pub fix_errors: Vec<FixError>,
+}
+
+/// A fix that returned an error instead of applying
+#[napi(object)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FixError {
+    /// The rule whose fix failed
+    pub rule_id: String,
+    /// The error the fix returned
+    pub message: String,
 }
 
 impl LintReport {
@@ -128,18 +291,85 @@ impl LintReport {
         let info_count = results.iter().filter(|r| r.severity == "info").count() as u32;
 
         Self {
+            total_before_truncation: results.len() as u32,
             results,
             error_count,
             warning_count,
             info_count,
             fixed_count,
+            truncated: false,
+            fail_fast_triggered: false,
+            nothing_to_fix: false,
+            fix_errors: Vec::new(),
+        }
+    }
+
+    /// Keep only the first `max_results` results. Severity counts and
+    /// `total_before_truncation` still describe the full set.
+    pub fn truncate(mut self, max_results: usize) -> LintReport {
+        if self.results.len() > max_results {
+            self.results.truncate(max_results);
+            self.truncated = true;
+        }
+        self
+    }
+
+    /// Combine two reports (e.g. from sharded scans) into one.
+    /// Results are concatenated, fixed counts summed and severity counts recomputed.
+    pub fn merge(self, other: LintReport) -> LintReport {
+        let truncated = self.truncated || other.truncated;
+        let fail_fast_triggered = self.fail_fast_triggered || other.fail_fast_triggered;
+        let nothing_to_fix = self.nothing_to_fix && other.nothing_to_fix;
+        let total = self.total_before_truncation + other.total_before_truncation;
+        let counts = [
+            self.error_count + other.error_count,
+            self.warning_count + other.warning_count,
+            self.info_count + other.info_count,
+        ];
+
+        let mut results = self.results;
+        results.extend(other.results);
+        let mut merged = LintReport::new(results, self.fixed_count + other.fixed_count);
+        merged.fail_fast_triggered = fail_fast_triggered;
+        merged.nothing_to_fix = nothing_to_fix;
+        merged.fix_errors = self.fix_errors;
+        merged.fix_errors.extend(other.fix_errors);
+
+        // Truncated reports carry counts for results that are no longer present
+        if truncated {
+            merged.truncated = true;
+            merged.total_before_truncation = total;
+            [merged.error_count, merged.warning_count, merged.info_count] = counts;
         }
+        merged
+    }
+
+    /// Drop results identical to an earlier one (same rule, check, path and message)
+    pub fn dedup(mut self) -> LintReport {
+        let mut seen = std::collections::HashSet::new();
+        self.retain(|r| {
+            seen.insert((r.rule_id.clone(), r.check_id.clone(), r.path.clone(), r.message.clone()))
+        });
+        self
+    }
+
+    /// Keep only the results `keep` accepts, recomputing the severity counts.
+    /// The report's flags and fix errors are left as they are.
+    pub fn retain(&mut self, keep: impl FnMut(&LintResult) -> bool) {
+        self.results.retain(keep);
+        let recounted = LintReport::new(std::mem::take(&mut self.results), self.fixed_count);
+        self.results = recounted.results;
+        self.error_count = recounted.error_count;
+        self.warning_count = recounted.warning_count;
+        self.info_count = recounted.info_count;
+        self.total_before_truncation = recounted.total_before_truncation;
     }
 }
 
 /// Rule information for listing
 #[napi(object)]
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct RuleInfo {
     /// Unique identifier for the rule
     pub id: String,
@@ -151,6 +381,10 @@ pub struct RuleInfo {
     pub default_severity: String,
     /// Whether any fixes are available
     pub can_fix: bool,
+    /// Whether the rule runs without being explicitly enabled in config
+    pub enabled_by_default: bool,
+    /// Group tags that `enabledTags` in config matches against
+    pub tags: Vec<String>,
     /// All checks this rule performs
     pub checks: Vec<CheckEntry>,
     /// All fixes this rule can apply
@@ -171,6 +405,10 @@ fn default_true() -> bool {
     true
 }
 
+fn default_max_file_size() -> u64 {
+    DEFAULT_MAX_FILE_SIZE
+}
+
 impl Default for RuleConfig {
     fn default() -> Self {
         Self {
@@ -181,41 +419,695 @@ impl Default for RuleConfig {
     }
 }
 
+/// Directories whose package.json files are test data rather than real projects
+pub const DEFAULT_FIXTURE_DIRS: &[&str] = &["__tests__", "__fixtures__", "fixtures", "examples"];
+
+/// Largest file (in bytes) rules read when scanning contents, unless `maxFileSize` is set
+pub const DEFAULT_MAX_FILE_SIZE: u64 = 1024 * 1024;
+
+/// How the final results of a run are ordered
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ResultOrder {
+    /// Errors first, then warnings, then info; ties ordered by path, then rule id
+    Severity,
+}
+
+/// A `suppress` regex, compiled when the config is parsed so invalid patterns
+/// are reported as config errors
+#[derive(Debug, Clone)]
+pub struct MessagePattern(regex::Regex);
+
+impl MessagePattern {
+    pub fn new(pattern: &str) -> Result<Self, regex::Error> {
+        regex::Regex::new(pattern).map(Self)
+    }
+
+    pub fn is_match(&self, message: &str) -> bool {
+        self.0.is_match(message)
+    }
+}
+
+impl Serialize for MessagePattern {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.0.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for MessagePattern {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let pattern = String::deserialize(deserializer)?;
+        Self::new(&pattern).map_err(serde::de::Error::custom)
+    }
+}
+
 /// Main configuration
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Config {
     #[serde(default)]
     pub rules: HashMap<String, RuleConfig>,
+    /// Descend into symlinked directories while scanning (see `walk` for the tradeoffs)
+    #[serde(default)]
+    pub follow_symlinks: bool,
+    /// Don't produce info-severity results
+    #[serde(default)]
+    pub quiet: bool,
+    /// Maximum number of fix passes; passes repeat until one applies no fixes.
+    /// Defaults to a single pass.
+    #[serde(default)]
+    pub fix_iterations: Option<u32>,
+    /// Report info results as warnings (applied before `warnings_as_errors`)
+    #[serde(default)]
+    pub promote_info: bool,
+    /// Report warning results as errors
+    #[serde(default)]
+    pub warnings_as_errors: bool,
+    /// Skip package.json files inside fixture directories (see `fixture_dirs`)
+    #[serde(default = "default_true")]
+    pub exclude_fixtures: bool,
+    /// Directory names (or `a/b` paths) treated as fixtures, replacing
+    /// `DEFAULT_FIXTURE_DIRS`
+    #[serde(default)]
+    pub fixture_dirs: Option<Vec<String>>,
+    /// Set `fix_runnable` on fixable results by checking each fix's required tools
+    #[serde(default)]
+    pub annotate_runnable_fixes: bool,
+    /// Never run external programs. Fixes that need one (see `fix_requirements`)
+    /// are dropped from `fixable_by`, and results left without a fix say manual
+    /// action is needed.
+    #[serde(default)]
+    pub offline: bool,
+    /// Replace the user's home directory in result paths with `~`
+    #[serde(default)]
+    pub redact_home: bool,
+    /// Literal prefix removed from result paths, e.g. a CI checkout like
+    /// `/github/workspace/`. Applied after `redact_home`.
+    #[serde(default)]
+    pub strip_prefix: Option<String>,
+    /// Files larger than this many bytes are skipped by rules that scan contents
+    #[serde(default = "default_max_file_size")]
+    pub max_file_size: u64,
+    /// Truncate reports to this many results; counts still cover all of them
+    #[serde(default)]
+    pub max_results: Option<u32>,
+    /// Only construct the rules this config enables. Listing and describing rules
+    /// still covers every built-in.
+    #[serde(default)]
+    pub load_enabled_rules_only: bool,
+    /// Run only rules carrying one of these tags; `rules.<id>.enabled` still wins
+    #[serde(default)]
+    pub enabled_tags: Option<Vec<String>>,
+    /// Stop running further rules once one reports an error. Only applies to
+    /// check runs; fix runs always apply every rule's fixes.
+    #[serde(default)]
+    pub fail_fast: bool,
+    /// Buffer each rule's file writes and removals during fix runs, applying them
+    /// only once the rule's fix succeeds. Commands a fix runs (e.g. `pnpm add`)
+    /// still take effect immediately.
+    #[serde(default)]
+    pub atomic_fix: bool,
+    /// Skip the check that fix runs do before fixing. Saves a full scan, but
+    /// `nothing_to_fix` is then never set.
+    #[serde(default)]
+    pub skip_check_in_fix_mode: bool,
+    /// Drop results whose message matches any of these regexes
+    #[serde(default)]
+    pub suppress: Vec<MessagePattern>,
+    /// Reorder results before building the report. Unset keeps rule order, then
+    /// the order each rule found them in.
+    #[serde(default)]
+    pub sort: Option<ResultOrder>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            rules: HashMap::new(),
+            follow_symlinks: false,
+            quiet: false,
+            fix_iterations: None,
+            promote_info: false,
+            warnings_as_errors: false,
+            exclude_fixtures: true,
+            fixture_dirs: None,
+            annotate_runnable_fixes: false,
+            offline: false,
+            redact_home: false,
+            strip_prefix: None,
+            max_file_size: DEFAULT_MAX_FILE_SIZE,
+            max_results: None,
+            load_enabled_rules_only: false,
+            enabled_tags: None,
+            fail_fast: false,
+            atomic_fix: false,
+            skip_check_in_fix_mode: false,
+            suppress: Vec::new(),
+            sort: None,
+        }
+    }
+}
+
+/// Error type for loading configuration
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigError {
+    #[error("Config file not found: {0}")]
+    NotFound(String),
+    #[error("Cannot read config file {0}: {1}")]
+    Io(String, std::io::Error),
+    #[error("Invalid config: {0}")]
+    Parse(#[from] serde_json::Error),
+    #[error("Invalid YAML config: {0}")]
+    ParseYaml(#[from] serde_yaml::Error),
+}
+
+impl Config {
+    /// Directories whose package.json files rules should skip
+    pub fn excluded_package_dirs(&self) -> Vec<String> {
+        if !self.exclude_fixtures {
+            return Vec::new();
+        }
+        match &self.fixture_dirs {
+            Some(dirs) => dirs.clone(),
+            None => DEFAULT_FIXTURE_DIRS.iter().map(|d| d.to_string()).collect(),
+        }
+    }
+
+    /// Parse a JSON config string; an empty string or `{}` yields the defaults
+    pub fn from_json(config_json: &str) -> Result<Self, ConfigError> {
+        if config_json.is_empty() || config_json == "{}" {
+            return Ok(Config::default());
+        }
+        Ok(serde_json::from_str(config_json)?)
+    }
+
+    /// Parse a YAML config string; an empty document yields the defaults
+    pub fn from_yaml(config_yaml: &str) -> Result<Self, ConfigError> {
+        if config_yaml.trim().is_empty() {
+            return Ok(Config::default());
+        }
+        Ok(serde_yaml::from_str(config_yaml)?)
+    }
+
+    /// Read and parse a config file, as YAML for `.yaml`/`.yml` files and JSON otherwise
+    pub fn from_file(path: &std::path::Path) -> Result<Self, ConfigError> {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            if e.kind() == std::io::ErrorKind::NotFound {
+                ConfigError::NotFound(path.display().to_string())
+            } else {
+                ConfigError::Io(path.display().to_string(), e)
+            }
+        })?;
+        let is_yaml = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|ext| ext == "yaml" || ext == "yml");
+        if is_yaml {
+            Self::from_yaml(&content)
+        } else {
+            Self::from_json(content.trim())
+        }
+    }
+}
+
+/// File changes buffered in memory instead of written to disk, so fixes can be
+/// previewed or applied all at once. Each path maps to its new content, or None
+/// when it was removed.
+#[derive(Default)]
+pub struct FileOverlay {
+    files: Mutex<BTreeMap<PathBuf, Option<String>>>,
+    executables: Mutex<BTreeSet<PathBuf>>,
+}
+
+impl FileOverlay {
+    /// The buffered entry for a path: Some(None) when it was removed
+    fn get(&self, path: &std::path::Path) -> Option<Option<String>> {
+        self.files.lock().unwrap().get(path).cloned()
+    }
+
+    fn set(&self, path: &std::path::Path, content: Option<String>) {
+        self.files.lock().unwrap().insert(path.to_path_buf(), content);
+    }
+
+    /// Every buffered change, sorted by path
+    pub fn changes(&self) -> Vec<(PathBuf, Option<String>)> {
+        let files = self.files.lock().unwrap();
+        files.iter().map(|(path, content)| (path.clone(), content.clone())).collect()
+    }
+
+    fn mark_executable(&self, path: &std::path::Path) {
+        self.executables.lock().unwrap().insert(path.to_path_buf());
+    }
+
+    /// Write the buffered changes to disk. New contents are first staged next to
+    /// their targets, so a failure while staging (e.g. a full disk) leaves every
+    /// target untouched; the staged files are then renamed into place.
+    pub fn commit(&self) -> std::io::Result<()> {
+        let changes = self.changes();
+        let mut staged: Vec<(PathBuf, PathBuf)> = Vec::new();
+
+        let mut stage_all = || -> std::io::Result<()> {
+            for (path, content) in &changes {
+                let Some(content) = content else {
+                    continue;
+                };
+                let mut staging = path.clone().into_os_string();
+                staging.push(".lineup-tmp");
+                let staging = PathBuf::from(staging);
+                if let Some(parent) = path.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                std::fs::write(&staging, content)?;
+                staged.push((staging, path.clone()));
+            }
+            Ok(())
+        };
+        if let Err(error) = stage_all() {
+            for (staging, _) in &staged {
+                let _ = std::fs::remove_file(staging);
+            }
+            return Err(error);
+        }
+
+        for (staging, path) in &staged {
+            std::fs::rename(staging, path)?;
+        }
+        for (path, _) in changes.iter().filter(|(_, content)| content.is_none()) {
+            if path.exists() {
+                std::fs::remove_file(path)?;
+            }
+        }
+        #[cfg(unix)]
+        for path in self.executables.lock().unwrap().iter().filter(|p| p.is_file()) {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755))?;
+        }
+        Ok(())
+    }
 }
 
 /// Context passed to rules during execution
 pub struct RuleContext {
     pub root: PathBuf,
-    pub fix_mode: bool,
     pub config: serde_json::Value,
+    pub follow_symlinks: bool,
+    /// Rules may skip checks that only produce info-severity results
+    pub quiet: bool,
+    /// External commands can't run, so rules skip the ones their options enable
+    pub offline: bool,
+    commands: Arc<dyn CommandRunner>,
+    scan: Option<Arc<WorkspaceScan>>,
+    workspace: Option<Arc<WorkspaceInfo>>,
+    excluded_dirs: Vec<String>,
+    max_file_size: u64,
+    overlay: Option<Arc<FileOverlay>>,
 }
 
 impl RuleContext {
-    pub fn new(root: PathBuf, fix_mode: bool, config: serde_json::Value) -> Self {
+    pub fn new(root: PathBuf, config: serde_json::Value) -> Self {
         Self {
             root,
-            fix_mode,
             config,
+            follow_symlinks: false,
+            quiet: false,
+            offline: false,
+            commands: Arc::new(SystemCommandRunner),
+            scan: None,
+            workspace: None,
+            excluded_dirs: Vec::new(),
+            max_file_size: DEFAULT_MAX_FILE_SIZE,
+            overlay: None,
+        }
+    }
+
+    pub fn with_follow_symlinks(mut self, follow_symlinks: bool) -> Self {
+        self.follow_symlinks = follow_symlinks;
+        self
+    }
+
+    pub fn with_quiet(mut self, quiet: bool) -> Self {
+        self.quiet = quiet;
+        self
+    }
+
+    pub fn with_offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    pub fn with_command_runner(mut self, commands: Arc<dyn CommandRunner>) -> Self {
+        self.commands = commands;
+        self
+    }
+
+    /// Run an external command in `cwd`, capturing its output
+    pub fn run_command(
+        &self,
+        program: &str,
+        args: &[&str],
+        cwd: &std::path::Path,
+    ) -> Result<std::process::Output, std::io::Error> {
+        self.commands.run(program, args, cwd)
+    }
+
+    /// Use a pre-built scan instead of walking the tree on every lookup
+    pub fn with_scan(mut self, scan: Arc<WorkspaceScan>) -> Self {
+        self.scan = Some(scan);
+        self
+    }
+
+    /// Share workspace facts detected once by the engine
+    pub fn with_workspace(mut self, workspace: Arc<WorkspaceInfo>) -> Self {
+        self.workspace = Some(workspace);
+        self
+    }
+
+    /// Workspace facts from the engine, or detected for the root when run standalone
+    pub fn workspace(&self) -> Arc<WorkspaceInfo> {
+        self.workspace
+            .clone()
+            .unwrap_or_else(|| Arc::new(WorkspaceInfo::detect(&self.root)))
+    }
+
+    /// Skip package.json files inside these directories (fixtures, examples)
+    pub fn with_excluded_dirs(mut self, excluded_dirs: Vec<String>) -> Self {
+        self.excluded_dirs = excluded_dirs;
+        self
+    }
+
+    /// Cap the size of files read with `read_file_capped`
+    pub fn with_max_file_size(mut self, max_file_size: u64) -> Self {
+        self.max_file_size = max_file_size;
+        self
+    }
+
+    /// Buffer writes and removals in `overlay` instead of touching the disk
+    pub fn with_overlay(mut self, overlay: Arc<FileOverlay>) -> Self {
+        self.overlay = Some(overlay);
+        self
+    }
+
+    /// Whether a package.json lies in an excluded fixture directory
+    pub fn is_excluded_package(&self, path: &std::path::Path) -> bool {
+        crate::walk::in_excluded_dir(path, &self.root, &self.excluded_dirs)
+    }
+
+    /// Find all package.json files under the root (excluding node_modules and
+    /// excluded fixture directories)
+    pub fn package_jsons(&self) -> Vec<PathBuf> {
+        let package_jsons = match &self.scan {
+            Some(scan) => scan.package_jsons().to_vec(),
+            None => crate::walk::find_package_jsons(&self.root, self.follow_symlinks),
+        };
+        package_jsons
+            .into_iter()
+            .filter(|path| !self.is_excluded_package(path))
+            .collect()
+    }
+
+    /// Find all git repositories under the root
+    pub fn git_repos(&self) -> Vec<PathBuf> {
+        match &self.scan {
+            Some(scan) => scan.git_repos().to_vec(),
+            None => crate::walk::find_git_repos(&self.root, self.follow_symlinks),
+        }
+    }
+
+    /// Parsed content of a package.json, from the scan when available.
+    /// Returns None if the file can't be read or isn't valid JSON; with a scan,
+    /// the engine reports such files once.
+    pub fn package_json_value(&self, path: &std::path::Path) -> Option<serde_json::Value> {
+        match self.scan.as_ref().and_then(|scan| scan.package_json(path)) {
+            Some(value) => value.as_ref().ok().cloned(),
+            None => crate::scan::read_package_json(path).ok(),
         }
     }
 
     pub fn read_file(&self, path: &std::path::Path) -> Result<String, std::io::Error> {
+        match self.overlay.as_ref().and_then(|overlay| overlay.get(path)) {
+            Some(buffered) => buffered.ok_or_else(|| std::io::ErrorKind::NotFound.into()),
+            None => std::fs::read_to_string(path),
+        }
+    }
+
+    /// Read a file for content scanning, failing with `ErrorKind::FileTooLarge`
+    /// when it exceeds the `maxFileSize` limit instead of loading it
+    pub fn read_file_capped(&self, path: &std::path::Path) -> Result<String, std::io::Error> {
+        if let Some(buffered) = self.overlay.as_ref().and_then(|overlay| overlay.get(path)) {
+            return buffered.ok_or_else(|| std::io::ErrorKind::NotFound.into());
+        }
+        let size = std::fs::metadata(path)?.len();
+        if size > self.max_file_size {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::FileTooLarge,
+                format!("{} bytes exceeds the {} byte limit", size, self.max_file_size),
+            ));
+        }
         std::fs::read_to_string(path)
     }
 
     pub fn write_file(&self, path: &std::path::Path, content: &str) -> Result<(), std::io::Error> {
+        if let Some(overlay) = &self.overlay {
+            overlay.set(path, Some(content.to_string()));
+            return Ok(());
+        }
         if let Some(parent) = path.parent() {
             std::fs::create_dir_all(parent)?;
         }
         std::fs::write(path, content)
     }
 
+    pub fn remove_file(&self, path: &std::path::Path) -> Result<(), std::io::Error> {
+        match &self.overlay {
+            Some(overlay) => {
+                overlay.set(path, None);
+                Ok(())
+            }
+            None => std::fs::remove_file(path),
+        }
+    }
+
+    /// Mark a script executable on Unix. Buffered writes apply it on commit.
+    pub fn set_executable(&self, path: &std::path::Path) -> Result<(), std::io::Error> {
+        if let Some(overlay) = &self.overlay {
+            overlay.mark_executable(path);
+            return Ok(());
+        }
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mut perms = std::fs::metadata(path)?.permissions();
+            perms.set_mode(0o755);
+            std::fs::set_permissions(path, perms)?;
+        }
+        #[cfg(not(unix))]
+        let _ = path;
+        Ok(())
+    }
+
+    /// Whether a file exists, counting buffered writes and removals
     pub fn file_exists(&self, path: &std::path::Path) -> bool {
-        path.exists()
+        match self.overlay.as_ref().and_then(|overlay| overlay.get(path)) {
+            Some(buffered) => buffered.is_some(),
+            None => path.exists(),
+        }
+    }
+
+    /// Files directly inside `dir`, sorted, counting buffered writes and removals
+    pub fn list_files(&self, dir: &std::path::Path) -> Vec<PathBuf> {
+        let mut files: BTreeSet<PathBuf> = std::fs::read_dir(dir)
+            .map(|entries| {
+                entries.flatten().map(|entry| entry.path()).filter(|p| p.is_file()).collect()
+            })
+            .unwrap_or_default();
+        if let Some(overlay) = &self.overlay {
+            for (path, content) in overlay.changes() {
+                if path.parent() != Some(dir) {
+                    continue;
+                }
+                if content.is_some() {
+                    files.insert(path);
+                } else {
+                    files.remove(&path);
+                }
+            }
+        }
+        files.into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(rule_id: &str, severity: Severity, path: &str, message: &str) -> LintResult {
+        LintResult::new(
+            rule_id,
+            "some-check",
+            severity,
+            message.into(),
+            PathBuf::from(path),
+            None,
+            None,
+            vec![],
+        )
+    }
+
+    #[test]
+    fn test_merge_combines_results_and_counts() {
+        let first = LintReport::new(
+            vec![
+                result("a", Severity::Error, "/repo/one", "first"),
+                result("a", Severity::Warning, "/repo/one", "second"),
+            ],
+            2,
+        );
+        let second = LintReport::new(
+            vec![
+                result("b", Severity::Info, "/repo/two", "third"),
+                result("a", Severity::Error, "/repo/one", "first"),
+            ],
+            1,
+        );
+
+        let merged = first.merge(second);
+
+        assert_eq!(merged.results.len(), 4);
+        assert_eq!(merged.error_count, 2);
+        assert_eq!(merged.warning_count, 1);
+        assert_eq!(merged.info_count, 1);
+        assert_eq!(merged.fixed_count, 3);
+
+        let deduped = merged.dedup();
+
+        assert_eq!(deduped.results.len(), 3);
+        assert_eq!(deduped.error_count, 1);
+        assert_eq!(deduped.warning_count, 1);
+        assert_eq!(deduped.info_count, 1);
+        assert_eq!(deduped.fixed_count, 3);
+    }
+
+    #[test]
+    fn test_config_from_file_parses_valid_config() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("lineup.json");
+        std::fs::write(
+            &path,
+            r#"{"rules": {"todo-budget": {"enabled": true}}, "followSymlinks": true}"#,
+        )
+        .unwrap();
+
+        let config = Config::from_file(&path).unwrap();
+
+        assert!(config.follow_symlinks);
+        assert!(config.rules["todo-budget"].enabled);
+    }
+
+    #[test]
+    fn test_config_from_file_reports_missing_file() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("missing.json");
+
+        let err = Config::from_file(&path).unwrap_err();
+
+        assert!(matches!(err, ConfigError::NotFound(_)));
+        assert!(err.to_string().contains("missing.json"));
+    }
+
+    #[test]
+    fn test_config_from_file_reports_malformed_json() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("broken.json");
+        std::fs::write(&path, r#"{"rules": "#).unwrap();
+
+        let err = Config::from_file(&path).unwrap_err();
+
+        assert!(matches!(err, ConfigError::Parse(_)));
+        assert!(err.to_string().starts_with("Invalid config"));
+    }
+
+    #[test]
+    fn test_serialized_keys_match_js_names() {
+        let mut report = LintReport::new(
+            vec![LintResult::new(
+                "rule",
+                "check",
+                Severity::Warning,
+                "message".into(),
+                PathBuf::from("/tmp/file"),
+                Some(1),
+                None,
+                vec!["fix"],
+            )],
+            0,
+        );
+        report.results[0].fingerprint = "abc".into();
+
+        let json = serde_json::to_value(&report).unwrap();
+        for key in ["results", "errorCount", "warningCount", "infoCount", "fixedCount"] {
+            assert!(json.get(key).is_some(), "missing {}", key);
+        }
+        let result = &json["results"][0];
+        for key in ["ruleId", "checkId", "severity", "message", "path", "line", "fixableBy", "fingerprint"] {
+            assert!(result.get(key).is_some(), "missing {}", key);
+        }
+        assert!(result.get("rule_id").is_none());
+
+        let info = serde_json::to_value(RuleInfo {
+            id: "rule".into(),
+            name: "Rule".into(),
+            description: "".into(),
+            default_severity: "error".into(),
+            can_fix: false,
+            enabled_by_default: true,
+            tags: vec![],
+            checks: vec![],
+            fixes: vec![],
+        })
+        .unwrap();
+        for key in ["defaultSeverity", "canFix", "enabledByDefault"] {
+            assert!(info.get(key).is_some(), "missing {}", key);
+        }
+    }
+
+    #[test]
+    fn test_yaml_config_matches_json_form() {
+        let json = Config::from_json(
+            r#"{"followSymlinks": true, "quiet": true, "rules": {"todo-budget": {"enabled": true, "severity": "Error", "options": {"maxPerFile": 3}}, "husky-init": {"enabled": false}}}"#,
+        )
+        .unwrap();
+        let yaml = Config::from_yaml(
+            "followSymlinks: true\nquiet: true\nrules:\n  todo-budget:\n    enabled: true\n    severity: Error\n    options:\n      maxPerFile: 3\n  husky-init:\n    enabled: false\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&yaml).unwrap(),
+            serde_json::to_value(&json).unwrap()
+        );
+        assert_eq!(yaml.rules["todo-budget"].options["maxPerFile"], 3);
+    }
+
+    #[test]
+    fn test_suppress_patterns_are_validated_at_parse() {
+        let config = Config::from_json(r#"{"suppress": ["^Missing .*\\.md$"]}"#).unwrap();
+        assert!(config.suppress[0].is_match("Missing CONTRIBUTING.md"));
+        assert!(!config.suppress[0].is_match("Missing LICENSE"));
+
+        let err = Config::from_json(r#"{"suppress": ["(unclosed"]}"#).unwrap_err();
+        assert!(matches!(err, ConfigError::Parse(_)));
+        assert!(Config::from_yaml("suppress:\n  - '[a-'\n").is_err());
+    }
+
+    #[test]
+    fn test_yaml_config_errors() {
+        let err = Config::from_yaml("rules: [unclosed").unwrap_err();
+        assert!(matches!(err, ConfigError::ParseYaml(_)));
+        assert!(err.to_string().starts_with("Invalid YAML config"));
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join(".lineuprc.yaml");
+        std::fs::write(&path, "followSymlinks: true\n").unwrap();
+        assert!(Config::from_file(&path).unwrap().follow_symlinks);
     }
 }
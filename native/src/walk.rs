@@ -0,0 +1,142 @@
+//! Shared directory traversal used by the rules.
+//!
+//! Symlinks are not followed by default. When `followSymlinks` is enabled, a
+//! symlinked directory is descended into, which lets shared config directories
+//! be scanned but also means files outside the scan root may be read (and
+//! fixed). To keep loops and diamond-shaped link graphs from hanging the scan
+//! or producing duplicate results, every directory is visited at most once,
+//! keyed by its canonical path.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use walkdir::{DirEntry, WalkDir};
+
+/// Walk `root` and return every entry not inside one of `skip_dirs`.
+pub fn entries(root: &Path, follow_symlinks: bool, skip_dirs: &[&str]) -> Vec<DirEntry> {
+    let mut visited: HashSet<PathBuf> = HashSet::new();
+
+    WalkDir::new(root)
+        .follow_links(follow_symlinks)
+        .into_iter()
+        .filter_entry(|e| {
+            if !e.file_type().is_dir() {
+                return true;
+            }
+            if e.depth() > 0
+                && e.file_name()
+                    .to_str()
+                    .is_some_and(|name| skip_dirs.contains(&name))
+            {
+                return false;
+            }
+            if follow_symlinks {
+                // Skip directories already reached through another path
+                let canonical = e.path().canonicalize().unwrap_or_else(|_| e.path().to_path_buf());
+                return visited.insert(canonical);
+            }
+            true
+        })
+        .filter_map(|e| e.ok())
+        .collect()
+}
+
+/// Find all package.json files in the given root (excluding node_modules)
+pub fn find_package_jsons(root: &Path, follow_symlinks: bool) -> Vec<PathBuf> {
+    entries(root, follow_symlinks, &["node_modules"])
+        .into_iter()
+        .filter(|e| e.file_name() == "package.json" && e.path().is_file())
+        .map(|e| e.into_path())
+        .collect()
+}
+
+/// Whether `path` lies inside one of `dirs` below `root`. Entries may span
+/// several components (`test/fixtures`); they match whole directory names only.
+pub fn in_excluded_dir(path: &Path, root: &Path, dirs: &[String]) -> bool {
+    let relative: Vec<&str> = path
+        .parent()
+        .and_then(|dir| dir.strip_prefix(root).ok())
+        .map(|dir| dir.components().filter_map(|c| c.as_os_str().to_str()).collect())
+        .unwrap_or_default();
+
+    dirs.iter().any(|dir| {
+        let parts: Vec<&str> = dir.split('/').filter(|p| !p.is_empty()).collect();
+        !parts.is_empty() && relative.windows(parts.len()).any(|w| w == parts.as_slice())
+    })
+}
+
+/// Find all git repositories in the given root, returning the repo roots
+pub fn find_git_repos(root: &Path, follow_symlinks: bool) -> Vec<PathBuf> {
+    entries(root, follow_symlinks, &[])
+        .into_iter()
+        .filter(|e| e.file_name() == ".git" && e.path().is_dir())
+        .filter_map(|e| e.path().parent().map(Path::to_path_buf))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_skips_node_modules() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(root.join("package.json"), "{}").unwrap();
+        let dep = root.join("node_modules").join("dep");
+        fs::create_dir_all(&dep).unwrap();
+        fs::write(dep.join("package.json"), "{}").unwrap();
+
+        let found = find_package_jsons(root, false);
+
+        assert_eq!(found, vec![root.join("package.json")]);
+    }
+
+    #[test]
+    fn test_in_excluded_dir_matches_whole_components() {
+        let root = Path::new("/repo");
+        let dirs = vec!["fixtures".to_string(), "test/data".to_string()];
+
+        assert!(in_excluded_dir(Path::new("/repo/pkg/fixtures/a/package.json"), root, &dirs));
+        assert!(in_excluded_dir(Path::new("/repo/test/data/package.json"), root, &dirs));
+        assert!(!in_excluded_dir(Path::new("/repo/my-fixtures/package.json"), root, &dirs));
+        assert!(!in_excluded_dir(Path::new("/repo/data/package.json"), root, &dirs));
+        assert!(!in_excluded_dir(Path::new("/repo/package.json"), root, &dirs));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_follows_symlinked_directory_only_when_enabled() {
+        let temp_dir = TempDir::new().unwrap();
+        let shared = temp_dir.path().join("shared");
+        fs::create_dir_all(&shared).unwrap();
+        fs::write(shared.join("package.json"), "{}").unwrap();
+
+        let root = temp_dir.path().join("project");
+        fs::create_dir_all(&root).unwrap();
+        std::os::unix::fs::symlink(&shared, root.join("linked")).unwrap();
+
+        assert!(find_package_jsons(&root, false).is_empty());
+        assert_eq!(
+            find_package_jsons(&root, true),
+            vec![root.join("linked").join("package.json")]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_symlink_cycle_terminates() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().join("project");
+        let nested = root.join("nested");
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(nested.join("package.json"), "{}").unwrap();
+        std::os::unix::fs::symlink(&root, nested.join("loop")).unwrap();
+        std::os::unix::fs::symlink(&nested, root.join("alias")).unwrap();
+
+        let found = find_package_jsons(&root, true);
+
+        assert_eq!(found.len(), 1);
+    }
+}
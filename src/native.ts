@@ -10,21 +10,58 @@ const __dirname = dirname(fileURLToPath(import.meta.url));
 interface NativeBinding {
   Engine: new (configJson: string) => EngineInstance;
   createEngine: (configJson: string) => EngineInstance;
+  createEngineYaml: (configYaml: string) => EngineInstance;
+  createEngineFromFile: (configPath: string) => EngineInstance;
+  defaultConfig: (preset: string) => string;
+  mergeReports: (
+    first: LintReport,
+    second: LintReport,
+    dedupe?: boolean
+  ) => LintReport;
+  toLspDiagnostics: (report: LintReport) => LspFileDiagnostics[];
 }
 
 export interface EngineInstance {
   lint: (path: string) => LintReport;
+  lintCached: (path: string) => LintReport;
+  prepareRoot: (path: string) => PreparedRoot;
+  lintPrepared: (root: PreparedRoot) => LintReport;
   fix: (path: string) => LintReport;
+  fixRule: (path: string, ruleId: string) => LintReport;
   listRules: () => RuleInfo[];
+  listFixableRules: () => RuleInfo[];
+  ruleOrder: () => string[];
+  lintWithBaseline: (path: string, baselineFingerprints: string[]) => LintReport;
+  baselineFingerprints: (path: string) => string[];
+  writeBaseline: (path: string, outPath: string) => number;
+  lintWithBaselineFile: (path: string, baselinePath: string) => LintReport;
+  describeCheck: (ruleId: string, checkId: string) => CheckDescription | null;
+  allCheckIds: () => CheckRef[];
+  allFixIds: () => FixRef[];
+  summarizeFixes: (report: LintReport) => FixSummary[];
+  scanWorkspace: (path: string) => WorkspaceReport;
+  scanStats: (path: string) => ScanStats;
+  previewFixedFiles: (path: string) => FileContent[];
+  planFix: (path: string) => PlannedChange[];
+  applyChange: (change: PlannedChange) => void;
+}
+
+/** Opaque handle to a scan root validated by prepareRoot */
+export interface PreparedRoot {
+  readonly __brand: "PreparedRoot";
 }
 
 export interface LintResult {
   ruleId: string;
+  checkId: string;
   severity: string;
   message: string;
   path: string;
   line?: number;
   suggestion?: string;
+  fixableBy: string[];
+  fingerprint: string;
+  fixRunnable?: boolean;
 }
 
 export interface LintReport {
@@ -33,6 +70,16 @@ export interface LintReport {
   warningCount: number;
   infoCount: number;
   fixedCount: number;
+  truncated: boolean;
+  totalBeforeTruncation: number;
+  failFastTriggered: boolean;
+  nothingToFix: boolean;
+  fixErrors: FixError[];
+}
+
+export interface FixError {
+  ruleId: string;
+  message: string;
 }
 
 export interface RuleInfo {
@@ -41,6 +88,93 @@ export interface RuleInfo {
   description: string;
   defaultSeverity: string;
   canFix: boolean;
+  enabledByDefault: boolean;
+  tags: string[];
+  checks: CheckEntry[];
+  fixes: FixEntry[];
+}
+
+export interface CheckEntry {
+  id: string;
+  description: string;
+}
+
+export interface FixEntry {
+  id: string;
+  description: string;
+  addresses: string[];
+}
+
+export interface CheckDescription {
+  ruleId: string;
+  checkId: string;
+  description: string;
+  fixable: boolean;
+  fixes: FixEntry[];
+}
+
+export interface CheckRef {
+  ruleId: string;
+  checkId: string;
+  description: string;
+}
+
+export interface FixRef {
+  ruleId: string;
+  fixId: string;
+  description: string;
+  addresses: string[];
+}
+
+export interface FixSummary {
+  fixId: string;
+  ruleId: string;
+  description: string;
+  affectedPaths: string[];
+  resolvesCount: number;
+}
+
+export interface FileContent {
+  path: string;
+  content: string;
+  deleted: boolean;
+}
+
+export interface ScanStats {
+  directoriesVisited: number;
+  filesExamined: number;
+  packageJsons: number;
+  gitRepos: number;
+  durationMs: number;
+}
+
+export interface PlannedChange {
+  path: string;
+  content: string;
+  deleted: boolean;
+  original?: string;
+}
+
+export interface LspDiagnostic {
+  range: {
+    start: { line: number; character: number };
+    end: { line: number; character: number };
+  };
+  severity: 1 | 2 | 3;
+  source: string;
+  code: string;
+  message: string;
+}
+
+export interface LspFileDiagnostics {
+  uri: string;
+  diagnostics: LspDiagnostic[];
+}
+
+export interface WorkspaceReport {
+  packageJsons: string[];
+  gitRepos: string[];
+  workspaceRoot?: string;
 }
 
 function getPackageName(): string {